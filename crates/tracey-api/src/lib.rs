@@ -28,6 +28,34 @@ pub enum GitStatus {
 pub struct ApiConfig {
     pub project_root: String,
     pub specs: Vec<ApiSpecInfo>,
+    /// Verbs declared in the config besides the built-in ones
+    #[facet(default)]
+    pub verbs: Vec<ApiVerb>,
+}
+
+/// A reference verb declared in the config
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ApiVerb {
+    pub name: String,
+    /// Coverage its references count toward, if any
+    #[facet(default)]
+    pub counts: Option<VerbCounts>,
+    /// Whether its references may appear in test files
+    pub in_tests: bool,
+    #[facet(default)]
+    pub description: Option<String>,
+}
+
+/// Coverage a declared verb's references count toward
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Facet)]
+#[facet(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum VerbCounts {
+    /// Like `impl` references
+    Impl,
+    /// Like `verify` references
+    Verify,
 }
 
 #[derive(Debug, Clone, Facet)]
@@ -49,6 +77,29 @@ pub struct ApiSpecInfo {
 
     /// Available implementations for this spec
     pub implementations: Vec<String>,
+
+    /// Package manifests implementations are also discovered from
+    /// ("cargo", "npm" or "go"), if any
+    #[facet(default)]
+    pub impls_from: Option<String>,
+
+    /// Rules removed from this spec (from the `removed` config list)
+    #[facet(default)]
+    pub removed: Vec<ApiRemovedRule>,
+}
+
+/// A rule that was removed from a spec
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ApiRemovedRule {
+    /// Base ID of the removed rule
+    pub id: String,
+    /// Base ID of the rule that replaces it
+    #[facet(default)]
+    pub replaced_by: Option<String>,
+    /// Why the rule was removed
+    #[facet(default)]
+    pub reason: Option<String>,
 }
 
 /// Forward traceability: rules with their code references
@@ -90,6 +141,10 @@ pub struct ApiRule {
     pub impl_refs: Vec<ApiCodeRef>,
     pub verify_refs: Vec<ApiCodeRef>,
     pub depends_refs: Vec<ApiCodeRef>,
+    /// References with a declared verb that counts toward neither impl nor
+    /// verify coverage
+    #[facet(default)]
+    pub other_refs: Vec<ApiCodeRef>,
     /// True if any reference to this rule is stale (points to an older version).
     /// A stale rule is not counted as covered.
     #[facet(default)]
@@ -97,12 +152,98 @@ pub struct ApiRule {
     /// Stale references pointing to older versions of this rule.
     #[facet(default)]
     pub stale_refs: Vec<ApiStaleRef>,
+    /// True if the rule is deprecated or its status is listed in the spec's
+    /// `exclude_statuses`. Excluded rules are left out of coverage numbers.
+    #[facet(default)]
+    pub excluded: bool,
+    /// IDs this rule replaces (`replaces=` in its marker). References to them
+    /// count for this rule.
+    #[facet(default)]
+    pub replaces: Vec<RuleId>,
+    /// References that still use one of the replaced IDs.
+    #[facet(default)]
+    pub renamed_refs: Vec<ApiStaleRef>,
+    /// Rules this rule depends on (`depends=` in its marker), possibly in
+    /// other specs.
+    #[facet(default)]
+    pub depends_on: Vec<ApiRuleLink>,
+    /// Rules of any spec that depend on this rule.
+    #[facet(default)]
+    pub dependents: Vec<ApiRuleLink>,
+    /// Combined outcome of the tests enclosing its `verify` references, when
+    /// test results were ingested and the rule has any.
+    #[facet(default)]
+    pub verification: Option<TestOutcome>,
+    /// Whether the test suite ran any of the code implementing this rule,
+    /// when runtime coverage data was imported and covers its `impl`
+    /// references.
+    #[facet(default)]
+    pub executed: Option<bool>,
+    /// Bumped rules this rule transitively depends on, at their current
+    /// version, whose change its `depends=` doesn't acknowledge yet. Only set
+    /// in specs with `propagate_stale`.
+    #[facet(default)]
+    pub needs_review: Vec<ApiRuleLink>,
+    /// Owners of this rule, e.g. "@payments": from `owner=` in its marker,
+    /// or else the spec's `owners` config.
+    #[facet(default)]
+    pub owners: Vec<String>,
+    /// Free-form tags of this rule (`tags=` in its marker), e.g. "security"
+    #[facet(default)]
+    pub tags: Vec<String>,
+}
+
+/// A dependency between two rules, possibly of different specs.
+#[derive(Debug, Clone, Facet)]
+pub struct ApiRuleLink {
+    /// Marker prefix of the other rule
+    pub prefix: String,
+    /// Spec defining the other rule, or `None` if no spec does
+    #[facet(default)]
+    pub spec: Option<String>,
+    pub id: RuleId,
 }
 
 #[derive(Debug, Clone, Facet)]
 pub struct ApiCodeRef {
     pub file: String,
     pub line: usize,
+    /// Outcome of the test enclosing a `verify` reference, when test results
+    /// were ingested
+    #[facet(default)]
+    pub outcome: Option<TestOutcome>,
+    /// How often the code unit containing an `impl` reference ran, when
+    /// runtime coverage data was imported
+    #[facet(default)]
+    pub hits: Option<u64>,
+    /// The reference's verb when it is one declared in the config, e.g.
+    /// "bench"
+    #[facet(default)]
+    pub verb: Option<String>,
+}
+
+/// Outcome of a test, or of all tests verifying a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Facet)]
+#[facet(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum TestOutcome {
+    /// The test passed; for a rule, no verifying test failed and one passed
+    Passed,
+    /// The test failed; for a rule, one of its verifying tests failed
+    Failed,
+    /// The test was skipped or has no result
+    NotRun,
+}
+
+/// A test case read from a test result file.
+#[derive(Debug, Clone, Facet)]
+pub struct ApiTestResult {
+    /// Test name as reported, e.g. `tests::login_works` or `TestLogin/ok`
+    pub name: String,
+    /// Source file of the test, when the format records it
+    #[facet(default)]
+    pub file: Option<String>,
+    pub outcome: TestOutcome,
 }
 
 /// A stale reference: code points to an older version of a rule.
@@ -110,7 +251,7 @@ pub struct ApiCodeRef {
 pub struct ApiStaleRef {
     pub file: String,
     pub line: usize,
-    /// The rule ID referenced in code (older version)
+    /// The rule ID referenced in code (older version, or replaced ID)
     pub reference_id: RuleId,
 }
 
@@ -158,6 +299,9 @@ pub struct ApiCodeUnit {
     pub end_line: usize,
     /// Rule references found in this code unit's comments
     pub rule_refs: Vec<String>,
+    /// How often this code unit ran, when runtime coverage data was imported
+    #[facet(default)]
+    pub hits: Option<u64>,
 }
 
 /// A section of a spec (one source file)
@@ -213,7 +357,7 @@ pub struct ApiSpecData {
 // Validation
 // ============================================================================
 
-/// r[impl validation.circular-deps]
+/// r[impl validation.circular-deps+2]
 /// r[impl validation.naming]
 ///
 /// A validation error found in the spec or implementation.
@@ -236,7 +380,7 @@ pub struct ValidationError {
     /// Related rule IDs (for dependency errors)
     #[facet(default)]
     pub related_rules: Vec<RuleId>,
-    /// The referenced rule ID (for StaleRequirement/UnknownRequirement errors)
+    /// The referenced rule ID (for Stale/Unknown/Removed/RenamedRequirement errors)
     #[facet(default)]
     pub reference_rule_id: Option<RuleId>,
     /// Original annotation text for unknown references (for example `r[impl auth.logn]`).
@@ -263,6 +407,14 @@ pub enum ValidationErrorCode {
     UnknownPrefix,
     /// Impl annotation in test file (only verify allowed)
     ImplInTestFile,
+    /// Coverage is below a threshold configured in `thresholds`
+    CoverageThreshold,
+    /// Reference to a rule whose status is `deprecated`
+    DeprecatedRequirement,
+    /// Reference to a rule listed in the spec's `removed` config
+    RemovedRequirement,
+    /// Reference to an ID that a rule replaces (`replaces=`)
+    RenamedRequirement,
 }
 
 /// Validation results for a spec/implementation pair
@@ -280,3 +432,65 @@ pub struct ValidationResult {
     /// Number of errors (fatal issues)
     pub error_count: usize,
 }
+
+/// Coverage counts recorded in the coverage history
+#[derive(Debug, Clone, Default, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ApiHistoryStats {
+    /// Total number of rules
+    pub total_rules: usize,
+    /// Rules with at least one exact implementation reference (not stale)
+    pub impl_covered: usize,
+    /// Rules with at least one verification reference
+    pub verify_covered: usize,
+    /// Rules with stale references
+    pub stale: usize,
+    /// Total code units (reverse coverage); zero for sections
+    #[facet(default)]
+    pub total_units: usize,
+    /// Code units with at least one rule reference; zero for sections
+    #[facet(default)]
+    pub covered_units: usize,
+}
+
+/// Coverage of one spec section at a recorded commit
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ApiHistorySection {
+    /// Section title (heading text), or "Uncategorized"
+    pub title: String,
+    pub stats: ApiHistoryStats,
+}
+
+/// Coverage of one spec/impl pair at a recorded commit
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ApiHistoryImpl {
+    pub spec: String,
+    pub impl_name: String,
+    pub stats: ApiHistoryStats,
+    /// Per-section coverage, in spec order
+    #[facet(default)]
+    pub sections: Vec<ApiHistorySection>,
+}
+
+/// Coverage recorded for one git commit
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ApiHistoryEntry {
+    /// Full commit hash
+    pub commit: String,
+    /// Committer date (YYYY-MM-DD)
+    pub date: String,
+    /// Committer timestamp (seconds since the Unix epoch), used for ordering
+    pub timestamp: i64,
+    /// First line of the commit message
+    pub subject: String,
+    pub impls: Vec<ApiHistoryImpl>,
+}
+
+/// Recorded coverage history, oldest entry first
+#[derive(Debug, Clone, Default, Facet)]
+pub struct ApiHistory {
+    pub entries: Vec<ApiHistoryEntry>,
+}
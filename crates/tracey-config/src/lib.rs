@@ -7,16 +7,30 @@
 
 use facet::Facet;
 
+/// Location of the config file, relative to the project root
+pub const DEFAULT_CONFIG_PATH: &str = ".config/tracey/config.styx";
+
+/// Parse the content of a config file.
+pub fn parse(content: &str) -> Result<Config, impl std::error::Error + Send + Sync + 'static> {
+    facet_styx::from_str(content)
+}
+
 /// Root configuration for tracey
 #[derive(Debug, Clone, Default, Facet)]
 pub struct Config {
     /// Specifications to track coverage against
     #[facet(default)]
     pub specs: Vec<SpecConfig>,
+
+    /// Verbs references may use besides `impl`, `verify`, `depends`,
+    /// `related` and `define`
+    /// r[impl config.verbs]
+    #[facet(default)]
+    pub verbs: Vec<VerbConfig>,
 }
 
 /// Configuration for a single specification
-#[derive(Debug, Clone, Facet)]
+#[derive(Debug, Clone, Default, Facet)]
 pub struct SpecConfig {
     /// Name of the spec (for display purposes)
     /// r[impl config.spec.name]
@@ -43,10 +57,117 @@ pub struct SpecConfig {
     /// Each impl block specifies which source files to scan
     #[facet(default)]
     pub impls: Vec<Impl>,
+
+    /// Add an implementation per package of the project's workspace, read
+    /// from its manifests: "cargo" (`Cargo.toml`), "npm" (`package.json`
+    /// workspaces) or "go" (`go.work`). Impls listed in `impls` take
+    /// precedence over discovered ones of the same name.
+    /// r[impl config.spec.impls-from]
+    #[facet(default)]
+    pub impls_from: Option<String>,
+
+    /// Coverage thresholds applied to every implementation of this spec
+    /// r[impl config.thresholds.spec]
+    #[facet(default)]
+    pub thresholds: Option<Thresholds>,
+
+    /// Coverage thresholds applied to rules whose ID starts with a given prefix
+    /// r[impl config.thresholds.prefix+2]
+    #[facet(default)]
+    pub prefix_thresholds: Vec<PrefixThresholds>,
+
+    /// Rule statuses left out of coverage numbers, e.g. `(draft deprecated)`
+    /// r[impl config.spec.exclude-statuses]
+    #[facet(default)]
+    pub exclude_statuses: Vec<String>,
+
+    /// Rules that were removed from the spec, so references to them can say
+    /// what replaced them
+    /// r[impl config.spec.removed]
+    #[facet(default)]
+    pub removed: Vec<RemovedRule>,
+
+    /// Read the spec files from a revision of another local git repository
+    /// instead of the working tree; `include` patterns are relative to it
+    /// r[impl config.spec.git]
+    #[facet(default)]
+    pub git: Option<GitSource>,
+
+    /// Mark rules that transitively depend on a bumped rule, and the code
+    /// implementing them, as needing review
+    /// r[impl config.spec.propagate-stale]
+    #[facet(default)]
+    pub propagate_stale: bool,
+
+    /// Owners of the spec's rules, by rule ID prefix; the last matching
+    /// entry wins, as in CODEOWNERS. A marker's `owner=` takes precedence.
+    /// r[impl config.spec.owners+2]
+    #[facet(default)]
+    pub owners: Vec<OwnersConfig>,
 }
 
-/// Configuration for a single implementation of a spec
+/// A spec read from a revision of another git repository, e.g.
+/// `git {repo ../protocol-spec, rev v2.0}`
+#[derive(Debug, Clone, Facet)]
+pub struct GitSource {
+    /// Path to the repository, relative to the project root
+    pub repo: String,
+
+    /// Branch, tag or commit to read the spec from. The commit it resolves
+    /// to is pinned in `.config/tracey/spec.lock` until `tracey spec update`.
+    pub rev: String,
+}
+
+/// A rule that was removed from a spec (a tombstone)
 #[derive(Debug, Clone, Facet)]
+pub struct RemovedRule {
+    /// Base ID of the removed rule, e.g. "auth.legacy-token"
+    pub id: String,
+
+    /// Base ID of the rule that replaces it, if any
+    #[facet(default)]
+    pub replaced_by: Option<String>,
+
+    /// Why the rule was removed
+    #[facet(default)]
+    pub reason: Option<String>,
+}
+
+/// Owners of the rules whose IDs start with a prefix, e.g.
+/// `{prefix payments., owners ("@payments" "@billing")}`
+#[derive(Debug, Clone, Facet)]
+pub struct OwnersConfig {
+    /// Rule ID prefix, e.g. "payments."
+    pub prefix: String,
+
+    /// Owners, e.g. "@payments"
+    pub owners: Vec<String>,
+}
+
+/// A verb declared by the project, e.g.
+/// `{name bench, counts verify, description "Benchmark exercising a requirement"}`
+#[derive(Debug, Clone, Facet)]
+pub struct VerbConfig {
+    /// The verb as written in references, e.g. "bench" for `r[bench auth.login]`
+    pub name: String,
+
+    /// Which coverage references with this verb count toward: "impl",
+    /// "verify", or neither when unset
+    #[facet(default)]
+    pub counts: Option<String>,
+
+    /// Whether references with this verb may appear in test files. Defaults
+    /// to false for verbs counting toward impl coverage, true otherwise.
+    #[facet(default)]
+    pub in_tests: Option<bool>,
+
+    /// Shown when completing the verb in an editor
+    #[facet(default)]
+    pub description: Option<String>,
+}
+
+/// Configuration for a single implementation of a spec
+#[derive(Debug, Clone, Default, Facet)]
 pub struct Impl {
     /// Name of this implementation (e.g., "main", "core", "frontend")
     /// r[impl config.impl.name]
@@ -66,4 +187,55 @@ pub struct Impl {
     /// r[impl config.impl.test_include]
     #[facet(default)]
     pub test_include: Vec<String>,
+
+    /// Coverage thresholds for this implementation, overriding the spec's
+    /// r[impl config.thresholds.impl]
+    #[facet(default)]
+    pub thresholds: Option<Thresholds>,
+
+    /// Test result files (JUnit XML, libtest/nextest JSON or `go test -json`
+    /// output) deciding whether `verify` references pass
+    /// r[impl config.impl.results]
+    #[facet(default)]
+    pub results: Vec<String>,
+
+    /// Runtime coverage files (lcov or `llvm-cov export` JSON) giving how
+    /// often each code unit ran
+    /// r[impl config.impl.runtime-coverage]
+    #[facet(default)]
+    pub runtime_coverage: Vec<String>,
+}
+
+/// Coverage thresholds, enforced by validation
+///
+/// All fields are optional; unset thresholds are not checked.
+#[derive(Debug, Clone, Default, Facet)]
+pub struct Thresholds {
+    /// Minimum percentage (0-100) of rules with an implementation reference
+    #[facet(default)]
+    pub min_impl: Option<f64>,
+
+    /// Minimum percentage (0-100) of rules with a verification reference
+    #[facet(default)]
+    pub min_verify: Option<f64>,
+
+    /// Maximum number of rules with stale references
+    #[facet(default)]
+    pub max_stale: Option<usize>,
+
+    /// Minimum percentage (0-100) of code units that reference at least one rule
+    /// (not checked for prefix thresholds)
+    #[facet(default)]
+    pub min_reverse: Option<f64>,
+}
+
+/// Coverage thresholds for the rules of a spec whose ID starts with a prefix
+#[derive(Debug, Clone, Facet)]
+pub struct PrefixThresholds {
+    /// Rule ID prefix, e.g. "auth." for all rules under `auth`
+    pub prefix: String,
+
+    /// Thresholds for the matching rules
+    #[facet(default)]
+    pub thresholds: Thresholds,
 }
@@ -0,0 +1,137 @@
+//! Reference attributes of the `tracey-macros` crate, such as
+//! `#[tracey::implements("auth.login")]` and
+//! `#[tracey::verifies("h2[stream.reset+2]")]`.
+//!
+//! The attribute macros check these against the spec at compile time; the
+//! extractor counts them as references, exactly like comments.
+
+use crate::{RefVerb, RuleId, parse_rule_id};
+
+/// Prefix of a reference attribute's ID when it doesn't name one.
+pub const DEFAULT_ATTRIBUTE_PREFIX: &str = "r";
+
+/// Crate paths the attributes may be written with: the `tracey` rename of
+/// `tracey-macros` recommended by the docs, and the crate's own name.
+const ATTRIBUTE_CRATES: &[&str] = &["tracey", "tracey_macros"];
+
+/// A requirement reference written as an attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeRef {
+    /// The prefix identifying which spec (e.g., "r", "h2")
+    pub prefix: String,
+    /// `impl` for `implements`, `verify` for `verifies`
+    pub verb: RefVerb,
+    /// The requirement ID
+    pub req_id: RuleId,
+    /// Byte offset of the ID's string literal contents in the attribute text
+    pub offset: usize,
+    /// Byte length of the ID's string literal contents
+    pub length: usize,
+}
+
+/// The verb of a reference attribute, by the name of its macro.
+pub fn attribute_verb(name: &str) -> Option<RefVerb> {
+    match name {
+        "implements" => Some(RefVerb::Impl),
+        "verifies" => Some(RefVerb::Verify),
+        _ => None,
+    }
+}
+
+/// Parse the argument of a reference attribute: a requirement ID with an
+/// optional version (`auth.login+2`), qualified by its spec's prefix when
+/// that isn't `r` (`h2[stream.reset]`).
+///
+/// r[impl ref.attributes.syntax]
+pub fn parse_attribute_reference(value: &str) -> Option<(String, RuleId)> {
+    let (prefix, id) = match value.split_once('[') {
+        Some((prefix, id)) => (prefix, id.strip_suffix(']')?),
+        None => (DEFAULT_ATTRIBUTE_PREFIX, value),
+    };
+    let valid_prefix = !prefix.is_empty()
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+    if !valid_prefix || !id.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return None;
+    }
+    let valid_chars = id.chars().all(|c| {
+        c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '-' | '_' | '+')
+    });
+    let req_id = parse_rule_id(id).filter(|_| valid_chars)?;
+    if !req_id.base.contains('.') || req_id.base.ends_with('.') {
+        return None;
+    }
+    Some((prefix.to_string(), req_id))
+}
+
+/// Parse the source text of a Rust attribute, such as
+/// `#[tracey::implements("auth.login")]`, as a reference. Returns `None` for
+/// any other attribute.
+///
+/// r[impl ref.attributes]
+pub fn parse_reference_attribute(text: &str) -> Option<AttributeRef> {
+    let inner = text.strip_prefix("#[")?.strip_suffix(']')?;
+    let open = inner.find('(')?;
+    let path: String = inner[..open].split_whitespace().collect();
+    let (krate, name) = path.trim_start_matches("::").split_once("::")?;
+    if !ATTRIBUTE_CRATES.contains(&krate) {
+        return None;
+    }
+    let verb = attribute_verb(name)?;
+
+    let args = &inner[open + 1..];
+    let literal = args.trim_start().strip_prefix('"')?;
+    let close = literal.find('"')?;
+    if literal[close + 1..].trim() != ")" {
+        return None;
+    }
+    let value = &literal[..close];
+    let (prefix, req_id) = parse_attribute_reference(value)?;
+
+    let offset = "#[".len() + open + 1 + (args.len() - literal.len());
+    Some(AttributeRef {
+        prefix,
+        verb,
+        req_id,
+        offset,
+        length: value.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_reference_attributes() {
+        let text = r#"#[tracey::implements("auth.login")]"#;
+        let attr = parse_reference_attribute(text).unwrap();
+        assert_eq!(attr.prefix, "r");
+        assert_eq!(attr.verb, RefVerb::Impl);
+        assert_eq!(attr.req_id, "auth.login");
+        assert_eq!(&text[attr.offset..attr.offset + attr.length], "auth.login");
+
+        let text = "#[ ::tracey_macros :: verifies ( \"h2[stream.reset+2]\" ) ]";
+        let attr = parse_reference_attribute(text).unwrap();
+        assert_eq!(attr.prefix, "h2");
+        assert_eq!(attr.verb, RefVerb::Verify);
+        assert_eq!(attr.req_id, "stream.reset+2");
+        assert_eq!(
+            &text[attr.offset..attr.offset + attr.length],
+            "h2[stream.reset+2]"
+        );
+
+        for other in [
+            r#"#[test]"#,
+            r#"#[doc = "r[impl auth.login]"]"#,
+            r#"#[other::implements("auth.login")]"#,
+            r#"#[tracey::depends("auth.login")]"#,
+            r#"#[tracey::implements("auth")]"#,
+            r#"#[tracey::implements("Auth.Login")]"#,
+            r#"#[tracey::implements("auth.login", extra)]"#,
+        ] {
+            assert!(parse_reference_attribute(other).is_none(), "{other}");
+        }
+    }
+}
@@ -14,7 +14,7 @@
 //! - Code added without updating the spec
 //! - Potential dead code or technical debt
 
-use crate::{RuleId, parse_rule_id};
+use crate::RuleId;
 use arborium::tree_sitter::{Node, Parser};
 use std::path::{Path, PathBuf};
 
@@ -37,6 +37,9 @@ pub struct CodeUnit {
     pub end_byte: usize,
     /// Requirement IDs referenced in comments associated with this code unit
     pub req_refs: Vec<RuleId>,
+    /// How often this code unit ran, according to runtime coverage data
+    /// (`None` without data, or if none of its lines were instrumented)
+    pub hits: Option<u64>,
 }
 
 /// The kind of code unit
@@ -718,6 +721,7 @@ fn extract_elixir_recursive(path: &Path, source: &str, node: Node, units: &mut C
             start_byte,
             end_byte: node.end_byte(),
             req_refs,
+            hits: None,
         });
     }
 
@@ -819,6 +823,7 @@ fn extract_clojure_recursive(path: &Path, source: &str, node: Node, units: &mut
             start_byte,
             end_byte: node.end_byte(),
             req_refs,
+            hits: None,
         });
     }
 
@@ -1124,6 +1129,7 @@ where
         start_byte,
         end_byte: node.end_byte(),
         req_refs,
+        hits: None,
     })
 }
 
@@ -1323,6 +1329,13 @@ fn collect_comment_refs(source: &str, node: Node, refs: &mut Vec<RuleId>) {
             extract_refs_from_comment_text(source, node, refs);
         }
         "attribute_item" | "decorator" => {
+            // r[impl ref.attributes]
+            if let Some(attr) = crate::parse_reference_attribute(&source[node.byte_range()]) {
+                if !refs.contains(&attr.req_id) {
+                    refs.push(attr.req_id);
+                }
+                return;
+            }
             // Could be a doc attribute or decorator, check children
             let mut cursor = node.walk();
             for child in node.children(&mut cursor) {
@@ -1345,7 +1358,8 @@ fn extract_refs_from_comment_text(source: &str, node: Node, refs: &mut Vec<RuleI
     }
 }
 
-/// Extract requirement IDs from comment text
+/// Extract requirement IDs from comment text. Code units are extracted
+/// without the project's config, so only built-in verbs are recognized.
 fn find_req_refs(text: &str) -> Vec<RuleId> {
     let mut refs = Vec::new();
     let code_mask = crate::markdown::markdown_code_mask(text);
@@ -1357,8 +1371,8 @@ fn find_req_refs(text: &str) -> Vec<RuleId> {
         }
         if ch == '[' {
             // Try to parse a requirement reference
-            if let Some(req_id) = try_parse_req_ref(&mut chars) {
-                refs.push(req_id);
+            if let Some(body) = crate::lexer::parse_ref_body(&mut chars, &[]) {
+                refs.push(body.req_id);
             }
         }
     }
@@ -1383,12 +1397,13 @@ pub struct FullReqRef {
     pub byte_length: usize,
 }
 
-/// Extract ALL requirement references from a file using tree-sitter
+/// Extract ALL requirement references from a file using tree-sitter, with
+/// built-in verbs or the declared `verbs`
 ///
 /// r[impl ref.parser.tree-sitter]
 /// r[impl ref.parser.languages]
 /// r[impl ref.parser.unified]
-pub fn extract_refs(path: &Path, source: &str) -> Vec<FullReqRef> {
+pub fn extract_refs(path: &Path, source: &str, verbs: &[String]) -> Vec<FullReqRef> {
     let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
     let language = match ext {
@@ -1434,7 +1449,13 @@ pub fn extract_refs(path: &Path, source: &str) -> Vec<FullReqRef> {
 
     let mut refs = Vec::new();
     let mut ignore_state = IgnoreState::default();
-    extract_refs_recursive(source, tree.root_node(), &mut refs, &mut ignore_state);
+    extract_refs_recursive(
+        source,
+        tree.root_node(),
+        verbs,
+        &mut refs,
+        &mut ignore_state,
+    );
     refs
 }
 
@@ -1495,6 +1516,7 @@ fn check_ignore_directives(text: &str, line: usize, state: &mut IgnoreState) ->
 fn extract_refs_recursive(
     source: &str,
     node: Node,
+    verbs: &[String],
     refs: &mut Vec<FullReqRef>,
     ignore_state: &mut IgnoreState,
 ) {
@@ -1526,14 +1548,32 @@ fn extract_refs_recursive(
 
         // Check ignore directives and determine if we should extract refs
         if check_ignore_directives(text, line, ignore_state) {
-            extract_full_refs_from_text(text, line, base_offset, refs);
+            extract_full_refs_from_text(text, line, base_offset, verbs, refs);
         }
     }
 
+    // Reference attributes count like comments, `#[tracey::implements("a.b")]`
+    // like `// r[impl a.b]`.
+    // r[impl ref.attributes]
+    if node.kind() == "attribute_item"
+        && !ignore_state.in_ignore_block
+        && let Some(attr) = crate::parse_reference_attribute(&source[node.byte_range()])
+    {
+        refs.push(FullReqRef {
+            prefix: attr.prefix,
+            verb: attr.verb.as_str().to_string(),
+            req_id: attr.req_id,
+            line: node.start_position().row + 1,
+            byte_offset: node.start_byte() + attr.offset,
+            byte_length: attr.length,
+        });
+        return;
+    }
+
     // Recurse into children
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        extract_refs_recursive(source, child, refs, ignore_state);
+        extract_refs_recursive(source, child, verbs, refs, ignore_state);
     }
 }
 
@@ -1542,6 +1582,7 @@ fn extract_full_refs_from_text(
     text: &str,
     line: usize,
     base_offset: usize,
+    verbs: &[String],
     refs: &mut Vec<FullReqRef>,
 ) {
     let code_mask = crate::markdown::markdown_code_mask(text);
@@ -1576,201 +1617,20 @@ fn extract_full_refs_from_text(
             chars.next(); // consume '['
 
             // Parse: [verb req.id] or [req.id]
-            if let Some((verb, req_id, end_idx)) = try_parse_full_ref(&mut chars) {
+            if let Some(body) = crate::lexer::parse_ref_body(&mut chars, verbs) {
                 refs.push(FullReqRef {
-                    prefix,
-                    verb,
-                    req_id,
+                    prefix: body.qualifier.unwrap_or(prefix),
+                    verb: body.verb.to_string(),
+                    req_id: body.req_id,
                     line,
                     byte_offset: base_offset + prefix_start,
-                    byte_length: end_idx - prefix_start + 1,
+                    byte_length: body.end - prefix_start + 1,
                 });
             }
         }
     }
 }
 
-// r[impl ref.syntax.req-id]
-fn try_parse_full_ref(
-    chars: &mut std::iter::Peekable<impl Iterator<Item = (usize, char)>>,
-) -> Option<(String, RuleId, usize)> {
-    // First char must be lowercase letter
-    let first_char = chars.peek().map(|(_, c)| *c)?;
-    if !first_char.is_ascii_lowercase() {
-        return None;
-    }
-
-    let mut first_word = String::new();
-    first_word.push(first_char);
-    chars.next();
-
-    // Read the first word
-    let mut end_idx = 0;
-    while let Some(&(idx, c)) = chars.peek() {
-        end_idx = idx;
-        if c == ']' || c == ' ' {
-            break;
-        } else if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.' || c == '+' {
-            first_word.push(c);
-            chars.next();
-        } else {
-            return None;
-        }
-    }
-
-    // Check what follows
-    match chars.peek().map(|(_, c)| *c) {
-        Some(' ') => {
-            // Might be [verb req.id]
-            let verbs = ["impl", "verify", "define", "depends", "related"];
-            if verbs.contains(&first_word.as_str()) {
-                let verb = first_word;
-                chars.next(); // consume space
-
-                // Read the requirement ID
-                let mut req_id = String::new();
-                let mut has_dot = false;
-
-                // First char must be lowercase
-                if let Some(&(_, c)) = chars.peek() {
-                    if c.is_ascii_lowercase() {
-                        req_id.push(c);
-                        chars.next();
-                    } else {
-                        return None;
-                    }
-                }
-
-                while let Some(&(idx, c)) = chars.peek() {
-                    end_idx = idx;
-                    if c == ']' {
-                        chars.next();
-                        break;
-                    } else if c.is_ascii_lowercase()
-                        || c.is_ascii_digit()
-                        || c == '-'
-                        || c == '_'
-                        || c == '+'
-                    {
-                        req_id.push(c);
-                        chars.next();
-                    } else if c == '.' {
-                        has_dot = true;
-                        req_id.push(c);
-                        chars.next();
-                    } else {
-                        return None;
-                    }
-                }
-
-                if has_dot && is_valid_req_id(&req_id) {
-                    return parse_rule_id(&req_id).map(|parsed| (verb, parsed, end_idx));
-                }
-            }
-            None
-        }
-        Some(']') => {
-            chars.next(); // consume ]
-            // [req.id] format - defaults to impl
-            if is_valid_req_id(&first_word) {
-                parse_rule_id(&first_word).map(|parsed| ("impl".to_string(), parsed, end_idx))
-            } else {
-                None
-            }
-        }
-        _ => None,
-    }
-}
-
-fn try_parse_req_ref(
-    chars: &mut std::iter::Peekable<impl Iterator<Item = (usize, char)>>,
-) -> Option<RuleId> {
-    // First char must be lowercase letter
-    let first_char = chars.peek().map(|(_, c)| *c)?;
-    if !first_char.is_ascii_lowercase() {
-        return None;
-    }
-
-    let mut first_word = String::new();
-    first_word.push(first_char);
-    chars.next();
-
-    // Read the first word
-    while let Some(&(_, c)) = chars.peek() {
-        if c == ']' || c == ' ' {
-            break;
-        } else if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.' || c == '+' {
-            first_word.push(c);
-            chars.next();
-        } else {
-            return None;
-        }
-    }
-
-    // Check what follows
-    match chars.peek().map(|(_, c)| *c) {
-        Some(' ') => {
-            // Might be [verb req.id]
-            let verbs = ["impl", "verify", "define", "depends", "related"];
-            if verbs.contains(&first_word.as_str()) {
-                chars.next(); // consume space
-
-                // Read the requirement ID
-                let mut req_id = String::new();
-                let mut has_dot = false;
-
-                // First char must be lowercase
-                if let Some(&(_, c)) = chars.peek() {
-                    if c.is_ascii_lowercase() {
-                        req_id.push(c);
-                        chars.next();
-                    } else {
-                        return None;
-                    }
-                }
-
-                while let Some(&(_, c)) = chars.peek() {
-                    if c == ']' {
-                        chars.next();
-                        break;
-                    } else if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '+' {
-                        req_id.push(c);
-                        chars.next();
-                    } else if c == '.' {
-                        has_dot = true;
-                        req_id.push(c);
-                        chars.next();
-                    } else {
-                        return None;
-                    }
-                }
-
-                if has_dot && is_valid_req_id(&req_id) {
-                    return parse_rule_id(&req_id);
-                }
-            }
-            None
-        }
-        Some(']') => {
-            chars.next(); // consume ]
-            // [req.id] format - must contain dot
-            if is_valid_req_id(&first_word) {
-                parse_rule_id(&first_word)
-            } else {
-                None
-            }
-        }
-        _ => None,
-    }
-}
-
-fn is_valid_req_id(req_id: &str) -> bool {
-    let Some(parsed) = parse_rule_id(req_id) else {
-        return false;
-    };
-    parsed.base.contains('.') && !parsed.base.ends_with('.')
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1786,7 +1646,7 @@ mod tests {
 /// Implements r[channel.id.parity] and r[channel.id.no-reuse]
 fn next_channel_id() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert_eq!(refs.len(), 2, "Expected 2 refs, got {:?}", refs);
         assert_eq!(refs[0].req_id, "channel.id.parity");
         assert_eq!(refs[1].req_id, "channel.id.no-reuse");
@@ -1798,12 +1658,47 @@ fn next_channel_id() {}
 // r[impl foo.bar]
 fn do_thing() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert_eq!(refs.len(), 1, "Expected 1 ref, got {:?}", refs);
         assert_eq!(refs[0].req_id, "foo.bar");
         assert_eq!(refs[0].verb, "impl");
     }
 
+    #[test]
+    fn test_extract_refs_attribute() {
+        let source = r#"
+#[tracey::implements("auth.login")]
+fn login() {}
+
+// r[verify auth.login]
+#[test]
+#[tracey::verifies("h2[stream.reset+2]")]
+fn login_works() {}
+"#;
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
+        let found: Vec<_> = refs
+            .iter()
+            .map(|r| (r.prefix.as_str(), r.verb.as_str(), r.req_id.to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("r", "impl", "auth.login".to_string()),
+                ("r", "verify", "auth.login".to_string()),
+                ("h2", "verify", "stream.reset+2".to_string()),
+            ]
+        );
+        let span = &source[refs[0].byte_offset..refs[0].byte_offset + refs[0].byte_length];
+        assert_eq!(span, "auth.login");
+
+        let units = extract_rust(Path::new("test.rs"), source);
+        assert_eq!(units.units[0].req_refs, vec![rid("auth.login")]);
+        assert_eq!(
+            units.units[1].req_refs,
+            vec![rid("stream.reset+2"), rid("auth.login")]
+        );
+    }
+
     #[test]
     fn test_extract_function() {
         let source = r#"
@@ -2346,7 +2241,7 @@ enum MyEnum {
 // This comment mentions r[impl auth.login] but it should be ignored
 fn example() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert!(
             refs.is_empty(),
             "Expected no refs due to ignore-next-line, got {:?}",
@@ -2362,7 +2257,7 @@ fn example() {}
 // But this r[impl visible.ref] should be extracted
 fn example() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert_eq!(refs.len(), 1, "Expected 1 ref, got {:?}", refs);
         assert_eq!(refs[0].req_id, "visible.ref");
     }
@@ -2376,7 +2271,7 @@ fn example() {}
 // @tracey:ignore-end
 fn test_validation() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert!(
             refs.is_empty(),
             "Expected no refs due to ignore block, got {:?}",
@@ -2394,7 +2289,7 @@ fn test_validation() {}
 // But this r[impl visible.ref] should be extracted
 fn example() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert_eq!(refs.len(), 1, "Expected 1 ref, got {:?}", refs);
         assert_eq!(refs[0].req_id, "visible.ref");
     }
@@ -2408,7 +2303,7 @@ fn example() {}
 // @tracey:ignore-end
 fn example() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert_eq!(refs.len(), 1, "Expected 1 ref, got {:?}", refs);
         assert_eq!(refs[0].req_id, "before.ref");
     }
@@ -2419,7 +2314,7 @@ fn example() {}
 // r[impl normal.ref]
 fn example() {}
 "#;
-        let refs = extract_refs(Path::new("test.rs"), source);
+        let refs = extract_refs(Path::new("test.rs"), source, &[]);
         assert_eq!(refs.len(), 1, "Expected 1 ref, got {:?}", refs);
         assert_eq!(refs[0].req_id, "normal.ref");
     }
@@ -2498,7 +2393,7 @@ void* alloc_buffer(size_t size) {
     return malloc(size);
 }
 "#;
-        let refs = extract_refs(Path::new("alloc.c"), source);
+        let refs = extract_refs(Path::new("alloc.c"), source, &[]);
         assert_eq!(refs.len(), 1);
         assert_eq!(refs[0].req_id, "buffer.alloc");
         assert_eq!(refs[0].verb, "impl");
@@ -2617,7 +2512,7 @@ void render() {
     // rendering logic
 }
 "#;
-        let refs = extract_refs(Path::new("widget.cpp"), source);
+        let refs = extract_refs(Path::new("widget.cpp"), source, &[]);
         assert_eq!(refs.len(), 2);
         assert_eq!(refs[0].req_id, "widget.render");
         assert_eq!(refs[1].req_id, "ui.framework");
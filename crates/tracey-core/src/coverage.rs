@@ -59,7 +59,7 @@ impl CoverageReport {
 
                 // Also group by verb
                 references_by_verb
-                    .entry(reference.verb)
+                    .entry(reference.verb.clone())
                     .or_default()
                     .entry(reference.req_id.clone())
                     .or_default()
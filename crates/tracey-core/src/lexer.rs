@@ -3,10 +3,8 @@
 //! This module implements parsing of rule references from Rust source code.
 //! It scans comments for patterns like `r[verb rule.id]`.
 
-use crate::RuleId;
-#[cfg(not(feature = "reverse"))]
-use crate::parse_rule_id;
 use crate::sources::{ExtractionResult, Sources};
+use crate::{RuleId, parse_rule_id};
 use eyre::Result;
 use facet::Facet;
 use std::path::{Path, PathBuf};
@@ -35,7 +33,7 @@ impl SourceSpan {
 /// r[impl ref.verb.verify]
 /// r[impl ref.verb.depends]
 /// r[impl ref.verb.related]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Facet)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Facet)]
 #[repr(u8)]
 pub enum RefVerb {
     /// Where the requirement is defined (typically in specs/docs)
@@ -48,10 +46,12 @@ pub enum RefVerb {
     Depends,
     /// Loose connection - show when reviewing
     Related,
+    /// A verb declared in the project's config, e.g. `bench`
+    Custom(String),
 }
 
 impl RefVerb {
-    /// Parse a verb from its string representation
+    /// Parse a built-in verb from its string representation
     pub fn parse(s: &str) -> Option<Self> {
         match s {
             "define" => Some(RefVerb::Define),
@@ -63,14 +63,29 @@ impl RefVerb {
         }
     }
 
+    /// Parse a built-in verb or one of the `custom` verbs declared by the
+    /// project. Any other word isn't a verb, so prose like `[payload bytes]`
+    /// isn't mistaken for a reference.
+    ///
+    /// r[impl ref.verb.custom+2]
+    pub fn parse_with(s: &str, custom: &[String]) -> Option<Self> {
+        Self::parse(s).or_else(|| {
+            custom
+                .iter()
+                .any(|verb| verb == s)
+                .then(|| RefVerb::Custom(s.to_string()))
+        })
+    }
+
     /// Get the string representation of this verb
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             RefVerb::Define => "define",
             RefVerb::Impl => "impl",
             RefVerb::Verify => "verify",
             RefVerb::Depends => "depends",
             RefVerb::Related => "related",
+            RefVerb::Custom(verb) => verb,
         }
     }
 }
@@ -155,10 +170,11 @@ impl Reqs {
         sources.extract()
     }
 
-    /// Extract requirements from raw content (no I/O)
-    pub fn extract_from_content(path: &Path, content: &str) -> Self {
+    /// Extract requirements from raw content (no I/O), recognizing the
+    /// project's declared `verbs` besides the built-in ones
+    pub fn extract_from_content(path: &Path, content: &str, verbs: &[String]) -> Self {
         let mut reqs = Reqs::new();
-        extract_from_content(path, content, &mut reqs);
+        extract_from_content(path, content, verbs, &mut reqs);
         reqs
     }
 
@@ -173,21 +189,16 @@ impl Reqs {
 ///
 /// When the "reverse" feature is enabled, this uses tree-sitter for proper
 /// comment parsing. Otherwise, falls back to text-based scanning.
-pub(crate) fn extract_from_content(path: &Path, content: &str, reqs: &mut Reqs) {
+pub(crate) fn extract_from_content(path: &Path, content: &str, verbs: &[String], reqs: &mut Reqs) {
     #[cfg(feature = "reverse")]
     {
         // Use tree-sitter based extraction
         // r[impl ref.comments.line]
         // r[impl ref.comments.doc]
         // r[impl ref.comments.block]
-        for full_ref in crate::code_units::extract_refs(path, content) {
-            let verb = match full_ref.verb.as_str() {
-                "define" => RefVerb::Define,
-                "impl" => RefVerb::Impl,
-                "verify" => RefVerb::Verify,
-                "depends" => RefVerb::Depends,
-                "related" => RefVerb::Related,
-                _ => continue,
+        for full_ref in crate::code_units::extract_refs(path, content, verbs) {
+            let Some(verb) = RefVerb::parse_with(&full_ref.verb, verbs) else {
+                continue;
             };
             reqs.references.push(ReqReference {
                 prefix: full_ref.prefix,
@@ -203,7 +214,7 @@ pub(crate) fn extract_from_content(path: &Path, content: &str, reqs: &mut Reqs)
     #[cfg(not(feature = "reverse"))]
     {
         // Fallback: text-based scanning
-        extract_from_content_text_based(path, content, reqs);
+        extract_from_content_text_based(path, content, verbs, reqs);
     }
 }
 
@@ -261,7 +272,7 @@ fn check_ignore_directives(text: &str, line: usize, state: &mut IgnoreState) ->
 }
 
 #[cfg(not(feature = "reverse"))]
-fn extract_from_content_text_based(path: &Path, content: &str, reqs: &mut Reqs) {
+fn extract_from_content_text_based(path: &Path, content: &str, verbs: &[String], reqs: &mut Reqs) {
     // Track line starts for computing line numbers from byte offsets
     let line_starts: Vec<usize> = std::iter::once(0)
         .chain(content.match_indices('\n').map(|(i, _)| i + 1))
@@ -281,6 +292,23 @@ fn extract_from_content_text_based(path: &Path, content: &str, reqs: &mut Reqs)
         let line_num = line_idx + 1;
         let line_start = line_starts.get(line_idx).copied().unwrap_or(0);
 
+        // r[impl ref.attributes]
+        let attribute = line.trim();
+        if attribute.starts_with("#[")
+            && !ignore_state.in_ignore_block
+            && let Some(attr) = crate::parse_reference_attribute(attribute)
+        {
+            let indent = line.len() - line.trim_start().len();
+            reqs.references.push(ReqReference {
+                prefix: attr.prefix,
+                verb: attr.verb,
+                req_id: attr.req_id,
+                file: path.to_path_buf(),
+                line: line_num,
+                span: SourceSpan::new(line_start + indent + attr.offset, attr.length),
+            });
+        }
+
         // Check for line comments (// or ///)
         if let Some(comment_pos) = line.find("//") {
             let comment = &line[comment_pos..];
@@ -288,7 +316,7 @@ fn extract_from_content_text_based(path: &Path, content: &str, reqs: &mut Reqs)
 
             // Check ignore directives before extracting
             if check_ignore_directives(comment, line_num, &mut ignore_state) {
-                extract_references_from_text(path, comment, comment_start, line_num, reqs);
+                extract_references_from_text(path, comment, comment_start, line_num, verbs, reqs);
             }
         }
     }
@@ -311,6 +339,7 @@ fn extract_from_content_text_based(path: &Path, content: &str, reqs: &mut Reqs)
                         block_content,
                         block_start,
                         block_line,
+                        verbs,
                         reqs,
                     );
                 }
@@ -336,6 +365,7 @@ fn extract_references_from_text(
     text: &str,
     text_offset: usize,
     base_line: usize,
+    verbs: &[String],
     reqs: &mut Reqs,
 ) {
     let code_mask = crate::markdown::markdown_code_mask(text);
@@ -376,139 +406,145 @@ fn extract_references_from_text(
             }
 
             let bracket_start = text_offset + prefix_start;
+            if let Some(body) = parse_ref_body(&mut chars, verbs) {
+                reqs.references.push(ReqReference {
+                    prefix: body.qualifier.unwrap_or(prefix),
+                    verb: body.verb,
+                    req_id: body.req_id,
+                    file: path.to_path_buf(),
+                    line: base_line,
+                    span: SourceSpan::new(bracket_start, body.end - prefix_start + 1),
+                });
+            }
+        }
+    }
+}
 
-            // Try to parse: r[verb rule.id] or r[rule.id]
-            let mut first_word = String::new();
-            let mut valid = true;
+/// A reference as read by [`parse_ref_body`]
+pub(crate) struct RefBody {
+    pub verb: RefVerb,
+    /// Prefix of another spec qualifying a `depends` target, as in
+    /// `[depends t[transport.framing]]`
+    pub qualifier: Option<String>,
+    pub req_id: RuleId,
+    /// Index of the closing bracket
+    pub end: usize,
+}
 
-            // First char must be lowercase letter
-            if let Some(&(_, first_char)) = chars.peek() {
-                if first_char.is_ascii_lowercase() {
-                    first_word.push(first_char);
-                    chars.next();
-                } else {
-                    valid = false;
-                }
-            } else {
-                valid = false;
+/// Parse the rest of a reference after its `[`: `verb req.id]` or
+/// `req.id]`, where the verb is built in or one of the declared `verbs`.
+/// Leaves `chars` wherever the reference stopped matching.
+///
+/// r[impl ref.syntax.verb]
+/// r[impl ref.syntax.qualified]
+/// r[impl ref.verb.default]
+pub(crate) fn parse_ref_body(
+    chars: &mut std::iter::Peekable<impl Iterator<Item = (usize, char)>>,
+    verbs: &[String],
+) -> Option<RefBody> {
+    // First char must be lowercase letter
+    let first_char = chars.peek().map(|(_, c)| *c)?;
+    if !first_char.is_ascii_lowercase() {
+        return None;
+    }
+
+    let mut first_word = String::new();
+    first_word.push(first_char);
+    chars.next();
+
+    // Read the first word (could be verb or start of rule ID)
+    let mut end = 0;
+    while let Some(&(idx, c)) = chars.peek() {
+        end = idx;
+        if c == ']' || c == ' ' {
+            break;
+        } else if is_req_id_char(c) || c == '.' {
+            first_word.push(c);
+            chars.next();
+        } else {
+            return None;
+        }
+    }
+
+    match chars.peek().map(|(_, c)| *c) {
+        Some(' ') => {
+            // Space after first word - might be [verb req.id]. Other words,
+            // like `payload` in [payload bytes], are ignored.
+            let verb = RefVerb::parse_with(&first_word, verbs)?;
+            chars.next(); // consume space
+
+            if !chars.peek().is_some_and(|(_, c)| c.is_ascii_lowercase()) {
+                return None;
             }
 
-            if valid {
-                // Read the first word (could be verb or start of rule ID)
-                // r[impl ref.syntax.req-id]
-                while let Some(&(_, c)) = chars.peek() {
-                    if c == ']' || c == ' ' {
-                        break;
-                    } else if c.is_ascii_lowercase()
-                        || c.is_ascii_digit()
-                        || c == '-'
-                        || c == '.'
-                        || c == '+'
-                    {
-                        first_word.push(c);
+            let mut req_id = String::new();
+            let mut qualifier: Option<String> = None;
+            while let Some(&(idx, c)) = chars.peek() {
+                end = idx;
+                if c == ']' {
+                    chars.next();
+                    if qualifier.is_some() {
+                        // A qualified target closes twice: `t[...]]`
+                        let &(idx, ']') = chars.peek()? else {
+                            return None;
+                        };
+                        end = idx;
                         chars.next();
-                    } else {
-                        valid = false;
-                        break;
                     }
+                    break;
+                } else if c == '['
+                    && verb == RefVerb::Depends
+                    && qualifier.is_none()
+                    && req_id
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                {
+                    // What was read so far is the prefix of another spec
+                    qualifier = Some(std::mem::take(&mut req_id));
+                    chars.next();
+                    if !chars.peek().is_some_and(|(_, c)| c.is_ascii_lowercase()) {
+                        return None;
+                    }
+                } else if is_req_id_char(c) || c == '.' {
+                    req_id.push(c);
+                    chars.next();
+                } else {
+                    return None;
                 }
             }
 
-            if !valid || first_word.is_empty() {
-                continue;
+            if !is_valid_req_id(&req_id) {
+                return None;
             }
-
-            // Check what follows
-            if let Some(&(end_idx, next_char)) = chars.peek() {
-                if next_char == ' ' {
-                    // Space after first word - might be r[verb rule.id]
-                    // r[impl ref.syntax.verb]
-                    if let Some(verb) = RefVerb::parse(&first_word) {
-                        chars.next(); // consume space
-
-                        // Now read the rule ID
-                        let mut req_id = String::new();
-                        let mut found_dot = false;
-
-                        // First char of rule ID must be lowercase letter
-                        if let Some(&(_, c)) = chars.peek() {
-                            if c.is_ascii_lowercase() {
-                                req_id.push(c);
-                                chars.next();
-                            } else {
-                                continue; // invalid, skip
-                            }
-                        }
-
-                        // Continue reading rule ID
-                        let mut final_idx = end_idx;
-                        while let Some(&(idx, c)) = chars.peek() {
-                            final_idx = idx;
-                            if c == ']' {
-                                chars.next();
-                                break;
-                            } else if c.is_ascii_lowercase()
-                                || c.is_ascii_digit()
-                                || c == '-'
-                                || c == '+'
-                            {
-                                req_id.push(c);
-                                chars.next();
-                            } else if c == '.' {
-                                found_dot = true;
-                                req_id.push(c);
-                                chars.next();
-                            } else {
-                                break; // invalid char
-                            }
-                        }
-
-                        // Validate rule ID
-                        if found_dot && is_valid_req_id(&req_id) {
-                            let span = SourceSpan::new(bracket_start, final_idx - prefix_start + 1);
-                            if let Some(rule_id) = parse_rule_id(&req_id) {
-                                reqs.references.push(ReqReference {
-                                    prefix: prefix.clone(),
-                                    verb,
-                                    req_id: rule_id,
-                                    file: path.to_path_buf(),
-                                    line: base_line,
-                                    span,
-                                });
-                            }
-                        }
-                    } else {
-                        // Not a known verb - just ignore it. We only match rule
-                        // references with known verbs: impl, verify, define, depends, related
-                        // This avoids false positives on things like [payload bytes]
-                    }
-                } else if next_char == ']' {
-                    // Immediate close - this is r[rule.id] format (defaults to impl)
-                    // r[impl ref.verb.default]
-                    chars.next(); // consume ]
-
-                    // Validate: must contain dot, not end with dot
-                    if is_valid_req_id(&first_word) {
-                        let span = SourceSpan::new(bracket_start, end_idx - prefix_start + 1);
-                        if let Some(rule_id) = parse_rule_id(&first_word) {
-                            reqs.references.push(ReqReference {
-                                prefix: prefix.clone(),
-                                verb: RefVerb::Impl, // default to impl
-                                req_id: rule_id,
-                                file: path.to_path_buf(),
-                                line: base_line,
-                                span,
-                            });
-                        }
-                    }
-                }
+            Some(RefBody {
+                verb,
+                qualifier,
+                req_id: parse_rule_id(&req_id)?,
+                end,
+            })
+        }
+        Some(']') => {
+            // Immediate close - [req.id] defaults to impl
+            chars.next(); // consume ]
+            if !is_valid_req_id(&first_word) {
+                return None;
             }
+            Some(RefBody {
+                verb: RefVerb::Impl,
+                qualifier: None,
+                req_id: parse_rule_id(&first_word)?,
+                end,
+            })
         }
+        _ => None,
     }
 }
 
+fn is_req_id_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_' || c == '+'
+}
+
 // r[impl ref.syntax.req-id+2]
-#[cfg(not(feature = "reverse"))]
 fn is_valid_req_id(req_id: &str) -> bool {
     let Some(parsed) = parse_rule_id(req_id) else {
         return false;
@@ -527,7 +563,7 @@ mod tests {
             fn allocate_id() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 1);
         assert_eq!(reqs.references[0].prefix, "r");
         assert_eq!(reqs.references[0].req_id, "channel.id.allocation");
@@ -554,7 +590,7 @@ mod tests {
             // This is where we define the format
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 5);
 
         assert_eq!(reqs.references[0].verb, RefVerb::Impl);
@@ -573,6 +609,22 @@ mod tests {
         assert_eq!(reqs.references[4].req_id, "channel.id.format");
     }
 
+    #[test]
+    fn test_extract_qualified_depends() {
+        let content = r#"
+            // r[depends t[transport.framing]] and r[impl msg.frame]
+            fn frame() {}
+        "#;
+
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
+        assert_eq!(reqs.len(), 2);
+        assert_eq!(reqs.references[0].prefix, "t");
+        assert_eq!(reqs.references[0].verb, RefVerb::Depends);
+        assert_eq!(reqs.references[0].req_id, "transport.framing");
+        assert_eq!(reqs.references[1].prefix, "r");
+        assert_eq!(reqs.references[1].req_id, "msg.frame");
+    }
+
     #[test]
     fn test_extract_multiple_references() {
         let content = r#"
@@ -580,7 +632,7 @@ mod tests {
             fn next_channel_id() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 2);
         assert_eq!(reqs.references[0].req_id, "channel.id.parity");
         assert_eq!(reqs.references[1].req_id, "channel.id.no-reuse");
@@ -593,7 +645,7 @@ mod tests {
             fn foo() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 2);
         assert_eq!(reqs.references[0].req_id, "channel.id.one");
         assert_eq!(reqs.references[0].verb, RefVerb::Impl);
@@ -609,7 +661,7 @@ mod tests {
             fn foo() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 2);
         assert_eq!(reqs.references[0].req_id, "auth.login+2");
         assert_eq!(reqs.references[1].req_id, "auth.session+3");
@@ -623,7 +675,7 @@ mod tests {
             fn foo() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 0);
     }
 
@@ -635,7 +687,7 @@ mod tests {
             fn foo() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 0);
     }
 
@@ -644,16 +696,40 @@ mod tests {
         // Unknown verbs are silently ignored to avoid false positives
         // on things like [payload bytes] in documentation
         let content = r#"
-            // [frobnicate rule.id]
+            // r[frobnicate rule.id]
             // [payload bytes]
+            // x[payload bytes.len]
             fn foo() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 0);
         assert_eq!(reqs.warnings.len(), 0); // No warnings for unknown verbs
     }
 
+    #[test]
+    fn test_custom_verb_extracted() {
+        let content = r#"
+            // r[bench auth.login]
+            // r[fuzz-target auth.token+2]
+            // r[sketch auth.session]
+            fn foo() {}
+        "#;
+
+        let declared = ["bench".to_string(), "fuzz-target".to_string()];
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &declared);
+        let verbs: Vec<_> = reqs.references.iter().map(|r| r.verb.clone()).collect();
+        assert_eq!(
+            verbs,
+            [
+                RefVerb::Custom("bench".to_string()),
+                RefVerb::Custom("fuzz-target".to_string()),
+            ]
+        );
+        assert_eq!(reqs.references[1].req_id, "auth.token+2");
+        assert_eq!(RefVerb::Custom("bench".to_string()).to_string(), "bench");
+    }
+
     #[test]
     fn test_verb_display() {
         assert_eq!(RefVerb::Impl.to_string(), "impl");
@@ -666,7 +742,7 @@ mod tests {
     #[test]
     fn test_span_tracking() {
         let content = "// r[impl foo.bar]";
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 1);
         assert_eq!(reqs.references[0].prefix, "r");
         assert_eq!(reqs.references[0].span.offset, 3); // after "// ", points to 'r'
@@ -680,7 +756,7 @@ mod tests {
             fn test() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 2);
         assert_eq!(reqs.references[0].prefix, "h2");
         assert_eq!(reqs.references[0].req_id, "stream.priority");
@@ -697,7 +773,7 @@ mod tests {
             `;
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.tsx"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.tsx"), content, &[]);
         assert_eq!(reqs.len(), 1);
         assert_eq!(reqs.references[0].prefix, "r");
         assert_eq!(reqs.references[0].verb, RefVerb::Impl);
@@ -712,7 +788,7 @@ mod tests {
             fn test() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 1);
         assert_eq!(reqs.references[0].req_id, "visible.ref");
     }
@@ -729,7 +805,7 @@ mod tests {
             fn test() {}
         "#;
 
-        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content);
+        let reqs = Reqs::extract_from_content(Path::new("test.rs"), content, &[]);
         assert_eq!(reqs.len(), 1);
         assert_eq!(reqs.references[0].req_id, "visible.ref");
     }
@@ -4,6 +4,7 @@
 //! - Extracting requirement references from source code (Rust, Swift, TypeScript, and more)
 //! - Computing coverage statistics
 
+mod attributes;
 mod coverage;
 mod lexer;
 mod markdown;
@@ -14,17 +15,21 @@ mod spec;
 #[cfg(feature = "reverse")]
 pub mod code_units;
 
+pub use attributes::{
+    AttributeRef, DEFAULT_ATTRIBUTE_PREFIX, attribute_verb, parse_attribute_reference,
+    parse_reference_attribute,
+};
 pub use coverage::CoverageReport;
 pub use lexer::{ParseWarning, RefVerb, ReqReference, Reqs, SourceSpan, WarningKind};
 pub use rule_id::{
     RuleId, RuleIdMatch, classify_reference_for_rule, classify_reference_for_rule_str,
-    parse_rule_id,
+    classify_reference_with_aliases, parse_rule_id,
 };
 pub use sources::{
-    ExtractionResult, MemorySources, PathSources, SUPPORTED_EXTENSIONS, Sources,
+    ExtractionResult, MemorySources, PathSources, SUPPORTED_EXTENSIONS, Sources, glob_base_dir,
     is_supported_extension,
 };
-pub use spec::ReqDefinition;
+pub use spec::{ReqDefinition, marker_attribute, marker_replaces};
 
 #[cfg(feature = "walk")]
 pub use sources::WalkSources;
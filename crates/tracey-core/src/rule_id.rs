@@ -68,6 +68,8 @@ pub enum RuleIdMatch {
     Exact,
     /// Same base ID but reference points to an older version.
     Stale,
+    /// Reference uses an ID the rule replaces (see `replaces=`).
+    Renamed,
     /// Different base ID or newer-version reference.
     NoMatch,
 }
@@ -108,6 +110,25 @@ pub fn classify_reference_for_rule(rule_id: &RuleId, reference_id: &RuleId) -> R
     }
 }
 
+/// Compare a reference against a rule and the IDs it replaces.
+///
+/// Replaced IDs match by base, whatever the version of the reference: the
+/// rule that replaced them has its own version history.
+///
+/// r[impl ref.alias]
+pub fn classify_reference_with_aliases(
+    rule_id: &RuleId,
+    aliases: &[RuleId],
+    reference_id: &RuleId,
+) -> RuleIdMatch {
+    match classify_reference_for_rule(rule_id, reference_id) {
+        RuleIdMatch::NoMatch if aliases.iter().any(|a| a.base == reference_id.base) => {
+            RuleIdMatch::Renamed
+        }
+        other => other,
+    }
+}
+
 /// Parse and compare string rule IDs.
 pub fn classify_reference_for_rule_str(rule_id: &str, reference_id: &str) -> RuleIdMatch {
     let Some(rule) = parse_rule_id(rule_id) else {
@@ -174,6 +195,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn classify_reference_detects_renamed() {
+        let rule = parse_rule_id("auth.session").expect("must parse");
+        let aliases = [parse_rule_id("auth.token").expect("must parse")];
+        let old = parse_rule_id("auth.token+3").expect("must parse");
+        let current = parse_rule_id("auth.session").expect("must parse");
+        let other = parse_rule_id("auth.logout").expect("must parse");
+        assert_eq!(
+            classify_reference_with_aliases(&rule, &aliases, &old),
+            RuleIdMatch::Renamed
+        );
+        assert_eq!(
+            classify_reference_with_aliases(&rule, &aliases, &current),
+            RuleIdMatch::Exact
+        );
+        assert_eq!(
+            classify_reference_with_aliases(&rule, &aliases, &other),
+            RuleIdMatch::NoMatch
+        );
+    }
+
     #[test]
     fn classify_reference_detects_no_match() {
         let rule = parse_rule_id("auth.login+2").expect("must parse");
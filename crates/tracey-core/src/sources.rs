@@ -3,9 +3,7 @@
 use crate::lexer::{Reqs, extract_from_content};
 use eyre::Result;
 use std::ffi::OsStr;
-#[cfg(feature = "walk")]
-use std::path::Path;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// r[impl ref.cross-workspace.missing-paths]
 /// Result of extracting requirements, including any warnings about missing files
@@ -89,9 +87,42 @@ pub fn is_supported_extension(ext: &OsStr) -> bool {
         .unwrap_or(false)
 }
 
+/// The directory files matching a glob pattern are under: the longest path
+/// prefix before any glob metacharacter.
+///
+/// # Examples
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use tracey_core::glob_base_dir;
+/// assert_eq!(glob_base_dir("docs/spec/**/*.md"), PathBuf::from("docs/spec"));
+/// assert_eq!(glob_base_dir("src/*.rs"), PathBuf::from("src"));
+/// assert_eq!(glob_base_dir("*.rs"), PathBuf::from("."));
+/// ```
+pub fn glob_base_dir(pattern: &str) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in Path::new(pattern).components() {
+        let s = component.as_os_str().to_string_lossy();
+        // Stop at the first component containing glob metacharacters
+        if s.contains('*') || s.contains('?') || s.contains('[') || s.contains('{') {
+            break;
+        }
+        result.push(component);
+    }
+
+    // If no prefix was found (pattern starts with glob), use the current directory
+    if result.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        result
+    }
+}
+
 /// Trait for providing source files to extract requirements from
 pub trait Sources {
-    /// Extract requirements from all sources
+    /// Extract requirements from all sources. Only built-in verbs are
+    /// recognized.
     fn extract(self) -> Result<ExtractionResult>;
 }
 
@@ -117,7 +148,7 @@ impl Sources for PathSources {
             self.0.par_iter().try_for_each(|path| -> Result<()> {
                 let content = std::fs::read_to_string(path)?;
                 let mut file_reqs = Reqs::new();
-                extract_from_content(path, &content, &mut file_reqs);
+                extract_from_content(path, &content, &[], &mut file_reqs);
 
                 let mut guard = reqs_mutex.lock().unwrap();
                 guard.extend(file_reqs);
@@ -135,7 +166,7 @@ impl Sources for PathSources {
             let mut reqs = Reqs::new();
             for path in self.0 {
                 let content = std::fs::read_to_string(&path)?;
-                extract_from_content(&path, &content, &mut reqs);
+                extract_from_content(&path, &content, &[], &mut reqs);
             }
             Ok(ExtractionResult {
                 reqs,
@@ -171,7 +202,7 @@ impl Sources for MemorySources {
     fn extract(self) -> Result<ExtractionResult> {
         let mut reqs = Reqs::new();
         for (path, content) in self.0 {
-            extract_from_content(&path, &content, &mut reqs);
+            extract_from_content(&path, &content, &[], &mut reqs);
         }
         Ok(ExtractionResult {
             reqs,
@@ -277,7 +308,7 @@ impl Sources for WalkSources {
                     // Read and extract
                     if let Ok(content) = std::fs::read_to_string(path) {
                         let mut file_reqs = Reqs::new();
-                        extract_from_content(path, &content, &mut file_reqs);
+                        extract_from_content(path, &content, &[], &mut file_reqs);
 
                         let mut guard = reqs_ref.lock().unwrap();
                         guard.extend(file_reqs);
@@ -1,3 +1,52 @@
-//! Req definitions re-exported from marq
+//! Req definitions re-exported from marq, and the attributes of their markers
 
 pub use marq::ReqDefinition;
+
+use crate::{RuleId, parse_rule_id};
+
+/// Comma-separated values of a `name=` attribute of a requirement marker,
+/// e.g. `["auth.token", "auth.cookie"]` for `replaces` in
+/// `r[auth.session replaces=auth.token,auth.cookie]`.
+pub fn marker_attribute<'a>(marker: &'a str, name: &str) -> Vec<&'a str> {
+    let inner = marker
+        .split_once('[')
+        .map(|(_, rest)| rest.trim_end().trim_end_matches(']'))
+        .unwrap_or_default();
+    inner
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|attr| attr.strip_prefix(name)?.strip_prefix('='))
+        .flat_map(|values| values.split(','))
+        .map(str::trim)
+        .collect()
+}
+
+/// IDs of the `replaces=` attribute of a requirement marker, e.g.
+/// `r[auth.session replaces=auth.token,auth.cookie]`.
+pub fn marker_replaces(marker: &str) -> Vec<RuleId> {
+    marker_attribute(marker, "replaces")
+        .into_iter()
+        .filter_map(parse_rule_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_replaced_ids_from_markers() {
+        assert_eq!(
+            marker_replaces("r[auth.session replaces=auth.token,auth.cookie+2]"),
+            [
+                parse_rule_id("auth.token").unwrap(),
+                parse_rule_id("auth.cookie+2").unwrap()
+            ]
+        );
+        assert!(marker_replaces("r[auth.session]").is_empty());
+        assert_eq!(
+            marker_attribute("r[auth.session tags=a,b replaces-x=c]", "tags"),
+            ["a", "b"]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! tracey-macros - requirement references as attributes
+//!
+//! `#[tracey::implements("auth.login")]` and `#[tracey::verifies("auth.login")]`
+//! are the attribute forms of `// r[impl auth.login]` and
+//! `// r[verify auth.login]`: tracey counts them exactly like comments, and
+//! they fail compilation when the ID isn't the current version of a rule of
+//! the project's spec.
+//!
+//! ```toml
+//! [dependencies]
+//! tracey = { package = "tracey-macros", version = "1" }
+//! ```
+//!
+//! ```ignore
+//! #[tracey::implements("auth.login")]
+//! fn login(credentials: &Credentials) -> Result<Session> { /* ... */ }
+//!
+//! #[test]
+//! #[tracey::verifies("auth.login+2")]
+//! fn rejects_expired_credentials() { /* ... */ }
+//! ```
+//!
+//! IDs of a spec whose prefix isn't `r` are qualified with it:
+//! `#[tracey::implements("h2[stream.reset]")]`.
+//!
+//! The spec is found through `.config/tracey/config.styx` in the directory
+//! of the file using the attribute or one of its ancestors, else in the
+//! crate's directory or one of its ancestors. Without a config, e.g. when
+//! the crate is built from a registry, the attributes are not checked. Cargo
+//! doesn't know about the spec files: after changing them, an incremental
+//! build may not check the attributes again until their crate is rebuilt.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+mod spec;
+
+/// Marks an item as implementing a requirement, like `// r[impl ...]`.
+///
+/// r[impl macros.check+2]
+#[proc_macro_attribute]
+pub fn implements(attr: TokenStream, item: TokenStream) -> TokenStream {
+    reference(attr, item)
+}
+
+/// Marks an item, typically a test, as verifying a requirement, like
+/// `// r[verify ...]`.
+///
+/// r[impl macros.check+2]
+#[proc_macro_attribute]
+pub fn verifies(attr: TokenStream, item: TokenStream) -> TokenStream {
+    reference(attr, item)
+}
+
+/// The item unchanged, preceded by a compile error if the reference is wrong.
+fn reference(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match check(attr) {
+        Ok(()) => item,
+        Err((span, message)) => {
+            let mut tokens = compile_error(span, &message);
+            tokens.extend(item);
+            tokens
+        }
+    }
+}
+
+fn check(attr: TokenStream) -> Result<(), (Span, String)> {
+    let mut tokens = attr.into_iter();
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        // Literals passed through `macro_rules!` arrive in an invisible group.
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::None => {
+            return check(group.stream());
+        }
+        (first, _) => {
+            let span = first.map_or_else(Span::call_site, |token| token.span());
+            return Err((
+                span,
+                "expected a requirement ID, e.g. \"auth.login\" or \"h2[stream.reset+2]\""
+                    .to_string(),
+            ));
+        }
+    };
+
+    let span = literal.span();
+    let text = literal.to_string();
+    let value = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .filter(|value| !value.contains('\\'))
+        .ok_or_else(|| (span, "expected a plain string literal".to_string()))?;
+    let (prefix, req_id) = tracey_core::parse_attribute_reference(value)
+        .ok_or_else(|| (span, format!("`{value}` is not a valid requirement ID")))?;
+
+    match &*spec::project_specs(span.local_file().as_deref()) {
+        Ok(Some(specs)) => specs.check(&prefix, &req_id).map_err(|e| (span, e)),
+        Ok(None) => Ok(()),
+        Err(e) => Err((span, e.clone())),
+    }
+}
+
+/// `::core::compile_error! { "message" }`, reported at `span`.
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut body = Group::new(Delimiter::Brace, TokenTree::Literal(message).into());
+    body.set_span(span);
+
+    let path_sep = || {
+        [
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        ]
+    };
+    path_sep()
+        .into_iter()
+        .chain([TokenTree::Ident(Ident::new("core", span))])
+        .chain(path_sep())
+        .chain([
+            TokenTree::Ident(Ident::new("compile_error", span)),
+            TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+            TokenTree::Group(body),
+        ])
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
@@ -0,0 +1,331 @@
+//! The rules of the project being compiled, read once per compiler process
+//! from the specs of its tracey config.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, Wake, Waker};
+
+use tracey_config::{DEFAULT_CONFIG_PATH, RemovedRule, SpecConfig};
+use tracey_core::{
+    RuleId, RuleIdMatch, classify_reference_with_aliases, glob_base_dir, marker_replaces,
+    parse_rule_id,
+};
+
+/// A rule defined by a spec, with the IDs it replaces.
+pub(crate) struct Rule {
+    pub(crate) id: RuleId,
+    pub(crate) replaces: Vec<RuleId>,
+}
+
+/// The rules of one spec of the config.
+pub(crate) struct Spec {
+    /// Prefix of the spec's markers; `None` if it has no rules
+    pub(crate) prefix: Option<String>,
+    pub(crate) rules: Vec<Rule>,
+    pub(crate) removed: Vec<RemovedRule>,
+    /// Whether the spec's files could be read. Specs from git repositories
+    /// are read by tracey from the object database, which isn't done here.
+    pub(crate) readable: bool,
+}
+
+pub(crate) struct ProjectSpecs {
+    pub(crate) specs: Vec<Spec>,
+}
+
+type LoadedSpecs = Arc<Result<Option<ProjectSpecs>, String>>;
+
+/// The specs of the project `file` belongs to: `Ok(None)` when there's no
+/// tracey config, `Err` when it or a spec can't be read. Each project is
+/// read once per compiler process.
+pub(crate) fn project_specs(file: Option<&Path>) -> LoadedSpecs {
+    static SPECS: Mutex<BTreeMap<Option<PathBuf>, LoadedSpecs>> = Mutex::new(BTreeMap::new());
+    let mut specs = SPECS.lock().unwrap_or_else(PoisonError::into_inner);
+    specs
+        .entry(project_root(file))
+        .or_insert_with_key(|root| {
+            Arc::new(match root {
+                Some(root) => load(root).map(Some),
+                None => Ok(None),
+            })
+        })
+        .clone()
+}
+
+/// The closest directory with a tracey config, from `file` up, else from the
+/// crate being compiled up.
+fn project_root(file: Option<&Path>) -> Option<PathBuf> {
+    // Relative paths are relative to the compiler's working directory.
+    let file_dir = file.and_then(|file| {
+        let file = std::env::current_dir().ok()?.join(file);
+        Some(file.parent()?.to_path_buf())
+    });
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    file_dir.into_iter().chain(manifest_dir).find_map(|dir| {
+        dir.ancestors()
+            .find(|dir| dir.join(DEFAULT_CONFIG_PATH).is_file())
+            .map(Path::to_path_buf)
+    })
+}
+
+fn load(root: &Path) -> Result<ProjectSpecs, String> {
+    let path = root.join(DEFAULT_CONFIG_PATH);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let config = tracey_config::parse(&content)
+        .map_err(|e| format!("failed to parse {}: {e}", path.display()))?;
+    let specs = config
+        .specs
+        .iter()
+        .map(|spec| load_spec(root, spec))
+        .collect::<Result<_, _>>()?;
+    Ok(ProjectSpecs { specs })
+}
+
+fn load_spec(root: &Path, config: &SpecConfig) -> Result<Spec, String> {
+    let mut spec = Spec {
+        prefix: None,
+        rules: Vec::new(),
+        removed: config.removed.clone(),
+        readable: config.git.is_none(),
+    };
+    if !spec.readable {
+        return Ok(spec);
+    }
+
+    let files: BTreeSet<PathBuf> = config
+        .include
+        .iter()
+        .flat_map(|pattern| spec_files(root, pattern))
+        .collect();
+
+    for path in files {
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        let doc = block_on(marq::render(&content, &marq::RenderOptions::default()))
+            .map_err(|e| format!("failed to process {}: {e}", path.display()))?;
+        for req in doc.reqs {
+            let start = req.marker_span.offset;
+            let marker = content
+                .get(start..start + req.marker_span.length)
+                .unwrap_or_default();
+            if spec.prefix.is_none()
+                && let Some((prefix, _)) = marker.split_once('[')
+            {
+                spec.prefix = Some(prefix.trim().to_string());
+            }
+            let Some(id) = parse_rule_id(&req.id.to_string()) else {
+                continue;
+            };
+            spec.rules.push(Rule {
+                id,
+                replaces: marker_replaces(marker),
+            });
+        }
+    }
+    Ok(spec)
+}
+
+/// The markdown files matching an include pattern. Only the directory the
+/// pattern is under is walked, not the whole project with its build output.
+fn spec_files(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let Ok(glob) = globset::Glob::new(pattern) else {
+        return Vec::new();
+    };
+    let matcher = glob.compile_matcher();
+    let base = glob_base_dir(pattern);
+    let walk_root = if base == Path::new(".") {
+        root.to_path_buf()
+    } else {
+        root.join(base)
+    };
+    ignore::WalkBuilder::new(walk_root)
+        .follow_links(true)
+        .hidden(false)
+        .git_ignore(true)
+        .build()
+        .flatten()
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .filter(|path| matcher.is_match(path.strip_prefix(root).unwrap_or(path)))
+        .collect()
+}
+
+/// Run a future to completion on the current thread. Rendering without
+/// handlers doesn't wait on anything, so this rarely parks.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct Unpark(std::thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+impl ProjectSpecs {
+    /// r[impl macros.check+2]
+    /// Check that a reference names the current version of a rule.
+    pub(crate) fn check(&self, prefix: &str, req_id: &RuleId) -> Result<(), String> {
+        let specs: Vec<&Spec> = self
+            .specs
+            .iter()
+            .filter(|spec| spec.prefix.as_deref() == Some(prefix))
+            .collect();
+        // IDs of a spec that couldn't be read can't be told apart from
+        // unknown ones.
+        let unchecked = self.specs.iter().any(|spec| !spec.readable);
+        if specs.is_empty() {
+            if unchecked {
+                return Ok(());
+            }
+            return Err(format!(
+                "no spec of the tracey config defines `{prefix}[...]` requirements"
+            ));
+        }
+
+        let reference = if prefix == tracey_core::DEFAULT_ATTRIBUTE_PREFIX {
+            req_id.to_string()
+        } else {
+            format!("{prefix}[{req_id}]")
+        };
+        for rule in specs.iter().flat_map(|spec| &spec.rules) {
+            match classify_reference_with_aliases(&rule.id, &rule.replaces, req_id) {
+                RuleIdMatch::Exact => return Ok(()),
+                RuleIdMatch::Stale => {
+                    return Err(format!(
+                        "`{reference}` is stale: the spec is at `{}`; review the change and \
+                         update the reference",
+                        rule.id
+                    ));
+                }
+                RuleIdMatch::Renamed => {
+                    return Err(format!("`{}` was renamed to `{}`", req_id.base, rule.id));
+                }
+                RuleIdMatch::NoMatch if rule.id.base == req_id.base => {
+                    return Err(format!(
+                        "`{reference}` is newer than the spec, which is at `{}`",
+                        rule.id
+                    ));
+                }
+                RuleIdMatch::NoMatch => {}
+            }
+        }
+
+        let removed = specs
+            .iter()
+            .flat_map(|spec| &spec.removed)
+            .find(|removed| removed.id == req_id.base);
+        if let Some(removed) = removed {
+            let mut message = format!("`{}` was removed from the spec", req_id.base);
+            if let Some(replaced_by) = &removed.replaced_by {
+                message.push_str(&format!("; use `{replaced_by}` instead"));
+            }
+            if let Some(reason) = &removed.reason {
+                message.push_str(&format!(" ({reason})"));
+            }
+            return Err(message);
+        }
+        if unchecked {
+            return Ok(());
+        }
+        Err(format!("`{reference}` is not defined by the spec"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rid(id: &str) -> RuleId {
+        parse_rule_id(id).expect("valid rule id")
+    }
+
+    fn spec(prefix: &str, rules: &[&str]) -> Spec {
+        Spec {
+            prefix: Some(prefix.to_string()),
+            rules: rules
+                .iter()
+                .map(|id| Rule {
+                    id: rid(id),
+                    replaces: Vec::new(),
+                })
+                .collect(),
+            removed: Vec::new(),
+            readable: true,
+        }
+    }
+
+    #[test]
+    fn checks_references_against_the_spec() {
+        let mut auth = spec("r", &["auth.login+2", "auth.session"]);
+        auth.rules[1].replaces = vec![rid("auth.token")];
+        auth.removed.push(RemovedRule {
+            id: "auth.cookie".to_string(),
+            replaced_by: Some("auth.session".to_string()),
+            reason: None,
+        });
+        let specs = ProjectSpecs {
+            specs: vec![auth, spec("h2", &["stream.reset"])],
+        };
+
+        assert!(specs.check("r", &rid("auth.login+2")).is_ok());
+        assert!(specs.check("h2", &rid("stream.reset")).is_ok());
+
+        let err = |prefix, id| specs.check(prefix, &rid(id)).unwrap_err();
+        assert_eq!(
+            err("r", "auth.login"),
+            "`auth.login` is stale: the spec is at `auth.login+2`; review the change and \
+             update the reference"
+        );
+        assert_eq!(
+            err("r", "auth.login+3"),
+            "`auth.login+3` is newer than the spec, which is at `auth.login+2`"
+        );
+        assert_eq!(
+            err("r", "auth.token"),
+            "`auth.token` was renamed to `auth.session`"
+        );
+        assert_eq!(
+            err("r", "auth.cookie"),
+            "`auth.cookie` was removed from the spec; use `auth.session` instead"
+        );
+        assert_eq!(
+            err("h2", "auth.login+2"),
+            "`h2[auth.login+2]` is not defined by the spec"
+        );
+        assert_eq!(
+            err("t", "transport.framing"),
+            "no spec of the tracey config defines `t[...]` requirements"
+        );
+    }
+
+    #[test]
+    fn unreadable_specs_leave_unknown_ids_unchecked() {
+        let specs = ProjectSpecs {
+            specs: vec![
+                spec("r", &["auth.login"]),
+                Spec {
+                    prefix: None,
+                    rules: Vec::new(),
+                    removed: Vec::new(),
+                    readable: false,
+                },
+            ],
+        };
+        assert!(specs.check("r", &rid("auth.logout")).is_ok());
+        assert!(specs.check("t", &rid("transport.framing")).is_ok());
+        assert!(specs.check("r", &rid("auth.login+2")).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+//! References the spec rejects must fail compilation.
+//!
+//! The UI tests are checked against the fixture spec next to them, found
+//! through `tests/ui/.config/tracey/config.styx`.
+
+// r[verify macros.check+2]
+#[test]
+fn rejected_references() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
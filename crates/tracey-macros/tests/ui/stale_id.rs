@@ -0,0 +1,6 @@
+#[tracey_macros::verifies("auth.login")]
+fn stale() {}
+
+fn main() {
+    stale();
+}
@@ -0,0 +1,6 @@
+#[tracey_macros::implements("auth.no-such-rule")]
+fn unknown() {}
+
+fn main() {
+    unknown();
+}
@@ -14,7 +14,7 @@ pub use tracey_api::*;
 /// Protocol version — bump this whenever any RPC method is added, removed, or changed.
 /// The daemon writes this into its PID file; connectors compare it before connecting
 /// to detect stale daemons running an incompatible build.
-pub const PROTOCOL_VERSION: u32 = 4;
+pub const PROTOCOL_VERSION: u32 = 14;
 
 // ============================================================================
 // Request/Response types for the TraceyDaemon service
@@ -33,6 +33,18 @@ pub struct UncoveredRequest {
     /// Filter rules by ID prefix (case-insensitive)
     #[facet(default)]
     pub prefix: Option<String>,
+    /// Only include rules of this RFC 2119 level (`must`, `should` or `may`)
+    #[facet(default)]
+    pub level: Option<String>,
+    /// Only include rules with this status (rules without one are `stable`)
+    #[facet(default)]
+    pub status: Option<String>,
+    /// Only include rules with this owner, e.g. `@payments`
+    #[facet(default)]
+    pub owner: Option<String>,
+    /// Only include rules with this tag, e.g. `security`
+    #[facet(default)]
+    pub tag: Option<String>,
 }
 
 /// Response for uncovered rules query
@@ -61,6 +73,9 @@ pub struct RuleRef {
     pub id: RuleId,
     #[facet(default)]
     pub text: Option<String>,
+    /// Outcome of the rule's verifying tests, when test results were given
+    #[facet(default)]
+    pub verification: Option<TestOutcome>,
 }
 
 /// Request for untested rules query
@@ -73,6 +88,17 @@ pub struct UntestedRequest {
     pub impl_name: Option<String>,
     #[facet(default)]
     pub prefix: Option<String>,
+    #[facet(default)]
+    pub level: Option<String>,
+    #[facet(default)]
+    pub status: Option<String>,
+    #[facet(default)]
+    pub owner: Option<String>,
+    #[facet(default)]
+    pub tag: Option<String>,
+    /// Test results to use for this query instead of the configured ones
+    #[facet(default)]
+    pub results: Vec<ApiTestResult>,
 }
 
 /// Response for untested rules query
@@ -99,6 +125,18 @@ pub struct StaleRequest {
     /// Filter rules by ID prefix (case-insensitive)
     #[facet(default)]
     pub prefix: Option<String>,
+    /// Only include rules of this RFC 2119 level (`must`, `should` or `may`)
+    #[facet(default)]
+    pub level: Option<String>,
+    /// Only include rules with this status (rules without one are `stable`)
+    #[facet(default)]
+    pub status: Option<String>,
+    /// Only include rules with this owner, e.g. `@payments`
+    #[facet(default)]
+    pub owner: Option<String>,
+    /// Only include rules with this tag, e.g. `security`
+    #[facet(default)]
+    pub tag: Option<String>,
 }
 
 /// Response for stale references query
@@ -111,6 +149,10 @@ pub struct StaleResponse {
     pub stale_count: usize,
     /// Flat list of stale entries (sorted by file, then line)
     pub refs: Vec<StaleEntry>,
+    /// Rules that need review because a rule they depend on was bumped
+    /// (specs with `propagate_stale`), in spec order
+    #[facet(default)]
+    pub needs_review: Vec<NeedsReviewEntry>,
 }
 
 /// A single stale reference entry
@@ -127,6 +169,17 @@ pub struct StaleEntry {
     pub reference_id: RuleId,
 }
 
+/// A rule that needs review, with the code implementing it
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct NeedsReviewEntry {
+    pub id: RuleId,
+    /// Bumped rules it depends on, at their current version
+    pub changed: Vec<ApiRuleLink>,
+    /// Implementation references to review along with the rule
+    pub impl_refs: Vec<ApiCodeRef>,
+}
+
 /// Request for unmapped code query
 #[derive(Debug, Clone, Facet)]
 #[facet(rename_all = "camelCase")]
@@ -176,6 +229,26 @@ pub struct UnmappedUnit {
     pub end_line: usize,
 }
 
+/// Request for coverage status
+#[derive(Debug, Clone, Default, Facet)]
+pub struct StatusRequest {
+    /// Only count rules of this RFC 2119 level (`must`, `should` or `may`)
+    #[facet(default)]
+    pub level: Option<String>,
+    /// Only count rules with this status (rules without one are `stable`)
+    #[facet(default)]
+    pub status: Option<String>,
+    /// Only count rules with this owner, e.g. `@payments`
+    #[facet(default)]
+    pub owner: Option<String>,
+    /// Only count rules with this tag, e.g. `security`
+    #[facet(default)]
+    pub tag: Option<String>,
+    /// Test results to use for this query instead of the configured ones
+    #[facet(default)]
+    pub results: Vec<ApiTestResult>,
+}
+
 /// Coverage status response
 #[derive(Debug, Clone, Facet)]
 #[facet(rename_all = "camelCase")]
@@ -196,6 +269,69 @@ pub struct ImplStatus {
     /// Not included in covered_rules. covered_rules + stale_rules + uncovered = total.
     pub stale_rules: usize,
     pub verified_rules: usize,
+    /// Coverage thresholds from the config that this pair does not meet.
+    #[facet(default)]
+    pub threshold_violations: Vec<String>,
+    /// Rules left out of the counts above by the spec's `exclude_statuses`.
+    #[facet(default)]
+    pub excluded_rules: usize,
+    /// The counts above per RFC 2119 level, strongest first
+    #[facet(default)]
+    pub by_level: Vec<LevelStatus>,
+    /// The counts above per owner, sorted, then for unowned rules. Empty
+    /// when no rule has an owner.
+    #[facet(default)]
+    pub by_owner: Vec<OwnerStatus>,
+    /// The counts above per tag, sorted; untagged rules are left out
+    #[facet(default)]
+    pub by_tag: Vec<TagStatus>,
+    /// Rules whose verifying tests passed, when test results were ingested
+    #[facet(default)]
+    pub passing_rules: Option<usize>,
+    /// Rules with a failing verifying test, when test results were ingested
+    #[facet(default)]
+    pub failing_rules: Option<usize>,
+    /// Rules whose implementing code ran, when runtime coverage data was
+    /// imported
+    #[facet(default)]
+    pub executed_rules: Option<usize>,
+}
+
+/// Coverage counts for the rules of one RFC 2119 level
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct LevelStatus {
+    /// `must`, `should` or `may`
+    pub level: String,
+    pub total_rules: usize,
+    pub covered_rules: usize,
+    pub stale_rules: usize,
+    pub verified_rules: usize,
+}
+
+/// Coverage counts for the rules of one owner
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct OwnerStatus {
+    /// e.g. `@payments`; `None` for rules without an owner
+    #[facet(default)]
+    pub owner: Option<String>,
+    pub total_rules: usize,
+    pub covered_rules: usize,
+    pub stale_rules: usize,
+    pub verified_rules: usize,
+}
+
+/// Coverage counts for the rules with one tag
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct TagStatus {
+    /// e.g. `security`
+    pub tag: String,
+    pub total_rules: usize,
+    pub covered_rules: usize,
+    pub stale_rules: usize,
+    pub verified_rules: usize,
 }
 
 /// Information about a specific rule
@@ -210,6 +346,12 @@ pub struct RuleInfo {
     pub source_file: Option<String>,
     #[facet(default)]
     pub source_line: Option<usize>,
+    /// Owners of the rule, e.g. `@payments`
+    #[facet(default)]
+    pub owners: Vec<String>,
+    /// Tags of the rule, e.g. `security`
+    #[facet(default)]
+    pub tags: Vec<String>,
     /// Coverage across all implementations
     pub coverage: Vec<RuleCoverage>,
     /// Diff from the previous rule version (N-1 → N), if version > 1 and git history is available.
@@ -226,6 +368,10 @@ pub struct RuleCoverage {
     pub impl_name: String,
     pub impl_refs: Vec<ApiCodeRef>,
     pub verify_refs: Vec<ApiCodeRef>,
+    /// Whether the test suite ran any of the code implementing the rule, when
+    /// runtime coverage data was imported
+    #[facet(default)]
+    pub executed: Option<bool>,
 }
 
 /// Response from reload command
@@ -294,6 +440,70 @@ pub struct ValidateRequest {
     pub impl_name: Option<String>,
 }
 
+/// Lines changed in one file, as reported by `git diff`
+#[derive(Debug, Clone, Facet)]
+pub struct ChangedFile {
+    /// Path relative to the project root
+    pub path: String,
+    /// Changed line ranges on the new side of the diff
+    pub ranges: Vec<LineRange>,
+}
+
+/// An inclusive, 1-indexed range of lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Facet)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Request for impact analysis
+#[derive(Debug, Clone, Facet)]
+pub struct ImpactRequest {
+    pub files: Vec<ChangedFile>,
+    /// Revision or tree the changed lines refer to. They're mapped onto the
+    /// daemon's current data if omitted.
+    #[facet(default)]
+    pub revision: Option<String>,
+}
+
+/// Requirements touched by a set of changed lines
+#[derive(Debug, Clone, Default, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ImpactResponse {
+    /// Number of changed files that were analyzed
+    pub changed_files: usize,
+    /// Affected rules, sorted by spec, impl and rule ID
+    pub rules: Vec<ImpactedRule>,
+    /// Changed code units that reference no rule
+    pub unmapped_units: Vec<ImpactedUnit>,
+}
+
+/// A rule referenced by at least one changed code unit
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ImpactedRule {
+    pub spec: String,
+    pub impl_name: String,
+    pub id: RuleId,
+    /// Changed code units referencing the rule
+    pub units: Vec<ImpactedUnit>,
+    /// Verification references of the rule: the tests to run
+    pub verify_refs: Vec<ApiCodeRef>,
+}
+
+/// A changed code unit
+#[derive(Debug, Clone, Facet)]
+#[facet(rename_all = "camelCase")]
+pub struct ImpactedUnit {
+    /// Path relative to the project root
+    pub file: String,
+    pub kind: String,
+    #[facet(default)]
+    pub name: Option<String>,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
 /// Notification of data update (sent via streaming)
 #[derive(Debug, Clone, Facet)]
 #[facet(rename_all = "camelCase")]
@@ -402,6 +612,9 @@ pub struct HoverInfo {
     /// Source file where the rule is defined
     #[facet(default)]
     pub source_file: Option<String>,
+    /// Owners of the rule, e.g. `@payments`
+    #[facet(default)]
+    pub owners: Vec<String>,
     /// Number of impl references
     pub impl_count: usize,
     /// Number of verify references
@@ -642,7 +855,7 @@ pub trait TraceyDaemon {
     // === Core Queries ===
 
     /// Get coverage status for all specs/impls
-    async fn status(&self) -> StatusResponse;
+    async fn status(&self, req: StatusRequest) -> StatusResponse;
 
     /// Get uncovered rules (rules without implementation references)
     async fn uncovered(&self, req: UncoveredRequest) -> UncoveredResponse;
@@ -781,6 +994,12 @@ pub trait TraceyDaemon {
     /// and unknown references.
     async fn validate(&self, req: ValidateRequest) -> ValidationResult;
 
+    // === Impact Analysis ===
+
+    /// Map changed line ranges onto code units and collect the rules they
+    /// reference
+    async fn impact(&self, req: ImpactRequest) -> Result<ImpactResponse, String>;
+
     // === Config Modification (for MCP) ===
 
     /// Add an exclude pattern to an implementation
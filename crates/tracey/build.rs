@@ -142,6 +142,7 @@ fn generate_typescript_types() {
     generator.add_type::<ApiSpecData>();
     generator.add_type::<ValidationResult>();
     generator.add_type::<ValidationError>();
+    generator.add_type::<ApiHistory>();
 
     // Generate TypeScript code
     let typescript = generator.finish();
@@ -0,0 +1,235 @@
+//! Coverage badges, as self-contained SVG or shields.io endpoint JSON.
+//!
+//! Badges are colored with the same thresholds as the coverage reports (see
+//! [`CoverageRating`]), and say `n/a` when there is nothing to measure.
+
+use eyre::Result;
+use facet::Facet;
+
+use tracey_api::{ApiReverseData, ApiSpecForward};
+
+use crate::data::DashboardData;
+use crate::output::CoverageRating;
+use crate::server::{CoverageStats, ReverseStats};
+
+/// Which coverage number a badge shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BadgeMetric {
+    /// Rules with implementation references
+    #[default]
+    Impl,
+    /// Rules with verification references
+    Verify,
+    /// Code units referencing a rule
+    Reverse,
+}
+
+impl std::str::FromStr for BadgeMetric {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "impl" => Ok(Self::Impl),
+            "verify" => Ok(Self::Verify),
+            "reverse" => Ok(Self::Reverse),
+            _ => Err(eyre::eyre!(
+                "unknown badge metric '{s}' (expected impl, verify or reverse)"
+            )),
+        }
+    }
+}
+
+/// Badge output format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BadgeFormat {
+    #[default]
+    Svg,
+    /// shields.io endpoint JSON
+    Json,
+}
+
+impl std::str::FromStr for BadgeFormat {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "svg" => Ok(Self::Svg),
+            "json" => Ok(Self::Json),
+            _ => Err(eyre::eyre!(
+                "unknown badge format '{s}' (expected svg or json)"
+            )),
+        }
+    }
+}
+
+/// A coverage badge for one metric of a spec/impl pair.
+#[derive(Debug, Clone)]
+pub struct Badge {
+    pub label: String,
+    /// `None` when there are no rules (or code units) to measure.
+    pub percent: Option<f64>,
+}
+
+/// Response body of a shields.io endpoint badge.
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct ShieldsEndpoint {
+    schema_version: u32,
+    label: String,
+    message: String,
+    color: String,
+}
+
+impl Badge {
+    /// Badge for one metric of a spec/impl pair.
+    pub fn new(
+        metric: BadgeMetric,
+        forward: &ApiSpecForward,
+        reverse: Option<&ApiReverseData>,
+    ) -> Self {
+        let stats = CoverageStats::from_rules(&forward.rules);
+        let (label, percent) = match metric {
+            BadgeMetric::Impl => (
+                "impl coverage",
+                (stats.total_rules > 0).then_some(stats.impl_percent),
+            ),
+            BadgeMetric::Verify => (
+                "verify coverage",
+                (stats.total_rules > 0).then_some(stats.verify_percent),
+            ),
+            BadgeMetric::Reverse => {
+                let stats = reverse
+                    .map(|r| ReverseStats {
+                        total_units: r.total_units,
+                        covered_units: r.covered_units,
+                    })
+                    .unwrap_or_default();
+                (
+                    "reverse coverage",
+                    (stats.total_units > 0).then(|| stats.percent()),
+                )
+            }
+        };
+        Self {
+            label: label.to_string(),
+            percent,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self.percent {
+            Some(percent) => format!("{percent:.1}%"),
+            None => "n/a".to_string(),
+        }
+    }
+
+    /// shields.io color name and the matching hex color.
+    fn color(&self) -> (&'static str, &'static str) {
+        match self.percent.map(CoverageRating::from_percent) {
+            Some(CoverageRating::Good) => ("brightgreen", "#4c1"),
+            Some(CoverageRating::Warn) => ("yellow", "#dfb317"),
+            Some(CoverageRating::Bad) => ("red", "#e05d44"),
+            None => ("lightgrey", "#9f9f9f"),
+        }
+    }
+
+    /// Render as a flat, self-contained SVG badge.
+    pub fn svg(&self) -> String {
+        let message = self.message();
+        let label_width = text_width(&self.label) + 10;
+        let message_width = text_width(&message) + 10;
+        let width = label_width + message_width;
+        let label_x = label_width as f64 / 2.0;
+        let message_x = label_width as f64 + message_width as f64 / 2.0;
+        let label = xml_escape(&self.label);
+        let message = xml_escape(&message);
+        let (_, color) = self.color();
+
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}">
+<title>{label}: {message}</title>
+<linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient>
+<clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+<g clip-path="url(#r)"><rect width="{label_width}" height="20" fill="#555"/><rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/><rect width="{width}" height="20" fill="url(#s)"/></g>
+<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+<text x="{label_x}" y="15" fill="#010101" fill-opacity=".3">{label}</text><text x="{label_x}" y="14">{label}</text>
+<text x="{message_x}" y="15" fill="#010101" fill-opacity=".3">{message}</text><text x="{message_x}" y="14">{message}</text>
+</g>
+</svg>
+"##
+        )
+    }
+
+    /// Render as shields.io endpoint JSON
+    /// (`https://img.shields.io/endpoint?url=...`).
+    pub fn shields_json(&self) -> String {
+        let (color, _) = self.color();
+        facet_json::to_string(&ShieldsEndpoint {
+            schema_version: 1,
+            label: self.label.clone(),
+            message: self.message(),
+            color: color.to_string(),
+        })
+        .expect("JSON serialization failed")
+    }
+
+    pub fn render(&self, format: BadgeFormat) -> String {
+        match format {
+            BadgeFormat::Svg => self.svg(),
+            BadgeFormat::Json => self.shields_json(),
+        }
+    }
+}
+
+/// Approximate rendered width of `text` in 11px Verdana.
+fn text_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '\'' | '|' | '!' => 4,
+            ' ' | 'f' | 'r' | 't' | '(' | ')' | '/' => 5,
+            'm' | 'w' | 'M' | 'W' | '%' => 10,
+            c if c.is_ascii_uppercase() => 8,
+            _ => 7,
+        })
+        .sum()
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Build the badge for a spec/impl pair of in-process dashboard data. Spec and
+/// impl may be omitted when they are unambiguous.
+pub fn badge_for(
+    data: &DashboardData,
+    spec: Option<&str>,
+    impl_name: Option<&str>,
+    metric: BadgeMetric,
+) -> Result<Badge> {
+    let matching: Vec<_> = data
+        .forward_by_impl
+        .iter()
+        .filter(|((s, i), _)| {
+            spec.is_none_or(|spec| s.as_str() == spec)
+                && impl_name.is_none_or(|name| i.as_str() == name)
+        })
+        .collect();
+    let (key, forward) = match matching.as_slice() {
+        [one] => *one,
+        [] => eyre::bail!("no spec/impl pair matches"),
+        _ => {
+            let pairs: Vec<String> = matching
+                .iter()
+                .map(|((s, i), _)| format!("{s}/{i}"))
+                .collect();
+            eyre::bail!(
+                "several spec/impl pairs match, pick one with --spec and --impl: {}",
+                pairs.join(", ")
+            )
+        }
+    };
+    Ok(Badge::new(metric, forward, data.reverse_by_impl.get(key)))
+}
@@ -1,6 +1,90 @@
 // This file is auto-generated from tracey-api Rust types
 // DO NOT EDIT MANUALLY - changes will be overwritten on build
 
+/**
+ * Recorded coverage history, oldest entry first
+ */
+export interface ApiHistory {
+  entries: ApiHistoryEntry[];
+}
+
+/**
+ * Coverage recorded for one git commit
+ */
+export interface ApiHistoryEntry {
+  /**
+   * Full commit hash
+   */
+  commit: string;
+  /**
+   * Committer date (YYYY-MM-DD)
+   */
+  date: string;
+  /**
+   * Committer timestamp (seconds since the Unix epoch), used for ordering
+   */
+  timestamp: number;
+  /**
+   * First line of the commit message
+   */
+  subject: string;
+  impls: ApiHistoryImpl[];
+}
+
+/**
+ * Coverage of one spec/impl pair at a recorded commit
+ */
+export interface ApiHistoryImpl {
+  spec: string;
+  implName: string;
+  stats: ApiHistoryStats;
+  /**
+   * Per-section coverage, in spec order
+   */
+  sections?: ApiHistorySection[];
+}
+
+/**
+ * Coverage of one spec section at a recorded commit
+ */
+export interface ApiHistorySection {
+  /**
+   * Section title (heading text), or "Uncategorized"
+   */
+  title: string;
+  stats: ApiHistoryStats;
+}
+
+/**
+ * Coverage counts recorded in the coverage history
+ */
+export interface ApiHistoryStats {
+  /**
+   * Total number of rules
+   */
+  totalRules: number;
+  /**
+   * Rules with at least one exact implementation reference (not stale)
+   */
+  implCovered: number;
+  /**
+   * Rules with at least one verification reference
+   */
+  verifyCovered: number;
+  /**
+   * Rules with stale references
+   */
+  stale: number;
+  /**
+   * Total code units (reverse coverage); zero for sections
+   */
+  totalUnits?: number;
+  /**
+   * Code units with at least one rule reference; zero for sections
+   */
+  coveredUnits?: number;
+}
+
 /**
  * r[impl validation.circular-deps]
  * r[impl validation.naming]
@@ -59,7 +143,7 @@ export interface RuleId {
 /**
  * Error codes for validation errors
  */
-export type ValidationErrorCode = "circular_dependency" | "invalid_naming" | "unknown_requirement" | "stale_requirement" | "duplicate_requirement" | "unknown_prefix" | "impl_in_test_file";
+export type ValidationErrorCode = "circular_dependency" | "invalid_naming" | "unknown_requirement" | "stale_requirement" | "duplicate_requirement" | "unknown_prefix" | "impl_in_test_file" | "coverage_threshold" | "deprecated_requirement" | "removed_requirement" | "renamed_requirement";
 
 /**
  * Validation results for a spec/implementation pair
@@ -173,6 +257,10 @@ export interface ApiCodeUnit {
    * Rule references found in this code unit's comments
    */
   ruleRefs: string[];
+  /**
+   * How often this code unit ran, when runtime coverage data was imported
+   */
+  hits?: number;
 }
 
 /**
@@ -224,8 +312,28 @@ export interface ApiReverseData {
 export interface ApiCodeRef {
   file: string;
   line: number;
+  /**
+   * Outcome of the test enclosing a `verify` reference, if the
+   * implementation has test results
+   */
+  outcome?: TestOutcome;
+  /**
+   * How often the code unit containing an `impl` reference ran, when
+   * runtime coverage data was imported
+   */
+  hits?: number;
+  /**
+   * The reference's verb when it is one declared in the config, e.g.
+   * "bench"
+   */
+  verb?: string;
 }
 
+/**
+ * Outcome of a test in a test result file
+ */
+export type TestOutcome = "passed" | "failed" | "not_run";
+
 export interface ApiRule {
   id: RuleId;
   /**
@@ -252,6 +360,11 @@ export interface ApiRule {
   implRefs: ApiCodeRef[];
   verifyRefs: ApiCodeRef[];
   dependsRefs: ApiCodeRef[];
+  /**
+   * References with a declared verb that counts toward neither impl nor
+   * verify coverage
+   */
+  otherRefs: ApiCodeRef[];
   /**
    * True if any reference to this rule is stale (points to an older version).
    * A stale rule is not counted as covered.
@@ -261,6 +374,70 @@ export interface ApiRule {
    * Stale references pointing to older versions of this rule.
    */
   staleRefs?: ApiStaleRef[];
+  /**
+   * True if the rule is deprecated or its status is listed in the spec's
+   * `exclude_statuses`. Excluded rules are left out of coverage numbers.
+   */
+  excluded?: boolean;
+  /**
+   * IDs this rule replaces (`replaces=` in its marker). References to them
+   * count for this rule.
+   */
+  replaces?: RuleId[];
+  /**
+   * References that still use one of the replaced IDs.
+   */
+  renamedRefs?: ApiStaleRef[];
+  /**
+   * Rules this rule depends on (`depends=` in its marker), possibly in
+   * other specs.
+   */
+  dependsOn?: ApiRuleLink[];
+  /**
+   * Rules of any spec that depend on this rule.
+   */
+  dependents?: ApiRuleLink[];
+  /**
+   * Bumped rules this rule transitively depends on, at their current
+   * version, whose change its `depends=` doesn't acknowledge yet. Only set
+   * in specs with `propagate_stale`.
+   */
+  needsReview?: ApiRuleLink[];
+  /**
+   * Owners of this rule, e.g. "@payments": from `owner=` in its marker,
+   * or else the spec's `owners` config.
+   */
+  owners?: string[];
+  /**
+   * Free-form tags of this rule (`tags=` in its marker), e.g. "security"
+   */
+  tags?: string[];
+  /**
+   * Combined outcome of the tests verifying this rule: failed if any
+   * failed, passed if any passed. Only set when the implementation has
+   * test results and the rule has `verify` references.
+   */
+  verification?: TestOutcome;
+  /**
+   * Whether the test suite ran any of the code implementing this rule, when
+   * runtime coverage data was imported and covers its `impl` references.
+   */
+  executed?: boolean;
+}
+
+/**
+ * A dependency between two rules, possibly of different specs.
+ */
+export interface ApiRuleLink {
+  /**
+   * Marker prefix of the other rule
+   */
+  prefix: string;
+  /**
+   * Spec defining the other rule, or `None` if no spec does
+   */
+  spec?: string;
+  id: RuleId;
 }
 
 /**
@@ -270,7 +447,7 @@ export interface ApiStaleRef {
   file: string;
   line: number;
   /**
-   * The rule ID referenced in code (older version)
+   * The rule ID referenced in code (older version, or replaced ID)
    */
   reference_id: RuleId;
 }
@@ -306,6 +483,33 @@ export interface ApiSpecInfo {
    * Available implementations for this spec
    */
   implementations: string[];
+  /**
+   * Package manifests implementations are also discovered from
+   * ("cargo", "npm" or "go"), if any
+   */
+  implsFrom?: string;
+  /**
+   * Rules removed from this spec (from the `removed` config list)
+   */
+  removed?: ApiRemovedRule[];
+}
+
+/**
+ * A rule that was removed from a spec
+ */
+export interface ApiRemovedRule {
+  /**
+   * Base ID of the removed rule
+   */
+  id: string;
+  /**
+   * Base ID of the rule that replaces it
+   */
+  replacedBy?: string;
+  /**
+   * Why the rule was removed
+   */
+  reason?: string;
 }
 
 /**
@@ -314,8 +518,33 @@ export interface ApiSpecInfo {
 export interface ApiConfig {
   projectRoot: string;
   specs: ApiSpecInfo[];
+  /**
+   * Verbs declared in the config besides the built-in ones
+   */
+  verbs: ApiVerb[];
+}
+
+/**
+ * A reference verb declared in the config
+ */
+export interface ApiVerb {
+  name: string;
+  /**
+   * Coverage its references count toward, if any
+   */
+  counts?: VerbCounts;
+  /**
+   * Whether its references may appear in test files
+   */
+  inTests: boolean;
+  description?: string;
 }
 
+/**
+ * Coverage a declared verb's references count toward
+ */
+export type VerbCounts = "impl" | "verify";
+
 /**
  * Git status for a file
  */
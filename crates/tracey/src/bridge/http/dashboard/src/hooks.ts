@@ -1,5 +1,6 @@
 // Custom hooks
 import { useCallback, useEffect, useState } from "preact/hooks";
+import { BASE_PATH, routeParts } from "./router";
 import type {
   ApiData,
   Config,
@@ -25,7 +26,60 @@ export class ApiError extends Error {
   }
 }
 
-async function fetchJson<T>(url: string): Promise<T> {
+// r[impl dashboard.static.loader+2]
+// Set by `tracey export-site`: API responses are pre-rendered JSON files and
+// the dashboard is read-only (no live updates, search or editing).
+export const STATIC_MODE =
+  (window as { __TRACEY_STATIC__?: boolean }).__TRACEY_STATIC__ === true;
+
+// Directory name `tracey export-site` uses for a spec or impl name.
+function staticSlug(name: string): string {
+  return name.replace(/[^A-Za-z0-9._-]/g, "_");
+}
+
+// URL of a pre-rendered file. Files are resolved against the dashboard's
+// base path rather than the host root, so the exported site isn't tied to it.
+function siteUrl(path: string): string {
+  return new URL(BASE_PATH + path, window.location.origin).href;
+}
+
+// Per spec/impl map from file path to its pre-rendered file number.
+const staticFileIndexes = new Map<string, Promise<Record<string, number>>>();
+
+// Map an API URL to the JSON file `tracey export-site` wrote for it.
+async function staticUrl(url: string): Promise<string> {
+  const parsed = new URL(url, window.location.origin);
+  const endpoint = parsed.pathname.replace(/^\/api\//, "");
+  if (endpoint === "config") return siteUrl("api/config.json");
+
+  // Without an explicit spec/impl, the server picks the first pair.
+  let spec = parsed.searchParams.get("spec");
+  let impl = parsed.searchParams.get("impl");
+  if (!spec || !impl) {
+    const config = await fetchJson<Config>(siteUrl("api/config.json"));
+    spec ??= config.specs?.[0]?.name ?? "";
+    impl ??= config.specs?.find((s) => s.name === spec)?.implementations?.[0] ?? "";
+  }
+  const pair = `${staticSlug(spec)}/${staticSlug(impl)}`;
+
+  if (endpoint === "file") {
+    const dir = siteUrl(`api/file/${pair}`);
+    let index = staticFileIndexes.get(dir);
+    if (!index) {
+      index = fetchJson<Record<string, number>>(`${dir}/index.json`);
+      staticFileIndexes.set(dir, index);
+    }
+    const n = (await index)[parsed.searchParams.get("path") ?? ""];
+    if (n === undefined) throw new ApiError(404, "not_found", "File not found");
+    return `${dir}/${n}.json`;
+  }
+  return siteUrl(`api/${endpoint}/${pair}.json`);
+}
+
+export async function fetchJson<T>(url: string): Promise<T> {
+  if (STATIC_MODE && url.startsWith("/api/") && !url.endsWith(".json")) {
+    url = await staticUrl(url);
+  }
   const res = await fetch(url);
   if (!res.ok) {
     // Try to parse error response
@@ -43,7 +97,7 @@ async function fetchJson<T>(url: string): Promise<T> {
 // Parse spec and impl from URL pathname
 // URL format: /:spec/:impl/:view/...
 function getImplFromUrl(): { spec: string | null; impl: string | null } {
-  const parts = window.location.pathname.split("/").filter(Boolean);
+  const parts = routeParts();
   return {
     spec: parts[0] || null,
     impl: parts[1] || null,
@@ -80,7 +134,8 @@ export function useApi(): UseApiResult {
       // Fetch config and health in parallel
       const [config, health] = await Promise.all([
         fetchJson<Config>("/api/config"),
-        fetchJson<HealthData>("/api/health").catch(() => null),
+        // A static export has no daemon to report on.
+        STATIC_MODE ? null : fetchJson<HealthData>("/api/health").catch(() => null),
       ]);
 
       // Update config error state
@@ -153,6 +208,9 @@ export function useApi(): UseApiResult {
   // r[impl dashboard.editing.reload.live-update]
   // Connect to WebSocket for live version updates
   useEffect(() => {
+    // A static export never changes.
+    if (STATIC_MODE) return;
+
     let ws: WebSocket | null = null;
     let reconnectTimeout: ReturnType<typeof setTimeout> | null = null;
     let lastVersion: number | null = null;
@@ -7,8 +7,8 @@ import "./style.scss";
 import { getDeviconClass, modKey, TAB_ICON_NAMES } from "./config";
 
 // Modules
-import { type UseApiResult, useApi } from "./hooks";
-import { buildUrl } from "./router";
+import { STATIC_MODE, type UseApiResult, useApi } from "./hooks";
+import { BASE_PATH, buildUrl, routeParts, routePath } from "./router";
 // Types
 import type {
   ButtonProps,
@@ -176,7 +176,8 @@ function SearchModal({ onClose, onSelect }: SearchModalProps) {
 
   // Debounced search
   useEffect(() => {
-    if (!query || query.length < 2) {
+    // Search runs on the server, which a static export doesn't have.
+    if (STATIC_MODE || !query || query.length < 2) {
       setResults(null);
       setSelectedIndex(0);
       return;
@@ -286,9 +287,11 @@ function SearchModal({ onClose, onSelect }: SearchModalProps) {
                     `,
                   )}
                 `
-              : query.length >= 2
-                ? html` <div class="search-modal-empty">No results found</div> `
-                : html` <div class="search-modal-empty">Type to search code and rules...</div> `}
+              : STATIC_MODE
+                ? html` <div class="search-modal-empty">Search is not available in this static export</div> `
+                : query.length >= 2
+                  ? html` <div class="search-modal-empty">No results found</div> `
+                  : html` <div class="search-modal-empty">Type to search code and rules...</div> `}
         </div>
         <div class="search-modal-hint">
           <span><kbd>↑</kbd><kbd>↓</kbd> Navigate</span>
@@ -552,7 +555,7 @@ function FilePath({
 }
 
 // File reference component
-function FileRef({ file, line, type, onSelectFile }: FileRefProps) {
+function FileRef({ file, line, type, verb, onSelectFile }: FileRefProps) {
   return html`
     <div class="ref-line">
       <${FilePath}
@@ -561,6 +564,7 @@ function FileRef({ file, line, type, onSelectFile }: FileRefProps) {
         type=${type}
         onClick=${() => onSelectFile(file, line)}
       />
+      ${verb ? html`<span class="ref-verb">${verb}</span>` : null}
     </div>
   `;
 }
@@ -655,7 +659,7 @@ function App() {
 
   if (error) {
     const goHome = () => {
-      route("/");
+      route(BASE_PATH);
       apiResult.refetch();
     };
 
@@ -698,7 +702,7 @@ function App() {
 
   // Determine current spec, impl, and view from pathname
   // URL format: /:spec/:impl/:view/...
-  const pathParts = window.location.pathname.split("/").filter(Boolean);
+  const pathParts = routeParts();
   const currentSpec = pathParts[0] || defaultSpec;
   const currentImpl = pathParts[1] || defaultImpl;
   const currentView = pathParts[2] || "spec";
@@ -794,12 +798,12 @@ function App() {
         `}
         <${Router}>
           <${Route}
-            path="/"
+            path=${BASE_PATH}
             component=${() => {
               // r[impl dashboard.url.root-redirect]
               // Redirect to default spec/impl
               useEffect(() => {
-                if (defaultSpec && defaultImpl) route(`/${defaultSpec}/${defaultImpl}/spec`, true);
+                if (defaultSpec && defaultImpl) route(routePath(`/${defaultSpec}/${defaultImpl}/spec`), true);
               }, []);
               // If no specs configured, show empty state instead of infinite "Redirecting..."
               if (!defaultSpec || !defaultImpl) {
@@ -817,29 +821,32 @@ function App() {
               return html`<div class="loading">Redirecting...</div>`;
             }}
           />
-          <${Route} path="/:spec/:impl/spec" component=${SpecViewRoute} />
-          <${Route} path="/:spec/:impl/sources/:file*" component=${SourcesViewRoute} />
-          <${Route} path="/:spec/:impl/coverage" component=${CoverageViewRoute} />
+          <${Route} path=${routePath("/:spec/:impl/spec")} component=${SpecViewRoute} />
           <${Route}
-            path="/:spec/:impl"
+            path=${routePath("/:spec/:impl/sources/:file*")}
+            component=${SourcesViewRoute}
+          />
+          <${Route} path=${routePath("/:spec/:impl/coverage")} component=${CoverageViewRoute} />
+          <${Route}
+            path=${routePath("/:spec/:impl")}
             component=${() => {
-              // r[impl dashboard.url.structure]
+              // r[impl dashboard.url.structure+2]
               const { params } = useRoute();
               useEffect(() => {
-                route(`/${params.spec}/${params.impl}/spec`, true);
+                route(routePath(`/${params.spec}/${params.impl}/spec`), true);
               }, [params.spec, params.impl]);
               return html`<div class="loading">Redirecting...</div>`;
             }}
           />
           <${Route}
-            path="/:spec"
+            path=${routePath("/:spec")}
             component=${() => {
               // Legacy URL without impl - redirect with default impl
               const { params } = useRoute();
               useEffect(() => {
                 const specInfo = config.specs?.find((s) => s.name === params.spec);
                 const impl = specInfo?.implementations?.[0] || defaultImpl;
-                route(`/${params.spec}/${impl}/spec`, true);
+                route(routePath(`/${params.spec}/${impl}/spec`), true);
               }, [params.spec]);
               return html`<div class="loading">Redirecting...</div>`;
             }}
@@ -860,7 +867,7 @@ function App() {
                   </p>
                   <p style="color: var(--text-secondary); margin: 1rem 0;">
                     This page doesn't exist. Try navigating from the sidebar or go to the
-                    <a href="/" style="color: var(--accent-primary);">home page</a>.
+                    <a href=${BASE_PATH} style="color: var(--accent-primary);">home page</a>.
                   </p>
                 </div>
               `;
@@ -1014,21 +1021,29 @@ function CoverageViewRoute() {
   const impl = params.impl;
   const filter = query.filter || null;
   const level = query.level || "all";
+  const tag = query.tag || null;
 
   const [search, setSearch] = useState("");
 
   const handleLevelChange = useCallback(
     (newLevel: string) => {
-      route(buildUrl(spec, impl, "coverage", { filter, level: newLevel }));
+      route(buildUrl(spec, impl, "coverage", { filter, level: newLevel, tag }));
     },
-    [route, spec, impl, filter],
+    [route, spec, impl, filter, tag],
+  );
+
+  const handleTagChange = useCallback(
+    (newTag: string | null) => {
+      route(buildUrl(spec, impl, "coverage", { filter, level, tag: newTag }));
+    },
+    [route, spec, impl, filter, level],
   );
 
   const handleFilterChange = useCallback(
     (newFilter: string | null) => {
-      route(buildUrl(spec, impl, "coverage", { filter: newFilter, level }));
+      route(buildUrl(spec, impl, "coverage", { filter: newFilter, level, tag }));
     },
-    [route, spec, impl, level],
+    [route, spec, impl, level, tag],
   );
 
   const handleSelectRule = useCallback(
@@ -1053,6 +1068,8 @@ function CoverageViewRoute() {
       onSearchChange=${setSearch}
       level=${level}
       onLevelChange=${handleLevelChange}
+      tag=${tag}
+      onTagChange=${handleTagChange}
       filter=${filter}
       onFilterChange=${handleFilterChange}
       onSelectRule=${handleSelectRule}
@@ -1065,6 +1082,10 @@ function CoverageViewRoute() {
 // Mount
 // ========================================================================
 
+if (STATIC_MODE) {
+  document.body.classList.add("static-mode");
+}
+
 render(
   html`
     <${LocationProvider}>
@@ -1,5 +1,5 @@
 // Router utilities using preact-iso
-// r[impl dashboard.url.structure] - URL structure: /:spec/:impl/:view
+// r[impl dashboard.url.structure+2] - URL structure: /:spec/:impl/:view
 //
 // Examples:
 //   /rapace/rust/spec                     -> spec view, no heading
@@ -13,6 +13,23 @@ export { LocationProvider, Route, Router, useLocation, useRoute } from "preact-i
 
 import type { ViewType } from "./types";
 
+// Path the dashboard is served from: `/` for the daemon, or wherever an
+// exported site lives. Read once, since pages without a `<base>` resolve
+// against their own URL, which changes as the app navigates.
+export const BASE_PATH = new URL(".", document.baseURI).pathname;
+
+// The route's path segments, without the base path
+export function routeParts(): string[] {
+  const path = window.location.pathname;
+  const rest = path.startsWith(BASE_PATH) ? path.slice(BASE_PATH.length) : path;
+  return rest.split("/").filter(Boolean);
+}
+
+// A route path under the base path
+export function routePath(path: string): string {
+  return BASE_PATH + path.replace(/^\//, "");
+}
+
 export interface UrlParams {
   file?: string | null;
   line?: number | null;
@@ -21,6 +38,7 @@ export interface UrlParams {
   heading?: string | null;
   filter?: string | null;
   level?: string | null;
+  tag?: string | null;
 }
 
 export function buildUrl(
@@ -29,10 +47,10 @@ export function buildUrl(
   view: ViewType,
   params: UrlParams = {},
 ): string {
-  // Build base path: /{spec}/{impl}
+  // Build base path: /{spec}/{impl}, under the dashboard's base path
   const specPart = spec ? `/${encodeURIComponent(spec)}` : "";
   const implPart = impl ? `/${encodeURIComponent(impl)}` : "";
-  const base = specPart + implPart;
+  const base = BASE_PATH.replace(/\/$/, "") + specPart + implPart;
 
   // r[impl dashboard.url.sources-view]
   // r[impl dashboard.url.context]
@@ -62,6 +80,7 @@ export function buildUrl(
   const searchParams = new URLSearchParams();
   if (params.filter) searchParams.set("filter", params.filter);
   if (params.level && params.level !== "all") searchParams.set("level", params.level);
+  if (params.tag) searchParams.set("tag", params.tag);
   const query = searchParams.toString();
   return `${base}/coverage${query ? `?${query}` : ""}`;
 }
@@ -59,6 +59,7 @@ export interface CoverageRoute {
   impl: string | null;
   filter: string | null;
   level: string | null;
+  tag: string | null;
 }
 
 export type Route = SourcesRoute | SpecRoute | CoverageRoute;
@@ -214,6 +215,8 @@ export interface CoverageViewProps {
   onSearchChange: (search: string) => void;
   level: string;
   onLevelChange: (level: string) => void;
+  tag: string | null;
+  onTagChange: (tag: string | null) => void;
   filter: string | null;
   onFilterChange: (filter: string | null) => void;
   onSelectRule: (ruleId: string) => void;
@@ -258,7 +261,9 @@ export interface CodeViewProps {
 export interface FileRefProps {
   file: string;
   line: number;
-  type: "impl" | "verify";
+  type: "impl" | "verify" | "source";
+  /** Verb declared in the config, shown next to the reference */
+  verb?: string;
   onSelectFile: (path: string, line?: number | null) => void;
 }
 
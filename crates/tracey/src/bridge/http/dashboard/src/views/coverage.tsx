@@ -10,23 +10,29 @@ import { getStatClass, ruleIdToString } from "../utils";
 // r[impl dashboard.coverage.stats]
 // r[impl dashboard.coverage.req-links]
 // r[impl dashboard.coverage.ref-links]
+// r[impl dashboard.coverage.filter-tag]
 export function CoverageView({
 	data,
 	search,
 	level,
 	onLevelChange,
+	tag,
+	onTagChange,
 	filter,
 	onFilterChange,
 	onSelectRule,
 	onSelectFile,
 }: CoverageViewProps) {
 	const [levelOpen, setLevelOpen] = useState(false);
+	const [tagOpen, setTagOpen] = useState(false);
 
 	// Close dropdowns when clicking outside
 	useEffect(() => {
 		const handleClick = (e: Event) => {
 			if (!(e.target as HTMLElement).closest("#level-dropdown"))
 				setLevelOpen(false);
+			if (!(e.target as HTMLElement).closest("#tag-dropdown"))
+				setTagOpen(false);
 		};
 		document.addEventListener("click", handleClick);
 		return () => document.removeEventListener("click", handleClick);
@@ -38,6 +44,17 @@ export function CoverageView({
 		[data],
 	);
 
+	const allTags = useMemo(
+		() => [...new Set(allRules.flatMap((r) => r.tags ?? []))].sort(),
+		[allRules],
+	);
+
+	const hasTag = useCallback(
+		(rule: { tags?: string[] }) =>
+			!tag || (rule.tags ?? []).some((t) => t.toLowerCase() === tag.toLowerCase()),
+		[tag],
+	);
+
 	// Infer level from rule html if not explicitly set
 	const inferLevel = useCallback((rule: { level?: string; html?: string }) => {
 		if (rule.level) return rule.level.toLowerCase();
@@ -63,6 +80,9 @@ export function CoverageView({
 			rules = rules.filter((r) => inferLevel(r) === level);
 		}
 
+		// Filter by tag
+		rules = rules.filter(hasTag);
+
 		// Filter by coverage
 		if (filter === "impl") {
 			rules = rules.filter((r) => r.implRefs.length === 0);
@@ -81,24 +101,30 @@ export function CoverageView({
 		}
 
 		return rules;
-	}, [allRules, search, level, filter, inferLevel]);
+	}, [allRules, search, level, filter, inferLevel, hasTag]);
 
 	const stats = useMemo(() => {
-		let rules = allRules;
+		// Rules excluded by status (`exclude_statuses`) do not count.
+		let rules = allRules.filter((r) => !r.excluded);
 		if (level !== "all") {
 			rules = rules.filter((r) => inferLevel(r) === level);
 		}
+		rules = rules.filter(hasTag);
 		const total = rules.length;
 		const impl = rules.filter((r) => r.implRefs.length > 0).length;
 		const verify = rules.filter((r) => r.verifyRefs.length > 0).length;
+		const hasResults = rules.some((r) => r.verification);
+		const passing = rules.filter((r) => r.verification === "passed").length;
 		return {
 			total,
 			impl,
 			verify,
+			hasResults,
 			implPct: total ? (impl / total) * 100 : 0,
 			verifyPct: total ? (verify / total) * 100 : 0,
+			passingPct: total ? (passing / total) * 100 : 0,
 		};
-	}, [allRules, level, inferLevel]);
+	}, [allRules, level, inferLevel, hasTag]);
 
 	const mdIcon = html`<svg
     class="rule-icon"
@@ -134,6 +160,16 @@ export function CoverageView({
           >${stats.verifyPct.toFixed(1)}%</span
         >
       </div>
+      ${/* r[impl dashboard.coverage.test-results] */ null}
+      ${
+				stats.hasResults &&
+				html`<div class="stat">
+          <span class="stat-label">Passing Verification</span>
+          <span class="stat-value ${getStatClass(stats.passingPct)}"
+            >${stats.passingPct.toFixed(1)}%</span
+          >
+        </div>`
+			}
 
       <div class="custom-dropdown ${levelOpen ? "open" : ""}" id="level-dropdown">
         <div
@@ -175,6 +211,47 @@ export function CoverageView({
 					)}
         </div>
       </div>
+      ${
+				allTags.length > 0 &&
+				html`<div class="custom-dropdown ${tagOpen ? "open" : ""}" id="tag-dropdown">
+          <div
+            class="dropdown-selected"
+            onClick=${(e: Event) => {
+							e.stopPropagation();
+							setTagOpen(!tagOpen);
+						}}
+          >
+            <span>${tag ? `#${tag}` : "All tags"}</span>
+            <svg
+              class="chevron"
+              width="12"
+              height="12"
+              viewBox="0 0 24 24"
+              fill="none"
+              stroke="currentColor"
+              stroke-width="2"
+            >
+              <path d="M6 9l6 6 6-6" />
+            </svg>
+          </div>
+          <div class="dropdown-menu">
+            ${[null, ...allTags].map(
+							(t) => html`
+                <div
+                  key=${t ?? ""}
+                  class="dropdown-option ${tag === t ? "active" : ""}"
+                  onClick=${() => {
+										onTagChange(t);
+										setTagOpen(false);
+									}}
+                >
+                  <span>${t ? `#${t}` : "All tags"}</span>
+                </div>
+              `,
+						)}
+          </div>
+        </div>`
+			}
     </div>
     <div class="main">
       <div class="content">
@@ -200,6 +277,63 @@ export function CoverageView({
                       <div class="rule-id-row">
                         ${mdIcon}
                         <span class="rule-id">${ruleId}</span>
+                        ${/* r[impl dashboard.coverage.owners] */ null}
+                        ${
+													rule.owners?.length
+														? html`<span class="owners-badge" title="Owners"
+                              >${rule.owners.join(", ")}</span
+                            >`
+														: null
+												}
+                        ${(rule.tags ?? []).map(
+													(t) => html`<span
+                            key=${t}
+                            class="tag-badge"
+                            title=${`Only show rules tagged ${t}`}
+                            onClick=${(e: Event) => {
+															e.stopPropagation();
+															onTagChange(t);
+														}}
+                            >#${t}</span
+                          >`,
+												)}
+                        ${/* r[impl dashboard.coverage.needs-review] */ null}
+                        ${
+													rule.needsReview?.length
+														? html`<span
+                              class="needs-review-badge"
+                              title=${`Depends on changed ${rule.needsReview
+																.map((l) => ruleIdToString(l.id))
+																.join(", ")}`}
+                              >needs review</span
+                            >`
+														: null
+												}
+                        ${
+													rule.verification === "failed" ||
+													rule.verification === "not_run"
+														? html`<span
+                              class="test-outcome-badge ${rule.verification}"
+                              title=${rule.verifyRefs
+																.filter((r) => r.outcome && r.outcome !== "passed")
+																.map((r) => `${r.file}:${r.line} ${r.outcome}`)
+																.join("\n")}
+                              >${rule.verification === "failed"
+																? "tests failing"
+																: "tests not run"}</span
+                            >`
+														: null
+												}
+                        ${/* r[impl dashboard.coverage.runtime-coverage] */ null}
+                        ${
+													rule.executed === false
+														? html`<span
+                              class="not-executed-badge"
+                              title="The test suite never runs the code implementing this rule"
+                              >never executed</span
+                            >`
+														: null
+												}
                       </div>
                       ${
 												rule.html &&
@@ -211,7 +345,9 @@ export function CoverageView({
                     </td>
                     <td class="rule-refs" onClick=${(e: Event) => e.stopPropagation()}>
                       ${
-												rule.implRefs.length > 0 || rule.verifyRefs.length > 0
+												rule.implRefs.length > 0 ||
+												rule.verifyRefs.length > 0 ||
+												rule.otherRefs.length > 0
 													? html`
                             ${rule.implRefs.map(
 															(r) => html`
@@ -220,6 +356,7 @@ export function CoverageView({
                                   file=${r.file}
                                   line=${r.line}
                                   type="impl"
+                                  verb=${r.verb}
                                   onSelectFile=${onSelectFile}
                                 />
                               `,
@@ -231,6 +368,20 @@ export function CoverageView({
                                   file=${r.file}
                                   line=${r.line}
                                   type="verify"
+                                  verb=${r.verb}
+                                  onSelectFile=${onSelectFile}
+                                />
+                              `,
+														)}
+                            ${/* r[impl dashboard.coverage.custom-verbs] */ null}
+                            ${rule.otherRefs.map(
+															(r) => html`
+                                <${FileRef}
+                                  key=${`${r.verb}:${r.file}:${r.line}`}
+                                  file=${r.file}
+                                  line=${r.line}
+                                  type="source"
+                                  verb=${r.verb}
                                   onSelectFile=${onSelectFile}
                                 />
                               `,
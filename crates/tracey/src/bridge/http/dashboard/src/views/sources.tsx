@@ -197,17 +197,22 @@ export function CodeView({ file, config, selectedLine, selectedLineEnd, selected
   }, [selectedLine, file.path]);
 
   // Build line metadata from code units
+  // r[impl dashboard.sources.runtime-coverage]
   const lineMetadata = useMemo(() => {
-    const meta: Record<number, { rules: string[]; kind: string | null }> = {};
+    const meta: Record<number, { rules: string[]; kind: string | null; hits: number | null }> = {};
     for (const unit of file.units) {
       for (let line = unit.startLine; line <= unit.endLine; line++) {
         if (!meta[line]) {
-          meta[line] = { rules: [], kind: null };
+          meta[line] = { rules: [], kind: null, hits: null };
         }
         meta[line].rules.push(...unit.ruleRefs);
         if (line === unit.startLine) {
           meta[line].kind = unit.kind;
         }
+        // Units come outermost first, so the innermost unit's hits win.
+        if (unit.hits !== undefined) {
+          meta[line].hits = unit.hits;
+        }
       }
     }
     return meta;
@@ -242,6 +247,7 @@ export function CodeView({ file, config, selectedLine, selectedLineEnd, selected
             const lineNum = idx + 1;
             const meta = lineMetadata[lineNum];
             const hasRules = meta?.rules.length > 0;
+            const notExecuted = meta?.hits === 0;
             const isSelected = selectedLine !== null &&
               lineNum >= selectedLine &&
               lineNum <= (selectedLineEnd ?? selectedLine);
@@ -252,8 +258,9 @@ export function CodeView({ file, config, selectedLine, selectedLineEnd, selected
             return html`
               <tr
                 key=${lineNum}
-                class="code-line ${selectedClass} ${hasRules ? "has-rules" : ""}"
+                class="code-line ${selectedClass} ${hasRules ? "has-rules" : ""} ${notExecuted ? "not-executed" : ""}"
                 data-line=${lineNum}
+                title=${notExecuted ? "Never executed by the test suite" : undefined}
               >
                 <td class="line-number" onClick=${() => handleEditorOpen(lineNum)}>${lineNum}</td>
                 <td class="line-gutter">
@@ -409,6 +416,35 @@ export function SourcesView({
                       `,
                     )}
                   </div>
+                  ${/* r[impl dashboard.sources.rule-dependencies] */ null}
+                  ${[
+                    { label: "Depends on", links: contextRule.dependsOn ?? [] },
+                    { label: "Depended on by", links: contextRule.dependents ?? [] },
+                  ]
+                    .filter(({ links }) => links.length > 0)
+                    .map(
+                      ({ label, links }) => html`
+                        <div key=${label} class="rule-context-deps">
+                          <div class="rule-context-deps-label">${label}</div>
+                          ${links.map((link) => {
+                            const id = ruleIdToString(link.id);
+                            const sameSpec = forward?.specs.some((s) => s.name === link.spec);
+                            return html`
+                              <div
+                                key=${`${link.spec}:${id}`}
+                                class="rule-context-ref ${sameSpec ? "" : "external"}"
+                                onClick=${sameSpec ? () => onSelectRule(id) : undefined}
+                                title=${link.spec ?? "unknown rule"}
+                              >
+                                <span>${link.prefix}[${id}]</span>
+                                ${!sameSpec &&
+                                html`<span class="rule-context-deps-spec">${link.spec ?? "unknown"}</span>`}
+                              </div>
+                            `;
+                          })}
+                        </div>
+                      `,
+                    )}
                   <a class="rule-context-back" onClick=${() => onSelectRule(ruleContext)}>
                     ${backIcon}
                     <span>Back to rule in spec</span>
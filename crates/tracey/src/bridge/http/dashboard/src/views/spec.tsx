@@ -1,12 +1,13 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from "preact/hooks";
 import { render } from "preact";
 import { EDITORS } from "../config";
-import { useSpec } from "../hooks";
+import { STATIC_MODE, fetchJson, useSpec } from "../hooks";
 import { CoverageArc, html, showRefsPopup } from "../main";
 import type { OutlineEntry, SpecViewProps, FileContent } from "../types";
 import { MarkdownEditor } from "../components/MarkdownEditor";
 import { InlineEditor } from "../components/InlineEditor";
 import { CodeView } from "./sources";
+import { buildUrl } from "../router";
 
 // Tree node for hierarchical outline
 interface OutlineTreeNode {
@@ -178,7 +179,7 @@ function OutlineTree({
             ? "is-in-active-branch"
             : ""} ${isComplete ? "is-complete" : ""} ${isIncomplete ? "is-incomplete" : ""}"
         >
-          <a class="toc-row" href=${`/${specName}/${impl}/spec#${h.slug}`}>
+          <a class="toc-row" href=${buildUrl(specName, impl, "spec", { heading: h.slug })}>
             <span class="toc-link"> ${h.title} </span>
             ${showCoverage &&
             html`
@@ -787,9 +788,8 @@ export function SpecView({
           const params = new URLSearchParams({ path: file });
           if (specName) params.append("spec", specName);
           if (selectedImpl) params.append("impl", selectedImpl);
-          fetch(`/api/file?${params}`)
-            .then((res) => res.json())
-            .then((data: FileContent) => {
+          fetchJson<FileContent>(`/api/file?${params}`)
+            .then((data) => {
               console.log("Setting preview modal");
               // r[impl code-unit.nested.smallest]
               // Find the smallest code unit containing this line.
@@ -822,6 +822,8 @@ export function SpecView({
       const editBadge = target.closest("button.req-badge.req-edit") as HTMLElement | null;
       if (editBadge) {
         e.preventDefault();
+        // r[impl dashboard.static.read-only]
+        if (STATIC_MODE) return;
         const sourceFile = editBadge.dataset.sourceFile;
         const byteRange = editBadge.dataset.br;
         if (sourceFile && byteRange) {
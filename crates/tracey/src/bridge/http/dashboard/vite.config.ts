@@ -3,6 +3,9 @@ import preact from "@preact/preset-vite";
 
 export default defineConfig({
   plugins: [preact()],
+  // Assets are referenced relative to the page, so an exported site works
+  // from any directory. The live server pins the base to `/` instead.
+  base: "./",
   build: {
     outDir: "dist",
     emptyOutDir: true,
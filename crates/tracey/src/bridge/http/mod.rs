@@ -7,7 +7,7 @@
 //! r[impl daemon.bridge.http]
 
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 use std::time::Duration;
 
 use axum::{
@@ -113,6 +113,8 @@ pub async fn run(
     // r[impl dashboard.api.reverse]
     // r[impl dashboard.api.spec]
     // r[impl dashboard.api.file]
+    // r[impl dashboard.api.badge]
+    // r[impl dashboard.api.history]
     let app = Router::new()
         // WebSocket for live updates
         .route("/ws", get(ws_handler))
@@ -126,12 +128,15 @@ pub async fn run(
         .route("/api/search", get(api_search))
         .route("/api/status", get(api_status))
         .route("/api/validate", get(api_validate))
+        .route("/api/validate.sarif", get(api_validate_sarif))
         .route("/api/uncovered", get(api_uncovered))
         .route("/api/untested", get(api_untested))
         .route("/api/unmapped", get(api_unmapped))
         .route("/api/rule", get(api_rule))
         .route("/api/reload", get(api_reload))
-        .route("/api/health", get(api_health));
+        .route("/api/health", get(api_health))
+        .route("/api/badge/{spec}/{impl_name}/{file}", get(api_badge))
+        .route("/api/history", get(api_history));
 
     // In dev mode, proxy to Vite; otherwise serve embedded assets
     let app = if dev {
@@ -199,13 +204,18 @@ pub async fn run(
 }
 
 // Embedded dashboard assets (colocated in src/bridge/http/dashboard/)
-static INDEX_HTML: &str = include_str!("dashboard/dist/index.html");
-static INDEX_CSS: &str = include_str!("dashboard/dist/assets/index.css");
-static INDEX_JS: &str = include_str!("dashboard/dist/assets/index.js");
+pub(crate) static INDEX_HTML: &str = include_str!("dashboard/dist/index.html");
+pub(crate) static INDEX_CSS: &str = include_str!("dashboard/dist/assets/index.css");
+pub(crate) static INDEX_JS: &str = include_str!("dashboard/dist/assets/index.js");
+
+/// index.html with its base pinned to `/`. Assets are built with relative
+/// URLs for `tracey export-site`, which would break on nested routes.
+static SERVED_INDEX_HTML: LazyLock<String> =
+    LazyLock::new(|| INDEX_HTML.replacen("<head>", "<head>\n  <base href=\"/\">", 1));
 
 /// SPA fallback - serve index.html for all non-API routes.
 async fn spa_fallback() -> Html<&'static str> {
-    Html(INDEX_HTML)
+    Html(SERVED_INDEX_HTML.as_str())
 }
 
 /// Serve static assets from embedded files.
@@ -235,6 +245,15 @@ struct ImplQuery {
     impl_name: Option<String>,
 }
 
+/// Query parameters for the history endpoint.
+#[derive(Debug, Clone, Deserialize)]
+struct HistoryQuery {
+    spec: Option<String>,
+    #[serde(rename = "impl")]
+    impl_name: Option<String>,
+    limit: Option<usize>,
+}
+
 /// Query parameters for search endpoint.
 #[derive(Debug, Clone, Deserialize)]
 struct SearchQuery {
@@ -266,6 +285,19 @@ struct CoverageQuery {
     #[serde(rename = "impl")]
     impl_name: Option<String>,
     prefix: Option<String>,
+    level: Option<String>,
+    status: Option<String>,
+    owner: Option<String>,
+    tag: Option<String>,
+}
+
+/// Query parameters for the status endpoint.
+#[derive(Debug, Clone, Deserialize)]
+struct StatusQuery {
+    level: Option<String>,
+    status: Option<String>,
+    owner: Option<String>,
+    tag: Option<String>,
 }
 
 /// Query parameters for unmapped endpoint.
@@ -327,7 +359,6 @@ impl ApiError {
             .into_response()
     }
 
-    #[allow(dead_code)]
     fn internal(msg: impl Into<String>) -> Response {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -490,9 +521,19 @@ async fn api_search(
 }
 
 /// GET /api/status - Get coverage status.
-async fn api_status(State(state): State<Arc<AppState>>) -> Response {
+async fn api_status(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<StatusQuery>,
+) -> Response {
     let client = state.client.clone();
-    match rpc(client.status().await) {
+    let req = tracey_proto::StatusRequest {
+        level: query.level,
+        status: query.status,
+        owner: query.owner,
+        tag: query.tag,
+        results: vec![],
+    };
+    match rpc(client.status(req).await) {
         Ok(status) => Json(status).into_response(),
         Err(e) => e,
     }
@@ -523,6 +564,125 @@ async fn api_validate(
     }
 }
 
+/// GET /api/validate.sarif - Validation results as a SARIF log.
+///
+/// Without `spec`/`impl` query parameters, every spec/impl pair is validated,
+/// so the log can be uploaded to code-scanning tools as-is.
+async fn api_validate_sarif(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ImplQuery>,
+) -> Response {
+    let client = state.client.clone();
+
+    let config = match rpc(client.config().await) {
+        Ok(c) => c,
+        Err(e) => return e,
+    };
+
+    let pairs: Vec<(String, String)> = if query.spec.is_some() || query.impl_name.is_some() {
+        vec![resolve_spec_impl(query.spec, query.impl_name, &config)]
+    } else {
+        config
+            .specs
+            .iter()
+            .flat_map(|s| {
+                s.implementations
+                    .iter()
+                    .map(move |i| (s.name.clone(), i.clone()))
+            })
+            .collect()
+    };
+
+    let mut results = Vec::new();
+    for (spec, impl_name) in pairs {
+        let req = tracey_proto::ValidateRequest {
+            spec: Some(spec),
+            impl_name: Some(impl_name),
+        };
+        match rpc(client.validate(req).await) {
+            Ok(result) => results.push(result),
+            Err(e) => return e,
+        }
+    }
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/sarif+json")],
+        crate::sarif::render_sarif(&results),
+    )
+        .into_response()
+}
+
+/// GET /api/badge/{spec}/{impl}/{metric}.svg - Coverage badge.
+///
+/// `{metric}.json` returns the same badge as shields.io endpoint JSON.
+async fn api_badge(
+    State(state): State<Arc<AppState>>,
+    Path((spec, impl_name, file)): Path<(String, String, String)>,
+) -> Response {
+    use crate::badge::{Badge, BadgeFormat, BadgeMetric};
+
+    let Some((metric, format)) = file.rsplit_once('.') else {
+        return ApiError::not_found("Badge not found");
+    };
+    let Ok(format) = format.parse::<BadgeFormat>() else {
+        return ApiError::not_found("Badge not found");
+    };
+    let metric = match metric.parse::<BadgeMetric>() {
+        Ok(metric) => metric,
+        Err(e) => return ApiError::bad_request(e.to_string()),
+    };
+
+    let client = state.client.clone();
+    let forward = match rpc(client.forward(spec.clone(), impl_name.clone()).await) {
+        Ok(Some(forward)) => forward,
+        Ok(None) => return ApiError::not_found("Spec/impl not found"),
+        Err(e) => return e,
+    };
+    let reverse = if metric == BadgeMetric::Reverse {
+        match rpc(client.reverse(spec, impl_name).await) {
+            Ok(reverse) => reverse,
+            Err(e) => return e,
+        }
+    } else {
+        None
+    };
+
+    let badge = Badge::new(metric, &forward, reverse.as_ref());
+    let content_type = match format {
+        BadgeFormat::Svg => "image/svg+xml",
+        BadgeFormat::Json => "application/json",
+    };
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, content_type),
+            // Badges are embedded in READMEs; ask proxies not to keep stale copies.
+            (header::CACHE_CONTROL, "no-cache, max-age=0"),
+        ],
+        badge.render(format),
+    )
+        .into_response()
+}
+
+/// GET /api/history - Recorded coverage history, oldest commit first.
+///
+/// Read straight from `.tracey/history`, so it doesn't go through the daemon.
+async fn api_history(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HistoryQuery>,
+) -> Response {
+    match crate::history::load(
+        &state.project_root,
+        query.spec.as_deref(),
+        query.impl_name.as_deref(),
+        query.limit,
+    ) {
+        Ok(history) => Json(history).into_response(),
+        Err(e) => ApiError::internal(e.to_string()),
+    }
+}
+
 /// GET /api/uncovered - Get uncovered rules.
 async fn api_uncovered(
     State(state): State<Arc<AppState>>,
@@ -541,6 +701,10 @@ async fn api_uncovered(
         spec: Some(spec),
         impl_name: Some(impl_name),
         prefix: query.prefix,
+        level: query.level,
+        status: query.status,
+        owner: query.owner,
+        tag: query.tag,
     };
 
     match rpc(client.uncovered(req).await) {
@@ -567,6 +731,11 @@ async fn api_untested(
         spec: Some(spec),
         impl_name: Some(impl_name),
         prefix: query.prefix,
+        level: query.level,
+        status: query.status,
+        owner: query.owner,
+        tag: query.tag,
+        results: vec![],
     };
 
     match rpc(client.untested(req).await) {
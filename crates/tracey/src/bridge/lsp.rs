@@ -126,8 +126,9 @@ impl Backend {
         content: &str,
         old_id: &tracey_core::RuleId,
         new_id: &str,
+        verbs: &[String],
     ) -> Vec<TextEdit> {
-        let reqs = tracey_core::Reqs::extract_from_content(&PathBuf::new(), content);
+        let reqs = tracey_core::Reqs::extract_from_content(&PathBuf::new(), content, verbs);
         let old_text = old_id.to_string();
         let mut edits = Vec::new();
         for reference in &reqs.references {
@@ -175,6 +176,9 @@ impl Backend {
             return Ok(());
         }
 
+        let verbs = rpc(self.daemon_client.config().await)
+            .map(|config| crate::verbs::names(&config.verbs))
+            .unwrap_or_default();
         let walker = ignore::WalkBuilder::new(&self.project_root)
             .follow_links(true)
             .hidden(false)
@@ -199,7 +203,7 @@ impl Backend {
             let Ok(content) = std::fs::read_to_string(path) else {
                 continue;
             };
-            let edits = Self::replacement_edits_for_file(&content, &old_id, new_rule);
+            let edits = Self::replacement_edits_for_file(&content, &old_id, new_rule, &verbs);
             if edits.is_empty() {
                 continue;
             }
@@ -282,34 +286,31 @@ impl Backend {
             client.publish_diagnostics(uri, vec![], None).await;
         }
 
-        // Publish config error diagnostic on config file
+        // Config error diagnostic on the config file. Published together with
+        // any workspace diagnostics for that file (e.g. coverage thresholds),
+        // since publishing replaces a file's diagnostics.
         let config_path = project_root.join(".config/tracey/config.styx");
-        if let Ok(uri) = Url::from_file_path(&config_path) {
-            if let Some(error_msg) = config_error {
-                let diagnostic = Diagnostic {
-                    range: Range {
-                        start: Position {
-                            line: 0,
-                            character: 0,
-                        },
-                        end: Position {
-                            line: 0,
-                            character: 0,
-                        },
+        let mut config_diagnostics = Vec::new();
+        if let Some(error_msg) = config_error {
+            config_diagnostics.push(Diagnostic {
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 0,
                     },
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    code: Some(NumberOrString::String("config-error".into())),
-                    source: Some("tracey".into()),
-                    message: error_msg,
-                    ..Default::default()
-                };
-                client
-                    .publish_diagnostics(uri, vec![diagnostic], None)
-                    .await;
-            } else {
-                client.publish_diagnostics(uri, vec![], None).await;
-            }
+                    end: Position {
+                        line: 0,
+                        character: 0,
+                    },
+                },
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String("config-error".into())),
+                source: Some("tracey".into()),
+                message: error_msg,
+                ..Default::default()
+            });
         }
+        let mut config_published = false;
 
         // Publish diagnostics for all files in the latest rebuild snapshot.
         let mut published_paths = HashSet::new();
@@ -320,7 +321,7 @@ impl Backend {
                 continue;
             };
 
-            let diagnostics: Vec<Diagnostic> = file_diag
+            let mut diagnostics: Vec<Diagnostic> = file_diag
                 .diagnostics
                 .into_iter()
                 .map(|d| Diagnostic {
@@ -347,10 +348,21 @@ impl Backend {
                 })
                 .collect();
 
+            if abs_path == config_path {
+                diagnostics.splice(0..0, std::mem::take(&mut config_diagnostics));
+                config_published = true;
+            }
+
             client.publish_diagnostics(uri, diagnostics, None).await;
             published_paths.insert(abs_path_str);
         }
 
+        if !config_published && let Ok(uri) = Url::from_file_path(&config_path) {
+            client
+                .publish_diagnostics(uri, config_diagnostics, None)
+                .await;
+        }
+
         let mut state = doc_state.lock().unwrap();
         state.files_with_diagnostics = published_paths;
     }
@@ -555,7 +567,7 @@ impl LanguageServer for Backend {
         self.notify_vfs_close(&uri).await;
     }
 
-    /// r[impl lsp.completions.verb]
+    /// r[impl lsp.completions.verb+2]
     /// r[impl lsp.completions.req-id]
     /// r[impl lsp.completions.req-id-fuzzy]
     /// r[impl lsp.completions.req-id-preview]
@@ -644,6 +656,10 @@ impl LanguageServer for Backend {
         if let Some(url) = &info.spec_url {
             markdown.push_str(&format!(" ([source]({}))", url));
         }
+        // r[impl lsp.hover.owners]
+        if !info.owners.is_empty() {
+            markdown.push_str(&format!("\n\n**Owners:** {}", info.owners.join(", ")));
+        }
 
         // Format impl refs as clickable links
         if !info.impl_refs.is_empty() {
@@ -24,6 +24,7 @@ use rust_mcp_sdk::{McpServer, StdioTransport, ToMcpServerHandler, TransportOptio
 use serde::{Deserialize, Serialize};
 
 use crate::bridge::query;
+use crate::impact::ChangeSource;
 
 // ============================================================================
 // Tool Definitions (same as mcp.rs)
@@ -32,15 +33,28 @@ use crate::bridge::query;
 /// Get coverage status for all specs/implementations
 #[mcp_tool(
     name = "tracey_status",
-    description = "Get coverage overview for all specs and implementations. Shows current coverage percentages and what changed since last rebuild."
+    description = "Get coverage overview for all specs and implementations. Shows current coverage percentages, split by requirement level (MUST/SHOULD/MAY), and what changed since last rebuild. Optionally filter by level, status, owner or tag."
 )]
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
-pub struct StatusTool {}
+pub struct StatusTool {
+    /// Only consider rules of this RFC 2119 level: "must", "should" or "may"
+    #[serde(default)]
+    pub level: Option<String>,
+    /// Only consider rules with this status, e.g. "stable" or "draft"
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Only consider rules owned by this owner, e.g. "@payments"
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Only consider rules with this tag, e.g. "security"
+    #[serde(default)]
+    pub tag: Option<String>,
+}
 
 /// Get rules without implementation references
 #[mcp_tool(
     name = "tracey_uncovered",
-    description = "List rules that have no implementation references ([impl ...] comments). Optionally filter by spec/impl or rule ID prefix."
+    description = "List rules that have no implementation references ([impl ...] comments). Optionally filter by spec/impl, rule ID prefix, requirement level (must/should/may), status, owner or tag."
 )]
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct UncoveredTool {
@@ -48,6 +62,18 @@ pub struct UncoveredTool {
     pub spec_impl: Option<String>,
     #[serde(default)]
     pub prefix: Option<String>,
+    /// Only consider rules of this RFC 2119 level: "must", "should" or "may"
+    #[serde(default)]
+    pub level: Option<String>,
+    /// Only consider rules with this status, e.g. "stable" or "draft"
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Only consider rules owned by this owner, e.g. "@payments"
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Only consider rules with this tag, e.g. "security"
+    #[serde(default)]
+    pub tag: Option<String>,
 }
 
 /// Get rules without verification references
@@ -61,6 +87,18 @@ pub struct UntestedTool {
     pub spec_impl: Option<String>,
     #[serde(default)]
     pub prefix: Option<String>,
+    /// Only consider rules of this RFC 2119 level: "must", "should" or "may"
+    #[serde(default)]
+    pub level: Option<String>,
+    /// Only consider rules with this status, e.g. "stable" or "draft"
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Only consider rules owned by this owner, e.g. "@payments"
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Only consider rules with this tag, e.g. "security"
+    #[serde(default)]
+    pub tag: Option<String>,
 }
 
 /// List stale references (code pointing to older rule versions)
@@ -74,6 +112,18 @@ pub struct StaleTool {
     pub spec_impl: Option<String>,
     #[serde(default)]
     pub prefix: Option<String>,
+    /// Only consider rules of this RFC 2119 level: "must", "should" or "may"
+    #[serde(default)]
+    pub level: Option<String>,
+    /// Only consider rules with this status, e.g. "stable" or "draft"
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Only consider rules owned by this owner, e.g. "@payments"
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Only consider rules with this tag, e.g. "security"
+    #[serde(default)]
+    pub tag: Option<String>,
 }
 
 /// Get code units without rule references
@@ -161,6 +211,23 @@ pub struct ConfigIncludeTool {
     pub pattern: String,
 }
 
+/// r[impl mcp.tool.impact]
+///
+/// Find the rules a change touches
+#[mcp_tool(
+    name = "tracey_impact",
+    description = "List the requirements touched by a change, with the verification references (tests) to run for each. Analyzes uncommitted changes by default; set staged to analyze staged changes only, or revisions to a range like \"main..HEAD\"."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ImpactTool {
+    /// Revision range (e.g., "main..HEAD" or "main...HEAD")
+    #[serde(default)]
+    pub revisions: Option<String>,
+    /// Only analyze staged changes
+    #[serde(default)]
+    pub staged: Option<bool>,
+}
+
 // Create toolbox
 tool_box!(
     TraceyTools,
@@ -175,7 +242,8 @@ tool_box!(
         ReloadTool,
         ValidateTool,
         ConfigExcludeTool,
-        ConfigIncludeTool
+        ConfigIncludeTool,
+        ImpactTool
     ]
 );
 
@@ -216,23 +284,37 @@ impl ServerHandler for TraceyHandler {
         _runtime: Arc<dyn McpServer>,
     ) -> std::result::Result<CallToolResult, CallToolError> {
         let args = params.arguments.unwrap_or_default();
+        let filtered = || {
+            let level = args.get("level").and_then(|v| v.as_str()).map(String::from);
+            let status = args
+                .get("status")
+                .and_then(|v| v.as_str())
+                .map(String::from);
+            let owner = args.get("owner").and_then(|v| v.as_str()).map(String::from);
+            let tag = args.get("tag").and_then(|v| v.as_str()).map(String::from);
+            self.client
+                .clone()
+                .with_filter(level, status)
+                .with_owner(owner)
+                .with_tag(tag)
+        };
 
         let response = match params.name.as_str() {
-            "tracey_status" => self.client.status().await,
+            "tracey_status" => filtered().status().await,
             "tracey_uncovered" => {
                 let spec_impl = args.get("spec_impl").and_then(|v| v.as_str());
                 let prefix = args.get("prefix").and_then(|v| v.as_str());
-                self.client.uncovered(spec_impl, prefix).await
+                filtered().uncovered(spec_impl, prefix).await
             }
             "tracey_untested" => {
                 let spec_impl = args.get("spec_impl").and_then(|v| v.as_str());
                 let prefix = args.get("prefix").and_then(|v| v.as_str());
-                self.client.untested(spec_impl, prefix).await
+                filtered().untested(spec_impl, prefix).await
             }
             "tracey_stale" => {
                 let spec_impl = args.get("spec_impl").and_then(|v| v.as_str());
                 let prefix = args.get("prefix").and_then(|v| v.as_str());
-                self.client.stale(spec_impl, prefix).await
+                filtered().stale(spec_impl, prefix).await
             }
             "tracey_unmapped" => {
                 let spec_impl = args.get("spec_impl").and_then(|v| v.as_str());
@@ -280,6 +362,16 @@ impl ServerHandler for TraceyHandler {
                     }
                 }
             }
+            "tracey_impact" => {
+                let revisions = args.get("revisions").and_then(|v| v.as_str());
+                let staged = args.get("staged").and_then(|v| v.as_bool());
+                let source = match (revisions, staged) {
+                    (Some(range), _) => ChangeSource::Range(range.to_string()),
+                    (None, Some(true)) => ChangeSource::Staged,
+                    (None, _) => ChangeSource::WorkingTree,
+                };
+                self.client.impact(&source).await
+            }
             other => {
                 self.client
                     .with_config_banner(format!("Unknown tool: {}", other))
@@ -6,7 +6,10 @@
 use std::path::PathBuf;
 use std::{collections::BTreeMap, collections::BTreeSet};
 
+use facet::Facet;
+
 use crate::daemon::{DaemonClient, new_client};
+use crate::output::{self, OutputFormat};
 use tracey_core::parse_rule_id;
 use tracey_proto::*;
 
@@ -48,19 +51,113 @@ fn unknown_rule_reference_from_error(error: &ValidationError) -> Option<(String,
     Some((rule_id, reference))
 }
 
+/// JSON shape for `tracey query rule` with one or more rule IDs.
+#[derive(Facet)]
+struct RuleLookup {
+    rules: Vec<RuleInfo>,
+    errors: Vec<String>,
+}
+
+/// JSON shape for [`QueryClient::config_include`] and [`QueryClient::config_exclude`].
+#[derive(Facet)]
+struct PatternAdded {
+    /// `include` or `exclude`.
+    kind: String,
+    pattern: String,
+}
+
 /// Shared query client used by both MCP and CLI.
 #[derive(Clone)]
 pub struct QueryClient {
     client: DaemonClient,
+    project_root: PathBuf,
     caller: Caller,
+    format: OutputFormat,
+    level: Option<String>,
+    status: Option<String>,
+    owner: Option<String>,
+    tag: Option<String>,
+    results: Vec<ApiTestResult>,
 }
 
 impl QueryClient {
     pub fn new(project_root: PathBuf, caller: Caller) -> Self {
         Self {
-            client: new_client(project_root),
+            client: new_client(project_root.clone()),
+            project_root,
             caller,
+            format: OutputFormat::Text,
+            level: None,
+            status: None,
+            owner: None,
+            tag: None,
+            results: Vec::new(),
+        }
+    }
+
+    /// Select the output format for query results.
+    ///
+    /// `Text` (the default) is the markdown-like output shared with MCP.
+    /// `Markdown` is the same without terminal hints, `Html` renders it as a
+    /// standalone document, and `Json` serializes the daemon responses as-is.
+    /// `Sarif` is only supported by [`Self::validate`].
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Restrict status, uncovered, untested and stale queries to rules of an
+    /// RFC 2119 level (`must`, `should` or `may`) and/or a status.
+    pub fn with_filter(mut self, level: Option<String>, status: Option<String>) -> Self {
+        self.level = level.map(|l| l.to_lowercase());
+        self.status = status;
+        self
+    }
+
+    /// Restrict status, uncovered, untested and stale queries to rules owned
+    /// by `owner`, e.g. `@payments`.
+    pub fn with_owner(mut self, owner: Option<String>) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Restrict status, uncovered, untested and stale queries to rules with
+    /// `tag`, e.g. `security`.
+    pub fn with_tag(mut self, tag: Option<String>) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Judge `verify` references of status and untested queries by these
+    /// test results instead of the configured ones.
+    pub fn with_results(mut self, results: Vec<ApiTestResult>) -> Self {
+        self.results = results;
+        self
+    }
+
+    /// Describe the active filter, e.g.
+    /// ` (MUST rules tagged security owned by @payments)`.
+    fn filter_note(&self) -> String {
+        if self.level.is_none()
+            && self.status.is_none()
+            && self.owner.is_none()
+            && self.tag.is_none()
+        {
+            return String::new();
         }
+        let mut rules = match (&self.level, &self.status) {
+            (None, None) => "rules".to_string(),
+            (Some(level), None) => format!("{} rules", level.to_uppercase()),
+            (None, Some(status)) => format!("{status} rules"),
+            (Some(level), Some(status)) => format!("{status} {} rules", level.to_uppercase()),
+        };
+        if let Some(tag) = &self.tag {
+            rules.push_str(&format!(" tagged {tag}"));
+        }
+        if let Some(owner) = &self.owner {
+            rules.push_str(&format!(" owned by {owner}"));
+        }
+        format!(" ({rules})")
     }
 
     /// Check for config errors and return a warning banner if present.
@@ -79,7 +176,32 @@ impl QueryClient {
         }
     }
 
+    /// Finish a query response: prepend the config banner for human-readable
+    /// formats and render HTML if requested. JSON is returned untouched.
+    async fn finish(&self, output: String) -> String {
+        match self.format {
+            OutputFormat::Json | OutputFormat::Sarif => output,
+            OutputFormat::Text | OutputFormat::Markdown => self.with_config_banner(output).await,
+            OutputFormat::Html => {
+                let output = self.with_config_banner(output).await;
+                output::markdown_to_html("tracey", &output).await
+            }
+        }
+    }
+
+    /// Format an error in the current output format.
+    fn error(&self, e: impl std::fmt::Display) -> String {
+        match self.format {
+            OutputFormat::Json | OutputFormat::Sarif => output::json_error(e),
+            _ => format!("Error: {e}"),
+        }
+    }
+
     fn hint(&self, cli_text: &str, mcp_text: &str) -> String {
+        // Hints are for interactive use only; exported reports omit them.
+        if self.format != OutputFormat::Text {
+            return String::new();
+        }
         match self.caller {
             Caller::Cli => format!("→ Run `{cli_text}`\n"),
             Caller::Mcp => format!("→ Use {mcp_text}\n"),
@@ -91,10 +213,20 @@ impl QueryClient {
         // Fetch status first to establish a single connection/startup path.
         // Running status+config concurrently on a cold client can race daemon
         // autostart and introduce extra startup delays.
-        let status_result = self.client.status().await;
+        let status_result = self
+            .client
+            .status(StatusRequest {
+                level: self.level.clone(),
+                status: self.status.clone(),
+                owner: self.owner.clone(),
+                tag: self.tag.clone(),
+                results: self.results.clone(),
+            })
+            .await;
         let config_result = self.client.config().await;
 
         let output = match status_result {
+            Ok(status) if self.format == OutputFormat::Json => output::to_json(&status),
             Ok(status) => {
                 if status.impls.is_empty() {
                     return "No specs configured".to_string();
@@ -143,8 +275,12 @@ impl QueryClient {
                     let verified = impl_status.verified_rules;
 
                     output.push_str(&format!(
-                        "{}/{}: {} of {} requirements are covered.",
-                        impl_status.spec, impl_status.impl_name, covered, total
+                        "{}/{}: {} of {} requirements{} are covered.",
+                        impl_status.spec,
+                        impl_status.impl_name,
+                        covered,
+                        total,
+                        self.filter_note()
                     ));
 
                     if stale > 0 {
@@ -164,9 +300,96 @@ impl QueryClient {
                     }
 
                     output.push_str(&format!(
-                        " {} of {} have a verification reference.\n",
+                        " {} of {} have a verification reference.",
                         verified, total
                     ));
+
+                    if let (Some(passing), Some(failing)) =
+                        (impl_status.passing_rules, impl_status.failing_rules)
+                    {
+                        output.push_str(&format!(
+                            " {} of {} have passing verification",
+                            passing, total
+                        ));
+                        if failing > 0 {
+                            output.push_str(&format!(", {} have failing tests", failing));
+                        }
+                        output.push('.');
+                    }
+
+                    if let Some(executed) = impl_status.executed_rules {
+                        output.push_str(&format!(
+                            " The test suite executes the implementation of {} of {}.",
+                            executed, covered
+                        ));
+                    }
+                    output.push('\n');
+
+                    if impl_status.by_level.len() > 1 {
+                        let levels: Vec<String> = impl_status
+                            .by_level
+                            .iter()
+                            .map(|l| {
+                                format!(
+                                    "{} {}/{} covered, {}/{} verified",
+                                    l.level.to_uppercase(),
+                                    l.covered_rules,
+                                    l.total_rules,
+                                    l.verified_rules,
+                                    l.total_rules
+                                )
+                            })
+                            .collect();
+                        output.push_str(&format!("  By level: {}\n", levels.join("; ")));
+                    }
+
+                    if impl_status.by_owner.len() > 1 {
+                        let owners: Vec<String> = impl_status
+                            .by_owner
+                            .iter()
+                            .map(|o| {
+                                format!(
+                                    "{} {}/{} covered, {}/{} verified",
+                                    o.owner.as_deref().unwrap_or("(unowned)"),
+                                    o.covered_rules,
+                                    o.total_rules,
+                                    o.verified_rules,
+                                    o.total_rules
+                                )
+                            })
+                            .collect();
+                        output.push_str(&format!("  By owner: {}\n", owners.join("; ")));
+                    }
+
+                    if !impl_status.by_tag.is_empty() {
+                        let tags: Vec<String> = impl_status
+                            .by_tag
+                            .iter()
+                            .map(|t| {
+                                format!(
+                                    "{} {}/{} covered, {}/{} verified",
+                                    t.tag,
+                                    t.covered_rules,
+                                    t.total_rules,
+                                    t.verified_rules,
+                                    t.total_rules
+                                )
+                            })
+                            .collect();
+                        output.push_str(&format!("  By tag: {}\n", tags.join("; ")));
+                    }
+
+                    if impl_status.excluded_rules > 0 {
+                        output.push_str(&format!(
+                            "  {} rule(s) are left out because of their status \
+                             (exclude_statuses in the config).\n",
+                            impl_status.excluded_rules
+                        ));
+                    }
+
+                    for violation in &impl_status.threshold_violations {
+                        output.push_str(&format!("  Threshold not met: {}\n", violation));
+                    }
                 }
 
                 output.push_str("\n---\n");
@@ -193,10 +416,10 @@ impl QueryClient {
 
                 output
             }
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     /// Get rules without implementation references
@@ -207,16 +430,22 @@ impl QueryClient {
             spec,
             impl_name,
             prefix: prefix.map(String::from),
+            level: self.level.clone(),
+            status: self.status.clone(),
+            owner: self.owner.clone(),
+            tag: self.tag.clone(),
         };
 
         let output = match self.client.uncovered(req).await {
+            Ok(response) if self.format == OutputFormat::Json => output::to_json(&response),
             Ok(response) => {
                 let mut output = format!(
-                    "{}/{}: {} uncovered out of {} rules\n\n",
+                    "{}/{}: {} uncovered out of {} rules{}\n\n",
                     response.spec,
                     response.impl_name,
                     response.uncovered_count,
-                    response.total_rules
+                    response.total_rules,
+                    self.filter_note()
                 );
 
                 for section in &response.by_section {
@@ -241,10 +470,10 @@ impl QueryClient {
 
                 output
             }
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     /// Get rules without verification references
@@ -255,23 +484,35 @@ impl QueryClient {
             spec,
             impl_name,
             prefix: prefix.map(String::from),
+            level: self.level.clone(),
+            status: self.status.clone(),
+            owner: self.owner.clone(),
+            tag: self.tag.clone(),
+            results: self.results.clone(),
         };
 
         let output = match self.client.untested(req).await {
+            Ok(response) if self.format == OutputFormat::Json => output::to_json(&response),
             Ok(response) => {
                 let mut output = format!(
-                    "{}/{}: {} untested (impl but no verify) out of {} rules\n\n",
+                    "{}/{}: {} untested (impl but no verify) out of {} rules{}\n\n",
                     response.spec,
                     response.impl_name,
                     response.untested_count,
-                    response.total_rules
+                    response.total_rules,
+                    self.filter_note()
                 );
 
                 for section in &response.by_section {
                     if !section.rules.is_empty() {
                         output.push_str(&format!("## {}\n", section.section));
                         for rule in &section.rules {
-                            output.push_str(&format!("  - {}\n", rule.id));
+                            let note = match rule.verification {
+                                Some(TestOutcome::Failed) => " (failing)",
+                                Some(TestOutcome::NotRun) => " (not run)",
+                                _ => "",
+                            };
+                            output.push_str(&format!("  - {}{}\n", rule.id, note));
                         }
                         output.push('\n');
                     }
@@ -289,10 +530,10 @@ impl QueryClient {
 
                 output
             }
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     /// Get code units without rule references
@@ -306,6 +547,7 @@ impl QueryClient {
         };
 
         let output = match self.client.unmapped(req).await {
+            Ok(response) if self.format == OutputFormat::Json => output::to_json(&response),
             Ok(response) => {
                 let mut output = format!(
                     "{}/{}: {} unmapped code units out of {} total\n\n",
@@ -367,10 +609,10 @@ impl QueryClient {
 
                 output
             }
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     /// Get stale references (code pointing to older rule versions)
@@ -381,15 +623,30 @@ impl QueryClient {
             spec,
             impl_name,
             prefix: prefix.map(String::from),
+            level: self.level.clone(),
+            status: self.status.clone(),
+            owner: self.owner.clone(),
+            tag: self.tag.clone(),
         };
 
         let output = match self.client.stale(req).await {
+            Ok(response) if self.format == OutputFormat::Json => output::to_json(&response),
             Ok(response) => {
-                if response.stale_count == 0 {
+                if response.stale_count == 0 && response.needs_review.is_empty() {
                     format!(
-                        "{}/{}: no stale references ({} rules total)\n",
-                        response.spec, response.impl_name, response.total_rules
+                        "{}/{}: no stale references ({} rules{} total)\n",
+                        response.spec,
+                        response.impl_name,
+                        response.total_rules,
+                        self.filter_note()
                     )
+                } else if response.stale_count == 0 {
+                    let mut output = format!(
+                        "# Stale references in {}/{}\n\nNo stale references\n\n",
+                        response.spec, response.impl_name
+                    );
+                    output.push_str(&format_needs_review(&response.needs_review));
+                    output
                 } else {
                     let mut output = format!(
                         "# Stale references in {}/{}\n\n{} stale reference(s) across {} file(s)\n\n",
@@ -416,6 +673,10 @@ impl QueryClient {
                             entry.line, entry.reference_id, entry.current_id
                         ));
                     }
+                    if !response.needs_review.is_empty() {
+                        output.push('\n');
+                        output.push_str(&format_needs_review(&response.needs_review));
+                    }
 
                     output.push_str("\n---\n");
                     output.push_str(&self.hint(
@@ -430,27 +691,35 @@ impl QueryClient {
                     output
                 }
             }
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     pub async fn rule(&self, rule_id: &str) -> String {
         let Some(rule_id) = parse_rule_id(rule_id) else {
-            return "Error: invalid rule ID".to_string();
+            return self.error("invalid rule ID");
         };
 
         let output = match self.client.rule(rule_id.clone()).await {
+            Ok(Some(info)) if self.format == OutputFormat::Json => output::to_json(&info),
             Ok(Some(info)) => format_rule_info(&info),
+            Ok(None) if self.format == OutputFormat::Json => {
+                output::json_error(format!("Rule not found: {}", rule_id))
+            }
             Ok(None) => format!("Rule not found: {}", rule_id),
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     pub async fn rules(&self, rule_ids: &[String]) -> String {
+        if self.format == OutputFormat::Json {
+            return self.rules_json(rule_ids).await;
+        }
+
         let mut sections = Vec::new();
 
         for raw_id in rule_ids {
@@ -467,12 +736,39 @@ impl QueryClient {
         }
 
         let output = sections.join("\n---\n\n");
-        self.with_config_banner(output).await
+        self.finish(output).await
+    }
+
+    /// JSON variant of [`Self::rules`]: found rules and lookup errors are
+    /// reported separately so the shape does not depend on what was found.
+    async fn rules_json(&self, rule_ids: &[String]) -> String {
+        let mut lookup = RuleLookup {
+            rules: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        for raw_id in rule_ids {
+            let Some(rule_id) = parse_rule_id(raw_id) else {
+                lookup.errors.push(format!("invalid rule ID '{}'", raw_id));
+                continue;
+            };
+
+            match self.client.rule(rule_id.clone()).await {
+                Ok(Some(info)) => lookup.rules.push(info),
+                Ok(None) => lookup.errors.push(format!("Rule not found: {}", rule_id)),
+                Err(e) => lookup
+                    .errors
+                    .push(format!("Error querying '{}': {e}", rule_id)),
+            }
+        }
+
+        output::to_json(&lookup)
     }
 
     /// Display current configuration
     pub async fn config(&self) -> String {
         let output = match self.client.config().await {
+            Ok(config) if self.format == OutputFormat::Json => output::to_json(&config),
             Ok(config) => {
                 let mut output = String::from("# Tracey Configuration\n\n");
 
@@ -482,6 +778,9 @@ impl QueryClient {
                     if let Some(source) = &spec.source {
                         output.push_str(&format!("  Source: {}\n", source));
                     }
+                    if let Some(impls_from) = &spec.impls_from {
+                        output.push_str(&format!("  Impls from: {}\n", impls_from));
+                    }
                     output.push_str(&format!(
                         "  Implementations: {}\n\n",
                         spec.implementations.join(", ")
@@ -490,41 +789,96 @@ impl QueryClient {
 
                 output
             }
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
+        };
+
+        self.finish(output).await
+    }
+
+    /// Show recorded coverage over time.
+    ///
+    /// The history is read from disk, so this works without a running daemon.
+    pub async fn trend(&self, spec_impl: Option<&str>, limit: Option<usize>) -> String {
+        let (spec, impl_name) = parse_spec_impl(spec_impl);
+        let output = match crate::history::load(
+            &self.project_root,
+            spec.as_deref(),
+            impl_name.as_deref(),
+            limit,
+        ) {
+            Ok(history) if self.format == OutputFormat::Json => output::to_json(&history),
+            Ok(history) => crate::history::format_trend(&history),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
+    }
+
+    /// Rules touched by uncommitted changes, staged changes or a revision
+    /// range, with the tests to run. Uncommitted changes are mapped onto the
+    /// daemon's current data, the others onto the revision they refer to.
+    pub async fn impact(&self, source: &crate::impact::ChangeSource) -> String {
+        let files = match crate::impact::changed_lines(&self.project_root, source) {
+            Ok(files) => files,
+            Err(e) => return self.finish(self.error(e)).await,
+        };
+        let revision = match crate::impact::changed_revision(&self.project_root, source) {
+            Ok(revision) => revision,
+            Err(e) => return self.finish(self.error(e)).await,
+        };
+
+        let output = match self.client.impact(ImpactRequest { files, revision }).await {
+            Ok(response) if self.format == OutputFormat::Json => output::to_json(&response),
+            Ok(response) => {
+                let mut output = crate::impact::format_impact(&response);
+                if !response.rules.is_empty() {
+                    output.push_str("---\n");
+                    output.push_str(&self.hint(
+                        "tracey query rule <rule-id>",
+                        "tracey_rule to see details about a specific rule",
+                    ));
+                }
+                output
+            }
+            Err(e) => self.error(e),
+        };
+
+        self.finish(output).await
     }
 
     pub async fn reload(&self) -> String {
         let output = match self.client.reload().await {
+            Ok(response) if self.format == OutputFormat::Json => output::to_json(&response),
             Ok(response) => format!(
                 "Reload complete (version {}, took {}ms)",
                 response.version, response.rebuild_time_ms
             ),
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     pub async fn validate(&self, spec_impl: Option<&str>) -> String {
+        match self.format {
+            OutputFormat::Json | OutputFormat::Sarif => return self.validate_json(spec_impl).await,
+            _ => {}
+        }
+
         let output = if spec_impl.is_some() {
             // If a specific spec/impl was requested, validate just that one.
             let (spec, impl_name) = parse_spec_impl(spec_impl);
             let req = ValidateRequest { spec, impl_name };
             match self.client.validate(req).await {
                 Ok(result) => format_validation_result(&result),
-                Err(e) => format!("Error: {e}"),
+                Err(e) => self.error(e),
             }
         } else {
             // No filter provided: validate ALL spec/impl combinations.
-            let status = match self.client.status().await {
+            let status = match self.client.status(StatusRequest::default()).await {
                 Ok(s) => s,
                 Err(e) => {
-                    return self
-                        .with_config_banner(format!("Error getting status: {e}"))
-                        .await;
+                    return self.finish(format!("Error getting status: {e}")).await;
                 }
             };
 
@@ -637,7 +991,43 @@ impl QueryClient {
             }
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
+    }
+
+    /// JSON/SARIF variant of [`Self::validate`]. JSON is always an array of
+    /// validation results, one per spec/impl combination that was validated;
+    /// SARIF is a single log covering all of them.
+    async fn validate_json(&self, spec_impl: Option<&str>) -> String {
+        let requests = if spec_impl.is_some() {
+            let (spec, impl_name) = parse_spec_impl(spec_impl);
+            vec![ValidateRequest { spec, impl_name }]
+        } else {
+            match self.client.status(StatusRequest::default()).await {
+                Ok(status) => status
+                    .impls
+                    .into_iter()
+                    .map(|s| ValidateRequest {
+                        spec: Some(s.spec),
+                        impl_name: Some(s.impl_name),
+                    })
+                    .collect(),
+                Err(e) => return self.error(e),
+            }
+        };
+
+        let mut results = Vec::new();
+        for req in requests {
+            match self.client.validate(req).await {
+                Ok(result) => results.push(result),
+                Err(e) => return self.error(e),
+            }
+        }
+
+        if self.format == OutputFormat::Sarif {
+            crate::sarif::render_sarif(&results)
+        } else {
+            output::to_json(&results)
+        }
     }
 
     pub async fn config_exclude(&self, spec_impl: Option<&str>, pattern: &str) -> String {
@@ -650,11 +1040,15 @@ impl QueryClient {
         };
 
         let output = match self.client.config_add_exclude(req).await {
+            Ok(()) if self.format == OutputFormat::Json => output::to_json(&PatternAdded {
+                kind: "exclude".to_string(),
+                pattern: pattern.to_string(),
+            }),
             Ok(()) => format!("Added exclude pattern: {pattern}"),
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 
     pub async fn config_include(&self, spec_impl: Option<&str>, pattern: &str) -> String {
@@ -667,11 +1061,15 @@ impl QueryClient {
         };
 
         let output = match self.client.config_add_include(req).await {
+            Ok(()) if self.format == OutputFormat::Json => output::to_json(&PatternAdded {
+                kind: "include".to_string(),
+                pattern: pattern.to_string(),
+            }),
             Ok(()) => format!("Added include pattern: {pattern}"),
-            Err(e) => format!("Error: {e}"),
+            Err(e) => self.error(e),
         };
 
-        self.with_config_banner(output).await
+        self.finish(output).await
     }
 }
 
@@ -685,16 +1083,32 @@ fn format_rule_info(info: &RuleInfo) -> String {
         output.push_str(&format!("Defined in: {}:{}\n\n", file, line));
     }
 
+    if !info.owners.is_empty() {
+        output.push_str(&format!("Owners: {}\n\n", info.owners.join(", ")));
+    }
+
+    if !info.tags.is_empty() {
+        output.push_str(&format!("Tags: {}\n\n", info.tags.join(", ")));
+    }
+
     if let Some(diff) = &info.version_diff {
         output.push_str(&format!("## Changes from previous version\n\n{diff}\n\n"));
     }
 
     for cov in &info.coverage {
         output.push_str(&format!("\n## {}/{}\n", cov.spec, cov.impl_name));
+        if cov.executed == Some(false) {
+            output.push_str("Never executed by the test suite.\n");
+        }
         if !cov.impl_refs.is_empty() {
             output.push_str("Impl references:\n");
             for r in &cov.impl_refs {
-                output.push_str(&format!("  - {}:{}\n", r.file, r.line));
+                let hits = match r.hits {
+                    Some(0) => " (never executed)".to_string(),
+                    Some(hits) => format!(" ({hits} hits)"),
+                    None => String::new(),
+                };
+                output.push_str(&format!("  - {}:{}{}\n", r.file, r.line, hits));
             }
         }
         if !cov.verify_refs.is_empty() {
@@ -708,6 +1122,33 @@ fn format_rule_info(info: &RuleInfo) -> String {
     output
 }
 
+/// Format the rules that need review after a rule they depend on was bumped.
+fn format_needs_review(entries: &[NeedsReviewEntry]) -> String {
+    let mut output = format!(
+        "## Needs review\n\n{} rule(s) need review\n\n",
+        entries.len()
+    );
+    for entry in entries {
+        let changed: Vec<String> = entry
+            .changed
+            .iter()
+            .map(|link| match &link.spec {
+                Some(spec) => format!("'{}' ({spec})", link.id),
+                None => format!("'{}'", link.id),
+            })
+            .collect();
+        output.push_str(&format!(
+            "### {}\n  - depends on changed {}\n",
+            entry.id,
+            changed.join(", ")
+        ));
+        for r in &entry.impl_refs {
+            output.push_str(&format!("  - impl: {}:{}\n", r.file, r.line));
+        }
+    }
+    output
+}
+
 /// Format a validation result for display.
 fn format_validation_result(result: &tracey_proto::ValidationResult) -> String {
     if result.errors.is_empty() {
@@ -717,9 +1158,13 @@ fn format_validation_result(result: &tracey_proto::ValidationResult) -> String {
         )
     } else {
         let mut output = format!(
-            "✗ {}/{}: {} error(s) found\n",
+            "✗ {}/{}: {} error(s) found",
             result.spec, result.impl_name, result.error_count
         );
+        if result.warning_count > 0 {
+            output.push_str(&format!(", {} warning(s)", result.warning_count));
+        }
+        output.push('\n');
 
         for error in &result.errors {
             let location = match (&error.file, error.line) {
@@ -832,14 +1277,19 @@ mod tests {
             html: format!("<p>Rule text for {}</p>", base),
             source_file: Some("docs/spec.md".to_string()),
             source_line: Some(10),
+            owners: vec![],
+            tags: vec![],
             coverage: vec![RuleCoverage {
                 spec: "test-spec".to_string(),
                 impl_name: "main".to_string(),
                 impl_refs: vec![ApiCodeRef {
                     file: "src/lib.rs".to_string(),
                     line: 42,
+                    outcome: None,
+                    hits: None,
                 }],
                 verify_refs: vec![],
+                executed: None,
             }],
             version_diff: None,
         }
@@ -884,6 +1334,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_rule_info_marks_unexecuted_impl_refs() {
+        let mut info = make_rule_info("foo.bar", 1);
+        info.coverage[0].impl_refs[0].hits = Some(0);
+        info.coverage[0].executed = Some(false);
+        let output = format_rule_info(&info);
+        assert!(
+            output.contains("Never executed by the test suite."),
+            "output:\n{}",
+            output
+        );
+        assert!(
+            output.contains("src/lib.rs:42 (never executed)"),
+            "output:\n{}",
+            output
+        );
+
+        info.coverage[0].impl_refs[0].hits = Some(3);
+        info.coverage[0].executed = Some(true);
+        let output = format_rule_info(&info);
+        assert!(
+            output.contains("src/lib.rs:42 (3 hits)"),
+            "output:\n{}",
+            output
+        );
+        assert!(!output.contains("Never executed"), "output:\n{}", output);
+    }
+
     #[test]
     fn format_rule_info_no_coverage() {
         let mut info = make_rule_info("lonely.rule", 1);
@@ -892,6 +1370,7 @@ mod tests {
             impl_name: "main".to_string(),
             impl_refs: vec![],
             verify_refs: vec![],
+            executed: None,
         }];
         let output = format_rule_info(&info);
         // Should have the spec/impl heading but no "Impl references:" section
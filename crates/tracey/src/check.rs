@@ -0,0 +1,236 @@
+//! `tracey check` implementation.
+//!
+//! Like `pre-commit` and `bump`, this command does not require the daemon. It
+//! builds dashboard data in-process, evaluates every spec/impl pair against the
+//! configured and requested thresholds, and reports pass/fail. Intended for CI pipelines where
+//! spawning a background process is undesirable.
+
+use eyre::Result;
+use owo_colors::OwoColorize;
+use std::collections::HashSet;
+use std::path::Path;
+
+use tracey_api::{ApiRule, ValidationErrorCode};
+use tracey_core::{CoverageReport, RuleId};
+
+use crate::config::Config;
+use crate::data::{ConfigFile, DashboardData, build_dashboard_data_from_file};
+use crate::server::CoverageStats;
+
+/// Thresholds and failure conditions for `tracey check`.
+#[derive(Debug, Clone, Default)]
+pub struct CheckOptions {
+    /// Minimum implementation coverage percentage (0-100).
+    pub min_impl: Option<f64>,
+    /// Minimum verification coverage percentage (0-100).
+    pub min_verify: Option<f64>,
+    /// Fail if any rule has stale references.
+    pub fail_on_stale: bool,
+    /// Fail if validation reports any errors.
+    pub fail_on_validation_errors: bool,
+}
+
+/// Check outcome for a single spec/impl pair.
+#[derive(Debug, Clone)]
+pub struct ImplCheck {
+    pub spec: String,
+    pub impl_name: String,
+    pub stats: CoverageStats,
+    pub validation_errors: usize,
+    /// Human-readable reasons this pair failed. Empty if it passed.
+    pub failures: Vec<String>,
+}
+
+impl ImplCheck {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Check outcome for the whole project.
+#[derive(Debug, Clone)]
+pub struct CheckReport {
+    pub impls: Vec<ImplCheck>,
+}
+
+impl CheckReport {
+    /// Whether every spec/impl pair passed.
+    pub fn passed(&self) -> bool {
+        self.impls.iter().all(ImplCheck::passed)
+    }
+
+    pub fn format_text(&self) -> String {
+        let mut out = String::new();
+
+        if self.impls.is_empty() {
+            out.push_str("No spec/impl pairs configured.\n");
+            return out;
+        }
+
+        for check in &self.impls {
+            let s = &check.stats;
+            let marker = if check.passed() {
+                "PASS".green().bold().to_string()
+            } else {
+                "FAIL".red().bold().to_string()
+            };
+            out.push_str(&format!(
+                "{} {}/{}: {:.0}% impl, {:.0}% verify, {} stale, {} validation errors ({} rules)\n",
+                marker,
+                check.spec,
+                check.impl_name,
+                s.impl_percent,
+                s.verify_percent,
+                s.stale_covered,
+                check.validation_errors,
+                s.total_rules
+            ));
+            for failure in &check.failures {
+                out.push_str(&format!("  {} {}\n", "-".red(), failure));
+            }
+        }
+
+        let failed = self.impls.iter().filter(|c| !c.passed()).count();
+        out.push('\n');
+        if failed == 0 {
+            out.push_str(&format!(
+                "{} {} spec/impl pair(s) passed\n",
+                "✓".green(),
+                self.impls.len()
+            ));
+        } else {
+            out.push_str(&format!(
+                "{} {} of {} spec/impl pair(s) failed\n",
+                "✗".red(),
+                failed,
+                self.impls.len()
+            ));
+        }
+
+        out
+    }
+}
+
+/// Build a `CoverageReport` treating rules matching `is_covered` as covered.
+///
+/// Invalid references are reported through validation instead, so the report
+/// only carries the covered/uncovered split needed for `is_passing`. Excluded
+/// rules are left out, matching the coverage numbers shown elsewhere.
+fn coverage_report(
+    spec_name: &str,
+    rules: &[ApiRule],
+    is_covered: impl Fn(&ApiRule) -> bool,
+) -> CoverageReport {
+    let mut covered_rules: HashSet<RuleId> = HashSet::new();
+    let mut uncovered_rules: HashSet<RuleId> = HashSet::new();
+    for rule in rules.iter().filter(|r| !r.excluded) {
+        if is_covered(rule) {
+            covered_rules.insert(rule.id.clone());
+        } else {
+            uncovered_rules.insert(rule.id.clone());
+        }
+    }
+
+    CoverageReport {
+        spec_name: spec_name.to_string(),
+        total_rules: covered_rules.len() + uncovered_rules.len(),
+        covered_rules,
+        uncovered_rules,
+        invalid_references: Vec::new(),
+        references_by_rule: Default::default(),
+        references_by_verb: Default::default(),
+    }
+}
+
+/// Evaluate already-built dashboard data against the check options.
+pub fn evaluate(data: &DashboardData, options: &CheckOptions) -> CheckReport {
+    let mut impls = Vec::new();
+
+    for ((spec, impl_name), forward) in &data.forward_by_impl {
+        let stats = CoverageStats::from_rules(&forward.rules);
+        let validation = data
+            .validation_by_impl
+            .get(&(spec.clone(), impl_name.clone()));
+        // Threshold breaches are reported as failures of their own below,
+        // so they aren't counted again as validation errors.
+        let validation_errors = validation
+            .map(|v| {
+                let thresholds = v
+                    .errors
+                    .iter()
+                    .filter(|e| e.code == ValidationErrorCode::CoverageThreshold)
+                    .count();
+                v.error_count - thresholds
+            })
+            .unwrap_or(0);
+
+        let mut failures = Vec::new();
+
+        // Thresholds from the config always apply; the options add to them.
+        if let Some(validation) = validation {
+            failures.extend(
+                validation
+                    .errors
+                    .iter()
+                    .filter(|e| e.code == ValidationErrorCode::CoverageThreshold)
+                    .map(|e| e.message.clone()),
+            );
+        }
+
+        if let Some(min) = options.min_impl {
+            let report = coverage_report(spec, &forward.rules, |r| {
+                !r.is_stale && !r.impl_refs.is_empty()
+            });
+            if !report.is_passing(min) {
+                failures.push(format!(
+                    "impl coverage {:.1}% is below the required {:.1}%",
+                    report.coverage_percent(),
+                    min
+                ));
+            }
+        }
+
+        if let Some(min) = options.min_verify {
+            let report = coverage_report(spec, &forward.rules, |r| !r.verify_refs.is_empty());
+            if !report.is_passing(min) {
+                failures.push(format!(
+                    "verify coverage {:.1}% is below the required {:.1}%",
+                    report.coverage_percent(),
+                    min
+                ));
+            }
+        }
+
+        if options.fail_on_stale && stats.stale_covered > 0 {
+            failures.push(format!(
+                "{} rule(s) have stale references",
+                stats.stale_covered
+            ));
+        }
+
+        if options.fail_on_validation_errors && validation_errors > 0 {
+            failures.push(format!("{} validation error(s)", validation_errors));
+        }
+
+        impls.push(ImplCheck {
+            spec: spec.clone(),
+            impl_name: impl_name.clone(),
+            stats,
+            validation_errors,
+            failures,
+        });
+    }
+
+    CheckReport { impls }
+}
+
+/// Build dashboard data in-process and evaluate it against the check options.
+pub async fn check(
+    project_root: &Path,
+    config: &Config,
+    config_file: &ConfigFile,
+    options: &CheckOptions,
+) -> Result<CheckReport> {
+    let data = build_dashboard_data_from_file(project_root, config, config_file, 1, true).await?;
+    Ok(evaluate(&data, options))
+}
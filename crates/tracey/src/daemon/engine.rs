@@ -19,7 +19,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::config::Config;
 use crate::data::{
-    BuildCache, DashboardData, FileOverlay, build_dashboard_data_with_overlay_and_cache,
+    BuildCache, ConfigFile, DashboardData, FileOverlay, build_dashboard_data_with_overlay_and_cache,
 };
 use crate::search::{self, SearchIndex, SearchResult};
 
@@ -41,8 +41,11 @@ pub struct Engine {
     project_root: PathBuf,
     /// Path to config file
     config_path: PathBuf,
-    /// Current config (reloaded on changes)
+    /// Current config (reloaded on changes), with the implementations of
+    /// `impls_from` discovered
     config: Arc<RwLock<Config>>,
+    /// The config file the current config was read from
+    config_file: Arc<RwLock<ConfigFile>>,
     /// Version counter
     version: Arc<std::sync::atomic::AtomicU64>,
     /// Current config error (if config file has errors)
@@ -88,13 +91,23 @@ impl Engine {
         let deprecated_error = Self::check_deprecated_configs(&project_root);
 
         // Load initial config - record errors but continue with empty config
+        let mut config_file = ConfigFile::new(&project_root, &config_path, String::new());
         let (mut config, mut config_error) = if let Some(err) = deprecated_error {
             // Deprecated config found - use empty config and record error
             (Config::default(), Some(err))
         } else {
             match tokio::fs::read_to_string(&config_path).await {
-                Ok(content) => match facet_styx::from_str(&content) {
-                    Ok(config) => (config, None),
+                Ok(content) => match crate::config::parse(&content) {
+                    Ok(config) => match crate::discover::expand(&config, &project_root) {
+                        Ok(config) => {
+                            config_file.text = content.clone();
+                            (config, None)
+                        }
+                        Err(e) => {
+                            let err = Self::format_config_error(&config_path, e);
+                            (Config::default(), Some(err))
+                        }
+                    },
                     Err(e) => {
                         // Config has errors - use empty config and record error
                         let err = Self::format_config_error(&config_path, e);
@@ -123,6 +136,7 @@ impl Engine {
         let data = match build_dashboard_data_with_overlay_and_cache(
             &project_root,
             &config,
+            &config_file,
             1,
             false,
             &overlay,
@@ -140,6 +154,7 @@ impl Engine {
                 build_dashboard_data_with_overlay_and_cache(
                     &project_root,
                     &config,
+                    &config_file,
                     1,
                     false,
                     &overlay,
@@ -195,6 +210,7 @@ impl Engine {
             project_root,
             config_path,
             config: Arc::new(RwLock::new(config)),
+            config_file: Arc::new(RwLock::new(config_file)),
             version: Arc::new(std::sync::atomic::AtomicU64::new(1)),
             config_error: Arc::new(RwLock::new(config_error)),
             build_cache: Arc::new(tokio::sync::Mutex::new(build_cache)),
@@ -377,10 +393,31 @@ impl Engine {
     async fn rebuild_once(&self, changed_files: &[PathBuf]) -> Result<(u64, Duration)> {
         let start = Instant::now();
 
-        // Reload config - record errors but continue with current config
+        // Reload config - record errors but continue with current config.
+        // Discovered implementations are cached with the config: an
+        // incremental rebuild with an unchanged config file reuses them, and
+        // full rebuilds (on config, gitignore or manifest changes) discover
+        // them again.
+        let cached_text = self.config_file.read().await.text.clone();
         let (config, new_config_error) = match tokio::fs::read_to_string(&self.config_path).await {
-            Ok(content) => match facet_styx::from_str(&content) {
-                Ok(config) => (Some(config), None),
+            Ok(content) if !changed_files.is_empty() && content == cached_text => {
+                (None, self.config_error().await)
+            }
+            Ok(content) => match crate::config::parse(&content) {
+                Ok(config) => match crate::discover::expand(&config, &self.project_root) {
+                    Ok(config) => (
+                        Some((
+                            config,
+                            ConfigFile::new(&self.project_root, &self.config_path, content.clone()),
+                        )),
+                        None,
+                    ),
+                    Err(e) => {
+                        let error_msg = Self::format_config_error(&self.config_path, e);
+                        warn!("{}", error_msg);
+                        (None, Some(error_msg))
+                    }
+                },
                 Err(e) => {
                     let error_msg = Self::format_config_error(&self.config_path, e);
                     warn!("{}", error_msg);
@@ -393,7 +430,13 @@ impl Engine {
                     "Config file {} not found, using empty config",
                     self.config_path.display()
                 );
-                (Some(Config::default()), None)
+                (
+                    Some((
+                        Config::default(),
+                        ConfigFile::new(&self.project_root, &self.config_path, String::new()),
+                    )),
+                    None,
+                )
             }
             Err(e) => {
                 let error_msg = format!(
@@ -407,9 +450,12 @@ impl Engine {
         };
 
         // Use new config if valid, otherwise keep the current one
-        let config = match config {
-            Some(cfg) => cfg,
-            None => self.config.read().await.clone(),
+        let (config, config_file) = match config {
+            Some(loaded) => loaded,
+            None => (
+                self.config.read().await.clone(),
+                self.config_file.read().await.clone(),
+            ),
         };
 
         // Get current VFS overlay
@@ -423,6 +469,7 @@ impl Engine {
         let build_result = build_dashboard_data_with_overlay_and_cache(
             &self.project_root,
             &config,
+            &config_file,
             new_version,
             true,
             &overlay,
@@ -455,6 +502,10 @@ impl Engine {
             let mut cfg = self.config.write().await;
             *cfg = config;
         }
+        {
+            let mut file = self.config_file.write().await;
+            *file = config_file;
+        }
 
         // Update config error state only after successful rebuild.
         {
@@ -494,8 +545,7 @@ impl Engine {
         &self.config_path
     }
 
-    /// Get the current config.
-    #[allow(dead_code)]
+    /// Get the current config, with discovered implementations.
     pub async fn config(&self) -> Config {
         self.config.read().await.clone()
     }
@@ -203,6 +203,7 @@ pub async fn run(project_root: PathBuf, config_path: PathBuf) -> Result<()> {
     let config_path_for_watcher = config_path.clone();
     let project_root_for_watcher = project_root.clone();
     let watcher_state_for_thread = Arc::clone(&watcher_state);
+    let engine_for_watcher = Arc::clone(&engine);
     std::thread::spawn(move || {
         let rt = tokio::runtime::Builder::new_current_thread()
             .enable_all()
@@ -220,6 +221,7 @@ pub async fn run(project_root: PathBuf, config_path: PathBuf) -> Result<()> {
                 match run_smart_watcher(
                     &project_root_for_watcher,
                     &config_path_for_watcher,
+                    Arc::clone(&engine_for_watcher),
                     watcher_tx.clone(),
                     Arc::clone(&watcher_state_for_thread),
                 )
@@ -253,7 +255,7 @@ pub async fn run(project_root: PathBuf, config_path: PathBuf) -> Result<()> {
         while let Some(event) = watcher_rx.recv().await {
             match event {
                 WatcherEvent::Reconfigure => {
-                    info!("Config or gitignore changed, reconfiguring watcher");
+                    info!("Config, gitignore or manifest changed, reconfiguring watcher");
 
                     // Rebuild gitignore matcher
                     gitignore = build_gitignore(&project_root_for_rebuild);
@@ -275,8 +277,10 @@ pub async fn run(project_root: PathBuf, config_path: PathBuf) -> Result<()> {
                     let mut include_patterns: Vec<String> = Vec::new();
                     let mut exclude_patterns: Vec<String> = Vec::new();
 
-                    // Get patterns from the raw config file if available
-                    if let Ok(config) = crate::load_config(&config_path_for_rebuild) {
+                    // Get patterns from the raw config file if available, with
+                    // the implementations the engine discovered
+                    if crate::load_config(&config_path_for_rebuild).is_ok() {
+                        let config = engine_for_rebuild.config().await;
                         for spec in &config.specs {
                             for pattern in &spec.include {
                                 include_patterns.push(pattern.clone());
@@ -550,26 +554,24 @@ fn is_temporary_edit_artifact(path: &Path) -> bool {
 async fn run_smart_watcher(
     project_root: &Path,
     config_path: &Path,
+    engine: Arc<Engine>,
     tx: tokio::sync::mpsc::Sender<WatcherEvent>,
     state: Arc<WatcherState>,
 ) -> Result<()> {
+    use std::collections::HashSet;
     use std::sync::Mutex;
 
-    // Load initial config
-    let config_path_buf = config_path.to_path_buf();
-    let config = crate::load_config(&config_path_buf).unwrap_or_default();
+    // Start from the engine's config, which has the discovered implementations
+    let config = engine.config().await;
 
     // Shared state for the event handler
     let config_path_owned = config_path.to_path_buf();
-    let gitignore_path = project_root.join(".gitignore");
     let tx_for_handler = tx.clone();
     let state_for_handler = Arc::clone(&state);
 
-    // Track paths that trigger reconfiguration
-    let reconfigure_paths: Arc<Mutex<(PathBuf, PathBuf)>> = Arc::new(Mutex::new((
-        config_path_owned.clone(),
-        gitignore_path.clone(),
-    )));
+    // Track paths that trigger reconfiguration (config, gitignore and
+    // manifests); filled in once the watcher is configured
+    let reconfigure_paths: Arc<Mutex<HashSet<PathBuf>>> = Arc::new(Mutex::new(HashSet::new()));
     let reconfigure_paths_for_handler = Arc::clone(&reconfigure_paths);
 
     // r[impl server.watch.debounce]
@@ -592,14 +594,13 @@ async fn run_smart_watcher(
             state_for_handler.record_event();
 
             // Check if any path triggers reconfiguration
-            let (config_path, gitignore_path) =
-                reconfigure_paths_for_handler.lock().unwrap().clone();
-            let needs_reconfigure = paths
-                .iter()
-                .any(|p| p == &config_path || p == &gitignore_path);
+            let needs_reconfigure = {
+                let reconfigure_paths = reconfigure_paths_for_handler.lock().unwrap();
+                paths.iter().any(|p| reconfigure_paths.contains(p))
+            };
 
             let watcher_event = if needs_reconfigure {
-                debug!("Config or gitignore changed, sending Reconfigure event");
+                debug!("Config, gitignore or manifest changed, sending Reconfigure event");
                 WatcherEvent::Reconfigure
             } else {
                 debug!(
@@ -619,6 +620,7 @@ async fn run_smart_watcher(
 
     // Configure initial watches based on config
     watcher_manager.reconfigure(&config)?;
+    *reconfigure_paths.lock().unwrap() = watcher_manager.reconfigure_triggers();
 
     // Update state with watched directories
     state.set_watched_dirs(watcher_manager.watched_dirs());
@@ -636,16 +638,16 @@ async fn run_smart_watcher(
 
         // Periodically check if we need to reconfigure (e.g., if directories were created)
         // This is a simple approach; a more sophisticated one would use inotify for directory creation
-        if let Ok(new_config) = crate::load_config(&config_path_buf) {
-            let old_dirs = watcher_manager.watched_dirs();
-            if let Err(e) = watcher_manager.reconfigure(&new_config) {
-                warn!("Failed to reconfigure watcher: {}", e);
-            } else {
-                let new_dirs = watcher_manager.watched_dirs();
-                if old_dirs != new_dirs {
-                    state.set_watched_dirs(new_dirs);
-                    debug!("Updated watched directories");
-                }
+        let new_config = engine.config().await;
+        let old_dirs = watcher_manager.watched_dirs();
+        if let Err(e) = watcher_manager.reconfigure(&new_config) {
+            warn!("Failed to reconfigure watcher: {}", e);
+        } else {
+            *reconfigure_paths.lock().unwrap() = watcher_manager.reconfigure_triggers();
+            let new_dirs = watcher_manager.watched_dirs();
+            if old_dirs != new_dirs {
+                state.set_watched_dirs(new_dirs);
+                debug!("Updated watched directories");
             }
         }
     }
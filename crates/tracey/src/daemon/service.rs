@@ -12,7 +12,7 @@ use tracing::debug;
 use super::engine::Engine;
 use super::watcher::WatcherState;
 use crate::rule_suggestions::suggest_similar_rule_ids;
-use crate::server::QueryEngine;
+use crate::server::{QueryEngine, RuleFilter};
 use roam::{Context, Tx};
 
 // Re-export the generated dispatcher from tracey-proto
@@ -90,6 +90,18 @@ impl TraceyService {
         }
     }
 
+    // Helper: current data, with the test results of a query applied
+    async fn data_with_results(
+        &self,
+        results: &[ApiTestResult],
+    ) -> Arc<crate::data::DashboardData> {
+        let data = self.inner.engine.data().await;
+        if results.is_empty() {
+            return data;
+        }
+        Arc::new(crate::test_results::with_results(&data, results))
+    }
+
     // Helper: resolve spec/impl from optional parameters
     fn resolve_spec_impl(
         &self,
@@ -120,6 +132,77 @@ impl TraceyService {
     }
 }
 
+/// Build the syntax-highlighted file view for a spec/impl pair.
+///
+/// Shared by the daemon's `file` RPC and `tracey export-site`.
+pub(crate) fn render_file(
+    data: &crate::data::DashboardData,
+    project_root: &Path,
+    highlighter: &mut arborium::Highlighter,
+    req: FileRequest,
+) -> Option<ApiFileData> {
+    let impl_key = (req.spec, req.impl_name);
+
+    // Get the code units map for this impl
+    let code_units_by_file = data.code_units_by_impl.get(&impl_key)?;
+
+    // Resolve the file path - it may be relative or absolute
+    let file_path = PathBuf::from(&req.path);
+    let full_path = if file_path.is_absolute() {
+        file_path
+    } else {
+        project_root.join(&file_path)
+    };
+    // Canonicalize to handle cross-workspace paths like ../marq/...
+    let full_path = full_path.canonicalize().unwrap_or(full_path);
+
+    // Look up code units for this file
+    let units = code_units_by_file.get(&full_path)?;
+
+    // Read file content
+    let content = match std::fs::read_to_string(&full_path) {
+        Ok(c) => c,
+        Err(_) => return None,
+    };
+
+    // Get relative path for display
+    let relative = full_path
+        .strip_prefix(project_root)
+        .unwrap_or(&full_path)
+        .display()
+        .to_string();
+
+    // Syntax highlight the content
+    let html = if let Some(lang) = arborium_language(&relative) {
+        match highlighter.highlight(lang, &content) {
+            Ok(highlighted) => highlighted,
+            Err(_) => html_escape(&content),
+        }
+    } else {
+        html_escape(&content)
+    };
+
+    // Convert code units to API format
+    let api_units: Vec<ApiCodeUnit> = units
+        .iter()
+        .map(|u| ApiCodeUnit {
+            kind: format!("{:?}", u.kind).to_lowercase(),
+            name: u.name.clone(),
+            start_line: u.start_line,
+            end_line: u.end_line,
+            rule_refs: u.req_refs.iter().map(|r| r.to_string()).collect(),
+            hits: u.hits,
+        })
+        .collect();
+
+    Some(ApiFileData {
+        path: relative,
+        content,
+        html,
+        units: api_units,
+    })
+}
+
 /// Escape HTML special characters.
 fn html_escape(s: &str) -> String {
     let mut out = String::with_capacity(s.len());
@@ -224,21 +307,81 @@ fn arborium_language(path: &str) -> Option<&'static str> {
 /// Implementation of the TraceyDaemon trait.
 impl TraceyDaemon for TraceyService {
     /// Get coverage status for all specs/impls
-    async fn status(&self, _cx: &Context) -> StatusResponse {
-        let data = self.inner.engine.data().await;
-        let query = QueryEngine::new(&data);
+    async fn status(&self, _cx: &Context, req: StatusRequest) -> StatusResponse {
+        let data = self.data_with_results(&req.results).await;
+        let query = QueryEngine::new(&data).with_filter(RuleFilter {
+            level: req.level,
+            status: req.status,
+            owner: req.owner,
+            tag: req.tag,
+        });
         let stats = query.status();
 
         StatusResponse {
             impls: stats
                 .into_iter()
-                .map(|(spec, impl_name, s)| ImplStatus {
-                    spec,
-                    impl_name,
-                    total_rules: s.total_rules,
-                    covered_rules: s.impl_covered,
-                    stale_rules: s.stale_covered,
-                    verified_rules: s.verify_covered,
+                .map(|(spec, impl_name, s)| {
+                    // r[impl config.thresholds.surface]
+                    let threshold_violations = data
+                        .validation_by_impl
+                        .get(&(spec.clone(), impl_name.clone()))
+                        .map(|v| {
+                            v.errors
+                                .iter()
+                                .filter(|e| e.code == ValidationErrorCode::CoverageThreshold)
+                                .map(|e| e.message.clone())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let by_level = query
+                        .status_by_level(&spec, &impl_name)
+                        .into_iter()
+                        .map(|(level, l)| LevelStatus {
+                            level: level.to_string(),
+                            total_rules: l.total_rules,
+                            covered_rules: l.impl_covered,
+                            stale_rules: l.stale_covered,
+                            verified_rules: l.verify_covered,
+                        })
+                        .collect();
+                    let by_owner = query
+                        .status_by_owner(&spec, &impl_name)
+                        .into_iter()
+                        .map(|(owner, o)| OwnerStatus {
+                            owner,
+                            total_rules: o.total_rules,
+                            covered_rules: o.impl_covered,
+                            stale_rules: o.stale_covered,
+                            verified_rules: o.verify_covered,
+                        })
+                        .collect();
+                    let by_tag = query
+                        .status_by_tag(&spec, &impl_name)
+                        .into_iter()
+                        .map(|(tag, t)| TagStatus {
+                            tag,
+                            total_rules: t.total_rules,
+                            covered_rules: t.impl_covered,
+                            stale_rules: t.stale_covered,
+                            verified_rules: t.verify_covered,
+                        })
+                        .collect();
+                    ImplStatus {
+                        spec,
+                        impl_name,
+                        total_rules: s.total_rules,
+                        covered_rules: s.impl_covered,
+                        stale_rules: s.stale_covered,
+                        verified_rules: s.verify_covered,
+                        threshold_violations,
+                        excluded_rules: s.excluded,
+                        by_level,
+                        by_owner,
+                        by_tag,
+                        passing_rules: s.has_results.then_some(s.verify_passing),
+                        failing_rules: s.has_results.then_some(s.verify_failing),
+                        executed_rules: s.has_runtime_coverage.then_some(s.impl_executed),
+                    }
                 })
                 .collect(),
         }
@@ -247,7 +390,12 @@ impl TraceyDaemon for TraceyService {
     /// Get uncovered rules
     async fn uncovered(&self, _cx: &Context, req: UncoveredRequest) -> UncoveredResponse {
         let data = self.inner.engine.data().await;
-        let query = QueryEngine::new(&data);
+        let query = QueryEngine::new(&data).with_filter(RuleFilter {
+            level: req.level,
+            status: req.status,
+            owner: req.owner,
+            tag: req.tag,
+        });
 
         // Find the spec/impl to query
         let (spec, impl_name) =
@@ -269,6 +417,7 @@ impl TraceyDaemon for TraceyService {
                             .map(|r| tracey_proto::RuleRef {
                                 id: r.id,
                                 text: None, // RuleRef in server.rs doesn't have text
+                                verification: r.verification,
                             })
                             .collect(),
                     })
@@ -287,8 +436,13 @@ impl TraceyDaemon for TraceyService {
 
     /// Get untested rules
     async fn untested(&self, _cx: &Context, req: UntestedRequest) -> UntestedResponse {
-        let data = self.inner.engine.data().await;
-        let query = QueryEngine::new(&data);
+        let data = self.data_with_results(&req.results).await;
+        let query = QueryEngine::new(&data).with_filter(RuleFilter {
+            level: req.level,
+            status: req.status,
+            owner: req.owner,
+            tag: req.tag,
+        });
 
         let (spec, impl_name) =
             self.resolve_spec_impl(req.spec.as_deref(), req.impl_name.as_deref(), &data.config);
@@ -309,6 +463,7 @@ impl TraceyDaemon for TraceyService {
                             .map(|r| tracey_proto::RuleRef {
                                 id: r.id,
                                 text: None,
+                                verification: r.verification,
                             })
                             .collect(),
                     })
@@ -328,7 +483,12 @@ impl TraceyDaemon for TraceyService {
     /// Get stale references
     async fn stale(&self, _cx: &Context, req: StaleRequest) -> StaleResponse {
         let data = self.inner.engine.data().await;
-        let query = QueryEngine::new(&data);
+        let query = QueryEngine::new(&data).with_filter(RuleFilter {
+            level: req.level,
+            status: req.status,
+            owner: req.owner,
+            tag: req.tag,
+        });
 
         let (spec, impl_name) =
             self.resolve_spec_impl(req.spec.as_deref(), req.impl_name.as_deref(), &data.config);
@@ -349,6 +509,15 @@ impl TraceyDaemon for TraceyService {
                         reference_id: e.reference_id,
                     })
                     .collect(),
+                needs_review: result
+                    .needs_review
+                    .into_iter()
+                    .map(|rule| NeedsReviewEntry {
+                        id: rule.id,
+                        changed: rule.needs_review,
+                        impl_refs: rule.impl_refs,
+                    })
+                    .collect(),
             }
         } else {
             StaleResponse {
@@ -357,6 +526,7 @@ impl TraceyDaemon for TraceyService {
                 total_rules: 0,
                 stale_count: 0,
                 refs: vec![],
+                needs_review: vec![],
             }
         }
     }
@@ -453,6 +623,8 @@ impl TraceyDaemon for TraceyService {
             html: info.html,
             source_file: info.source_file,
             source_line: info.source_line,
+            owners: info.owners,
+            tags: info.tags,
             coverage: info
                 .coverage
                 .into_iter()
@@ -461,6 +633,7 @@ impl TraceyDaemon for TraceyService {
                     impl_name: c.impl_name,
                     impl_refs: c.impl_refs,
                     verify_refs: c.verify_refs,
+                    executed: c.executed,
                 })
                 .collect(),
             version_diff,
@@ -649,68 +822,8 @@ impl TraceyDaemon for TraceyService {
     /// Get file with syntax highlighting
     async fn file(&self, _cx: &Context, req: FileRequest) -> Option<ApiFileData> {
         let data = self.inner.engine.data().await;
-        let project_root = self.inner.engine.project_root();
-
-        let impl_key = (req.spec, req.impl_name);
-
-        // Get the code units map for this impl
-        let code_units_by_file = data.code_units_by_impl.get(&impl_key)?;
-
-        // Resolve the file path - it may be relative or absolute
-        let file_path = PathBuf::from(&req.path);
-        let full_path = if file_path.is_absolute() {
-            file_path
-        } else {
-            project_root.join(&file_path)
-        };
-        // Canonicalize to handle cross-workspace paths like ../marq/...
-        let full_path = full_path.canonicalize().unwrap_or(full_path);
-
-        // Look up code units for this file
-        let units = code_units_by_file.get(&full_path)?;
-
-        // Read file content
-        let content = match std::fs::read_to_string(&full_path) {
-            Ok(c) => c,
-            Err(_) => return None,
-        };
-
-        // Get relative path for display
-        let relative = full_path
-            .strip_prefix(project_root)
-            .unwrap_or(&full_path)
-            .display()
-            .to_string();
-
-        // Syntax highlight the content
-        let html = if let Some(lang) = arborium_language(&relative) {
-            let mut hl = self.inner.highlighter.lock().unwrap();
-            match hl.highlight(lang, &content) {
-                Ok(highlighted) => highlighted,
-                Err(_) => html_escape(&content),
-            }
-        } else {
-            html_escape(&content)
-        };
-
-        // Convert code units to API format
-        let api_units: Vec<ApiCodeUnit> = units
-            .iter()
-            .map(|u| ApiCodeUnit {
-                kind: format!("{:?}", u.kind).to_lowercase(),
-                name: u.name.clone(),
-                start_line: u.start_line,
-                end_line: u.end_line,
-                rule_refs: u.req_refs.iter().map(|r| r.to_string()).collect(),
-            })
-            .collect();
-
-        Some(ApiFileData {
-            path: relative,
-            content,
-            html,
-            units: api_units,
-        })
+        let mut hl = self.inner.highlighter.lock().unwrap();
+        render_file(&data, self.inner.engine.project_root(), &mut hl, req)
     }
 
     /// Get rendered spec content
@@ -732,10 +845,10 @@ impl TraceyDaemon for TraceyService {
         let forward = data
             .forward_by_impl
             .get(&(spec.clone(), impl_name.clone()))?;
-        let include_patterns = data.spec_includes_by_name.get(&spec)?;
+        let spec_files = data.spec_files_by_name.get(&spec)?;
         crate::data::render_spec_content_for_impl(
             self.inner.engine.project_root(),
-            include_patterns,
+            spec_files,
             &spec,
             &impl_name,
             forward,
@@ -879,6 +992,28 @@ impl TraceyDaemon for TraceyService {
             })
     }
 
+    /// Map changed lines onto code units and collect the rules they reference
+    ///
+    /// Lines of a revision range or of the index don't match the worktree, so
+    /// they're mapped onto a build of the revision they refer to instead of
+    /// the current data.
+    async fn impact(&self, _cx: &Context, req: ImpactRequest) -> Result<ImpactResponse, String> {
+        let Some(revision) = &req.revision else {
+            let data = self.inner.engine.data().await;
+            return Ok(crate::impact::analyze(&data, &req.files));
+        };
+        let config = self.inner.engine.config().await;
+        let data = crate::diff::build_at_revision(
+            self.inner.engine.project_root(),
+            self.inner.engine.config_path(),
+            &config,
+            revision,
+        )
+        .await
+        .map_err(|e| format!("{e:?}"))?;
+        Ok(crate::impact::analyze(&data, &req.files))
+    }
+
     // =========================================================================
     // LSP Support Methods
     // =========================================================================
@@ -891,8 +1026,14 @@ impl TraceyDaemon for TraceyService {
         let path = PathBuf::from(&req.path);
 
         // Find the rule at cursor position (works for both spec and source files)
-        let rule_at_pos =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await?;
+        let rule_at_pos = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await?;
 
         // Look up the rule in our data
         let (spec_name, rule) = find_rule_in_data(&data, &rule_at_pos.req_id)?;
@@ -968,6 +1109,7 @@ impl TraceyDaemon for TraceyService {
             spec_name: spec_name.clone(),
             spec_url,
             source_file: rule.source_file.clone(),
+            owners: rule.owners.clone(),
             impl_count,
             verify_count,
             impl_refs,
@@ -988,8 +1130,14 @@ impl TraceyDaemon for TraceyService {
         let path = PathBuf::from(&req.path);
 
         // Find the rule at cursor position (works for both spec and source files)
-        let Some(rule_at_pos) =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await
+        let Some(rule_at_pos) = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await
         else {
             return vec![];
         };
@@ -1020,8 +1168,14 @@ impl TraceyDaemon for TraceyService {
         let path = PathBuf::from(&req.path);
 
         // Find the rule at cursor position (works for both spec and source files)
-        let Some(rule_at_pos) =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await
+        let Some(rule_at_pos) = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await
         else {
             return vec![];
         };
@@ -1051,8 +1205,14 @@ impl TraceyDaemon for TraceyService {
         let path = PathBuf::from(&req.path);
 
         // Find the rule at cursor position (works for both spec and source files)
-        let Some(rule_at_pos) =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await
+        let Some(rule_at_pos) = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await
         else {
             return vec![];
         };
@@ -1092,8 +1252,8 @@ impl TraceyDaemon for TraceyService {
             });
         }
 
-        // Add all depends refs
-        for r in &rule.depends_refs {
+        // Add all depends refs and those of declared verbs
+        for r in rule.depends_refs.iter().chain(&rule.other_refs) {
             locations.push(LspLocation {
                 path: r.file.clone(),
                 line: r.line.saturating_sub(1) as u32,
@@ -1106,7 +1266,7 @@ impl TraceyDaemon for TraceyService {
 
     /// Get completions for a position
     ///
-    /// r[impl lsp.completions.verb]
+    /// r[impl lsp.completions.verb+2]
     /// r[impl lsp.completions.req-id]
     /// r[impl lsp.completions.req-id-fuzzy]
     async fn lsp_completions(
@@ -1137,20 +1297,25 @@ impl TraceyDaemon for TraceyService {
                 // If we haven't closed the bracket and there's no space yet, suggest verbs
                 if !after_bracket.contains(']') {
                     if !after_bracket.contains(' ') {
-                        // Suggest verbs
-                        for (verb, desc) in [
-                            ("impl ", "Implementation of a requirement"),
-                            ("verify ", "Test/verification of a requirement"),
-                            ("depends ", "Dependency on another requirement"),
-                            ("related ", "Related requirement"),
-                        ] {
+                        // Suggest verbs, then those declared in the config
+                        let builtin = [
+                            ("impl", "Implementation of a requirement"),
+                            ("verify", "Test/verification of a requirement"),
+                            ("depends", "Dependency on another requirement"),
+                            ("related", "Related requirement"),
+                        ];
+                        let declared = data.config.verbs.iter().map(|v| {
+                            let desc = v.description.as_deref().unwrap_or("Declared verb");
+                            (v.name.as_str(), desc)
+                        });
+                        for (verb, desc) in builtin.into_iter().chain(declared) {
                             if verb.starts_with(after_bracket) || after_bracket.is_empty() {
                                 completions.push(LspCompletionItem {
-                                    label: verb.trim().to_string(),
+                                    label: verb.to_string(),
                                     kind: "verb".to_string(),
                                     detail: Some(desc.to_string()),
                                     documentation: None,
-                                    insert_text: Some(verb.to_string()),
+                                    insert_text: Some(format!("{verb} ")),
                                 });
                             }
                         }
@@ -1205,9 +1370,11 @@ impl TraceyDaemon for TraceyService {
                     let (start_line, start_char, end_line, end_char) =
                         span_to_range(&req.content, def.marker_span.offset, def.marker_span.length);
 
-                    // Look up the rule to check coverage
+                    // Look up the rule to check coverage. Excluded rules (e.g.
+                    // deprecated ones) don't need any.
                     if let Some(def_id) = parse_rule_id(&def.id.to_string())
                         && let Some((_, rule)) = find_rule_in_data(&data, &def_id)
+                        && !rule.excluded
                     {
                         let impl_count = rule.impl_refs.len();
                         let verify_count = rule.verify_refs.len();
@@ -1243,7 +1410,8 @@ impl TraceyDaemon for TraceyService {
         }
 
         // For source files, check references
-        let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content);
+        let verbs = crate::verbs::names(&data.config.verbs);
+        let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content, &verbs);
 
         // Check if this is a test file
         let is_test = data.test_files.contains(&path);
@@ -1318,7 +1486,38 @@ impl TraceyDaemon for TraceyService {
                 .map(Vec::as_slice)
                 .unwrap_or(&[]);
             match classify_reference_against_known_rules(&reference.req_id, known_for_prefix) {
-                KnownRuleMatch::Exact => {}
+                KnownRuleMatch::Exact => {
+                    // r[impl lsp.diagnostics.deprecated]
+                    if rules_by_id
+                        .get(&reference.req_id)
+                        .is_some_and(crate::data::is_deprecated)
+                    {
+                        diagnostics.push(LspDiagnostic {
+                            severity: "warning".to_string(),
+                            code: "deprecated".to_string(),
+                            message: crate::data::deprecated_rule_message(&reference.req_id),
+                            start_line,
+                            start_char,
+                            end_line,
+                            end_char,
+                        });
+                    }
+                    // r[impl lsp.diagnostics.needs-review]
+                    if reference.verb == tracey_core::RefVerb::Impl
+                        && let Some(rule) = rules_by_id.get(&reference.req_id)
+                        && !rule.needs_review.is_empty()
+                    {
+                        diagnostics.push(LspDiagnostic {
+                            severity: "warning".to_string(),
+                            code: "needs-review".to_string(),
+                            message: crate::data::needs_review_message(rule),
+                            start_line,
+                            start_char,
+                            end_line,
+                            end_char,
+                        });
+                    }
+                }
                 KnownRuleMatch::Stale(current_rule_id) => {
                     // r[impl lsp.diagnostics.stale]
                     // r[impl lsp.diagnostics.stale.message-prefix]
@@ -1338,11 +1537,39 @@ impl TraceyDaemon for TraceyService {
                     });
                 }
                 KnownRuleMatch::Missing => {
-                    let message =
-                        unknown_rule_message_with_suggestions(&reference.req_id, known_for_prefix);
+                    // r[impl lsp.diagnostics.renamed]
+                    // r[impl lsp.diagnostics.removed]
+                    let renamed_to = crate::data::aliases_for_prefix(
+                        &data.config,
+                        &data.forward_by_impl,
+                        &reference.prefix,
+                    )
+                    .remove(&reference.req_id.base);
+                    let removed = crate::data::find_removed_rule(
+                        &data.config,
+                        &reference.prefix,
+                        &reference.req_id,
+                    );
+                    let (code, message) = match (renamed_to, removed) {
+                        (Some(rule_id), _) => (
+                            "renamed",
+                            crate::data::renamed_rule_message(&reference.req_id, &rule_id),
+                        ),
+                        (None, Some(removed)) => (
+                            "removed",
+                            crate::data::removed_rule_message(&reference.req_id, removed),
+                        ),
+                        (None, None) => (
+                            "orphaned",
+                            unknown_rule_message_with_suggestions(
+                                &reference.req_id,
+                                known_for_prefix,
+                            ),
+                        ),
+                    };
                     diagnostics.push(LspDiagnostic {
                         severity: "warning".to_string(),
-                        code: "orphaned".to_string(),
+                        code: code.to_string(),
                         message,
                         start_line,
                         start_char,
@@ -1352,13 +1579,12 @@ impl TraceyDaemon for TraceyService {
                 }
             }
 
-            // Check for impl in test file
-            if is_test && reference.verb == tracey_core::RefVerb::Impl {
+            // Check for impl (or a verb kept out of tests) in test file
+            if is_test && !crate::verbs::allowed_in_tests(&reference.verb, &data.config.verbs) {
                 diagnostics.push(LspDiagnostic {
                     severity: "warning".to_string(),
                     code: "impl-in-test".to_string(),
-                    message: "Implementation reference in test file (use 'verify' instead)"
-                        .to_string(),
+                    message: crate::data::impl_in_test_message(&reference.verb),
                     start_line,
                     start_char,
                     end_line,
@@ -1440,13 +1666,15 @@ impl TraceyDaemon for TraceyService {
             }
         } else {
             // For implementation files, extract references
-            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content);
+            let data = self.inner.engine.data().await;
+            let verbs = crate::verbs::names(&data.config.verbs);
+            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content, &verbs);
             for r in &reqs.references {
                 let (start_line, start_char, end_line, end_char) =
                     span_to_range(&req.content, r.span.offset, r.span.length);
                 symbols.push(LspSymbol {
                     name: r.req_id.to_string(),
-                    kind: format!("{:?}", r.verb).to_lowercase(),
+                    kind: r.verb.as_str().to_string(),
                     start_line,
                     start_char,
                     end_line,
@@ -1535,7 +1763,8 @@ impl TraceyDaemon for TraceyService {
             }
         } else {
             // For source files, tokenize references in comments
-            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content);
+            let verbs = crate::verbs::names(&data.config.verbs);
+            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content, &verbs);
 
             for reference in &reqs.references {
                 let (start_line, start_char, _, _) =
@@ -1613,7 +1842,8 @@ impl TraceyDaemon for TraceyService {
             }
         } else {
             // For source files, show code lenses for definition references
-            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content);
+            let verbs = crate::verbs::names(&data.config.verbs);
+            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content, &verbs);
 
             for reference in &reqs.references {
                 // Only show code lens for definitions
@@ -1695,7 +1925,8 @@ impl TraceyDaemon for TraceyService {
             }
         } else {
             // For source files, show hints for references in comments
-            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content);
+            let verbs = crate::verbs::names(&data.config.verbs);
+            let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content, &verbs);
 
             for reference in &reqs.references {
                 let (line, _, _, end_char) =
@@ -1737,8 +1968,14 @@ impl TraceyDaemon for TraceyService {
         let path = PathBuf::from(&req.path);
 
         // Find the rule at cursor position (works for both spec and source files)
-        let rule_at_pos =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await?;
+        let rule_at_pos = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await?;
 
         // Check if the rule exists
         find_rule_in_data(&data, &rule_at_pos.req_id)?;
@@ -1769,8 +2006,14 @@ impl TraceyDaemon for TraceyService {
         let path = PathBuf::from(&req.path);
 
         // Find the rule at cursor position (works for both spec and source files)
-        let Some(rule_at_pos) =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await
+        let Some(rule_at_pos) = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await
         else {
             return vec![];
         };
@@ -1832,8 +2075,14 @@ impl TraceyDaemon for TraceyService {
         let mut actions = Vec::new();
 
         // Check if we're on a rule (works for both spec and source files)
-        if let Some(rule_at_pos) =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await
+        if let Some(rule_at_pos) = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await
         {
             // Check if it's an orphaned reference
             if find_rule_in_data(&data, &rule_at_pos.req_id).is_none() {
@@ -1851,12 +2100,25 @@ impl TraceyDaemon for TraceyService {
                         .filter(|((spec_name, _), _)| spec_names.contains(spec_name.as_str()))
                         .flat_map(|(_, forward)| forward.rules.iter().map(|r| r.id.clone()))
                         .collect();
-                    let suggestions = suggest_similar_rule_ids(
-                        &rule_at_pos.req_id,
-                        &known_rule_ids_for_prefix,
-                        1,
-                    );
-                    if let Some(best) = suggestions.first() {
+                    // The rule that replaced the ID, or a removed rule's
+                    // replacement, beats any typo suggestion.
+                    let replacement = crate::data::aliases_for_prefix(
+                        &data.config,
+                        &data.forward_by_impl,
+                        prefix,
+                    )
+                    .remove(&rule_at_pos.req_id.base)
+                    .or_else(|| {
+                        crate::data::find_removed_rule(&data.config, prefix, &rule_at_pos.req_id)
+                            .and_then(|removed| removed.replaced_by.as_deref())
+                            .and_then(parse_rule_id)
+                    });
+                    let best = replacement.or_else(|| {
+                        suggest_similar_rule_ids(&rule_at_pos.req_id, &known_rule_ids_for_prefix, 1)
+                            .into_iter()
+                            .next()
+                    });
+                    if let Some(best) = best {
                         actions.push(LspCodeAction {
                             title: format!(
                                 "Replace '{}' with '{}' (all impl annotations)",
@@ -1900,11 +2162,18 @@ impl TraceyDaemon for TraceyService {
         _cx: &Context,
         req: LspPositionRequest,
     ) -> Vec<LspLocation> {
+        let data = self.inner.engine.data().await;
         let path = PathBuf::from(&req.path);
 
         // Find the rule at cursor position (works for both spec and source files)
-        let Some(rule_at_pos) =
-            find_rule_at_position(&path, &req.content, req.line, req.character).await
+        let Some(rule_at_pos) = find_rule_at_position(
+            &path,
+            &req.content,
+            req.line,
+            req.character,
+            &data.config.verbs,
+        )
+        .await
         else {
             return vec![];
         };
@@ -1934,7 +2203,8 @@ impl TraceyDaemon for TraceyService {
         }
 
         // For source files, find all references to the same rule in this document
-        let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content);
+        let verbs = crate::verbs::names(&data.config.verbs);
+        let reqs = tracey_core::Reqs::extract_from_content(&path, &req.content, &verbs);
         reqs.references
             .iter()
             .filter(|r| r.req_id == rule_at_pos.req_id)
@@ -1975,23 +2245,9 @@ impl TraceyDaemon for TraceyService {
         };
 
         // Find the spec and impl
-        let mut found = false;
-        for spec in &mut config.specs {
-            if spec.name == spec_name {
-                for impl_ in &mut spec.impls {
-                    if impl_.name == impl_name {
-                        impl_.exclude.push(req.pattern.clone());
-                        found = true;
-                        break;
-                    }
-                }
-                break;
-            }
-        }
-
-        if !found {
-            return Err(format!("Spec/impl '{}/{}' not found", spec_name, impl_name));
-        }
+        let discovered = self.inner.engine.config().await;
+        let impl_ = config_impl_entry(&mut config, &discovered, &spec_name, &impl_name)?;
+        impl_.exclude.push(req.pattern.clone());
 
         // Save config
         if let Err(e) = save_config(&config_path, &config) {
@@ -2022,23 +2278,9 @@ impl TraceyDaemon for TraceyService {
         };
 
         // Find the spec and impl
-        let mut found = false;
-        for spec in &mut config.specs {
-            if spec.name == spec_name {
-                for impl_ in &mut spec.impls {
-                    if impl_.name == impl_name {
-                        impl_.include.push(req.pattern.clone());
-                        found = true;
-                        break;
-                    }
-                }
-                break;
-            }
-        }
-
-        if !found {
-            return Err(format!("Spec/impl '{}/{}' not found", spec_name, impl_name));
-        }
+        let discovered = self.inner.engine.config().await;
+        let impl_ = config_impl_entry(&mut config, &discovered, &spec_name, &impl_name)?;
+        impl_.include.push(req.pattern.clone());
 
         // Save config
         if let Err(e) = save_config(&config_path, &config) {
@@ -2074,6 +2316,7 @@ async fn find_rule_at_position(
     content: &str,
     line: u32,
     character: u32,
+    verbs: &[ApiVerb],
 ) -> Option<RuleAtPosition> {
     if path.extension().is_some_and(|ext| ext == "md") {
         // Parse markdown to find requirement definitions
@@ -2098,7 +2341,8 @@ async fn find_rule_at_position(
         })
     } else {
         // Parse source file to find references in comments
-        let reqs = tracey_core::Reqs::extract_from_content(path, content);
+        let reqs =
+            tracey_core::Reqs::extract_from_content(path, content, &crate::verbs::names(verbs));
         let ref_at_pos = find_ref_at_position(&reqs, content, line, character)?;
 
         Some(RuleAtPosition {
@@ -2214,7 +2458,7 @@ fn classify_reference_against_known_rules(
             RuleIdMatch::Stale => {
                 stale_target = Some(rule_id.clone());
             }
-            RuleIdMatch::NoMatch => {}
+            RuleIdMatch::Renamed | RuleIdMatch::NoMatch => {}
         }
     }
 
@@ -2328,6 +2572,36 @@ fn stale_diagnostic_message_short(
     message
 }
 
+/// The entry of an implementation in the config file, for MCP edits.
+///
+/// An implementation discovered through `impls_from` has no entry yet, so one
+/// is added, copied from `discovered`. Like any entry of `impls`, it then
+/// takes precedence over the discovered implementation.
+fn config_impl_entry<'a>(
+    config: &'a mut crate::config::Config,
+    discovered: &crate::config::Config,
+    spec_name: &str,
+    impl_name: &str,
+) -> Result<&'a mut crate::config::Impl, String> {
+    let not_found = || format!("Spec/impl '{}/{}' not found", spec_name, impl_name);
+    let spec = config
+        .specs
+        .iter_mut()
+        .find(|s| s.name == spec_name)
+        .ok_or_else(not_found)?;
+    if let Some(index) = spec.impls.iter().position(|i| i.name == impl_name) {
+        return Ok(&mut spec.impls[index]);
+    }
+    let impl_ = discovered
+        .specs
+        .iter()
+        .find(|s| s.name == spec_name)
+        .and_then(|s| s.impls.iter().find(|i| i.name == impl_name))
+        .ok_or_else(not_found)?;
+    spec.impls.push(impl_.clone());
+    Ok(spec.impls.last_mut().expect("impl was just added"))
+}
+
 /// Save config to file
 fn save_config(path: &Path, config: &crate::config::Config) -> eyre::Result<()> {
     use std::io::Write;
@@ -13,13 +13,14 @@
 //!
 //! ## Reconfiguration
 //!
-//! When config.styx or .gitignore changes, the watcher sends a
-//! `Reconfigure` event. The rebuild loop then:
+//! When config.styx, .gitignore or a workspace manifest read for
+//! `impls_from` changes, the watcher sends a `Reconfigure` event. The
+//! rebuild loop then:
 //! 1. Rebuilds the gitignore matcher
 //! 2. Calls `WatcherManager::reconfigure()` to update watches
 //! 3. Triggers a rebuild
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
@@ -171,36 +172,10 @@ impl Default for WatcherState {
 // Glob Pattern Utilities
 // ============================================================================
 
-/// Extract the directory prefix from a glob pattern.
-///
-/// This finds the longest path prefix before any glob metacharacter.
-///
-/// # Examples
-///
-/// ```ignore
-/// glob_to_watch_dir("foo/bar/**/*.rs") => "foo/bar"
-/// glob_to_watch_dir("src/*.rs") => "src"
-/// glob_to_watch_dir("*.rs") => "."
-/// glob_to_watch_dir("docs/spec/**/*.md") => "docs/spec"
-/// ```
+/// Extract the directory prefix from a glob pattern, the longest path prefix
+/// before any glob metacharacter (see [`tracey_core::glob_base_dir`]).
 pub fn glob_to_watch_dir(pattern: &str) -> PathBuf {
-    let mut result = PathBuf::new();
-
-    for component in Path::new(pattern).components() {
-        let s = component.as_os_str().to_string_lossy();
-        // Stop at the first component containing glob metacharacters
-        if s.contains('*') || s.contains('?') || s.contains('[') || s.contains('{') {
-            break;
-        }
-        result.push(component);
-    }
-
-    // If no prefix was found (pattern starts with glob), watch current directory
-    if result.as_os_str().is_empty() {
-        PathBuf::from(".")
-    } else {
-        result
-    }
+    tracey_core::glob_base_dir(pattern)
 }
 
 /// Extract all watch directories from a config.
@@ -366,6 +341,9 @@ pub struct WatcherManager {
 
     /// Gitignore path (always watched if exists).
     gitignore_path: PathBuf,
+
+    /// Manifests of the packages implementations are discovered from.
+    watched_manifests: BTreeSet<PathBuf>,
 }
 
 impl WatcherManager {
@@ -424,6 +402,7 @@ impl WatcherManager {
             project_root,
             config_path,
             gitignore_path,
+            watched_manifests: BTreeSet::new(),
         };
 
         // Always watch config file
@@ -468,7 +447,11 @@ impl WatcherManager {
     /// This is called on startup and whenever config changes.
     /// It computes the new set of watch directories, removes watches
     /// for directories no longer needed, and adds watches for new ones.
+    /// `config` must have its discovered implementations expanded.
     pub fn reconfigure(&mut self, config: &Config) -> Result<()> {
+        // r[impl server.watch.manifests]
+        self.reconfigure_manifests(config);
+
         let new_dirs = extract_watch_dirs_from_config(config, &self.project_root);
 
         // Find directories to remove and add
@@ -520,6 +503,30 @@ impl WatcherManager {
         Ok(())
     }
 
+    /// Watch the manifests implementations are discovered from, which may
+    /// not exist yet.
+    fn reconfigure_manifests(&mut self, config: &Config) {
+        let new_manifests = crate::discover::manifests(config, &self.project_root);
+
+        for manifest in self.watched_manifests.difference(&new_manifests) {
+            if let Err(e) = self.watcher.unwatch(manifest) {
+                debug!("Failed to unwatch {}: {}", manifest.display(), e);
+            }
+        }
+        for manifest in new_manifests.difference(&self.watched_manifests) {
+            if !manifest.exists() {
+                continue;
+            }
+            match self.watcher.watch(manifest, RecursiveMode::NonRecursive) {
+                Ok(()) => info!("Watching manifest: {}", manifest.display()),
+                Err(e) => warn!("Failed to watch {}: {}", manifest.display(), e),
+            }
+        }
+
+        // Missing manifests are retried on the next reconfigure.
+        self.watched_manifests = new_manifests.into_iter().filter(|m| m.exists()).collect();
+    }
+
     /// Get the currently watched directories (for health reporting).
     pub fn watched_dirs(&self) -> Vec<PathBuf> {
         let mut dirs: Vec<_> = self.watched_dirs.iter().cloned().collect();
@@ -537,9 +544,20 @@ impl WatcherManager {
         path == self.gitignore_path
     }
 
-    /// Check if a path should trigger reconfiguration (config or gitignore).
+    /// Check if a path should trigger reconfiguration (config, gitignore or
+    /// a watched manifest).
     pub fn is_reconfigure_trigger(&self, path: &Path) -> bool {
-        self.is_config_path(path) || self.is_gitignore_path(path)
+        self.is_config_path(path)
+            || self.is_gitignore_path(path)
+            || self.watched_manifests.contains(path)
+    }
+
+    /// Paths that should trigger reconfiguration, for event handlers.
+    pub fn reconfigure_triggers(&self) -> HashSet<PathBuf> {
+        let mut paths: HashSet<PathBuf> = self.watched_manifests.iter().cloned().collect();
+        paths.insert(self.config_path.clone());
+        paths.insert(self.gitignore_path.clone());
+        paths
     }
 }
 
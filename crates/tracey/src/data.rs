@@ -10,6 +10,7 @@ use owo_colors::OwoColorize;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::future::Future;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
@@ -21,7 +22,7 @@ use tracey_core::code_units::CodeUnit;
 use tracey_core::is_supported_extension;
 use tracey_core::{
     ParseWarning, RefVerb, ReqDefinition, ReqReference, Reqs, RuleId, RuleIdMatch,
-    classify_reference_for_rule, parse_rule_id,
+    classify_reference_for_rule, classify_reference_with_aliases, parse_rule_id,
 };
 use tracing::info;
 
@@ -34,6 +35,7 @@ use marq::{
 use crate::config::Config;
 use crate::rule_suggestions::suggest_similar_rule_ids;
 use crate::search;
+use crate::verbs::VerbRole;
 
 // ============================================================================
 // JSON API Types
@@ -41,9 +43,10 @@ use crate::search;
 
 // Re-export API types from tracey-api crate
 pub use tracey_api::{
-    ApiCodeRef, ApiCodeUnit, ApiConfig, ApiFileData, ApiFileEntry, ApiForwardData, ApiReverseData,
-    ApiRule, ApiSpecData, ApiSpecForward, ApiSpecInfo, ApiStaleRef, GitStatus, OutlineCoverage,
-    OutlineEntry, SpecSection, ValidationError, ValidationErrorCode, ValidationResult,
+    ApiCodeRef, ApiCodeUnit, ApiConfig, ApiFileData, ApiFileEntry, ApiForwardData, ApiRemovedRule,
+    ApiReverseData, ApiRule, ApiRuleLink, ApiSpecData, ApiSpecForward, ApiSpecInfo, ApiStaleRef,
+    ApiTestResult, ApiVerb, GitStatus, OutlineCoverage, OutlineEntry, SpecSection, TestOutcome,
+    ValidationError, ValidationErrorCode, ValidationResult, VerbCounts,
 };
 use tracey_proto::{LspDiagnostic, LspFileDiagnostics};
 
@@ -54,7 +57,22 @@ use tracey_proto::{LspDiagnostic, LspFileDiagnostics};
 /// Key for implementation-specific data: (spec_name, impl_name)
 pub type ImplKey = (String, String);
 
+/// The markdown files of a spec, for rendering its content.
+#[derive(Debug, Clone)]
+pub enum SpecFiles {
+    /// Files of the project matching the spec's include patterns
+    Includes(Vec<String>),
+    /// Files of another repository at the commit pinned for the spec, the
+    /// same ones its rules are extracted from
+    Git {
+        source: crate::config::GitSource,
+        commit: String,
+        include: Vec<String>,
+    },
+}
+
 /// Computed dashboard data that gets rebuilt on file changes
+#[derive(Clone)]
 pub struct DashboardData {
     pub config: ApiConfig,
     /// Forward data per implementation: (spec_name, impl_name) -> data
@@ -65,8 +83,8 @@ pub struct DashboardData {
     pub code_units_by_impl: BTreeMap<ImplKey, BTreeMap<PathBuf, Vec<CodeUnit>>>,
     /// Spec content per implementation (coverage info varies by impl)
     pub specs_content_by_impl: BTreeMap<ImplKey, ApiSpecData>,
-    /// Spec include patterns by spec name
-    pub spec_includes_by_name: BTreeMap<String, Vec<String>>,
+    /// Where each spec's markdown is rendered from, by spec name
+    pub spec_files_by_name: BTreeMap<String, SpecFiles>,
     /// Source files for full-text index construction
     pub search_files: BTreeMap<PathBuf, String>,
     /// Parsed requirement references and warnings by source file, captured during rebuild.
@@ -95,6 +113,9 @@ pub struct BuildCache {
     impl_scan_paths: HashMap<ImplScanKey, CachedScanPaths>,
     spec_scan_paths: HashMap<SpecScanKey, CachedScanPaths>,
     markdown_files: HashMap<PathBuf, CachedMarkdownFile>,
+    git_specs: HashMap<GitSpecKey, Vec<crate::ExtractedRule>>,
+    /// Declared verbs the cached source files were parsed with
+    verbs: Vec<String>,
 }
 
 #[derive(Clone)]
@@ -129,6 +150,14 @@ struct SpecScanKey {
     include: Vec<String>,
 }
 
+/// Rules read from a commit never change, so they are cached by commit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct GitSpecKey {
+    repo: PathBuf,
+    commit: String,
+    include: Vec<String>,
+}
+
 #[derive(Default, Clone)]
 struct CachedScanPaths {
     files: BTreeSet<PathBuf>,
@@ -617,7 +646,7 @@ async fn get_cached_source_file(
             return Ok(entry.clone());
         }
 
-        let reqs = Reqs::extract_from_content(&canonical, &content);
+        let reqs = Reqs::extract_from_content(&canonical, &content, &cache.verbs);
         let code_units = tracey_core::code_units::extract(&canonical, &content).units;
         let parsed = CachedSourceFile {
             content_hash,
@@ -660,7 +689,7 @@ async fn get_cached_source_file(
         return Ok(updated);
     }
 
-    let reqs = Reqs::extract_from_content(&canonical, &content);
+    let reqs = Reqs::extract_from_content(&canonical, &content, &cache.verbs);
     let code_units = tracey_core::code_units::extract(&canonical, &content).units;
     let parsed = CachedSourceFile {
         content_hash,
@@ -1019,6 +1048,10 @@ async fn extract_markdown_rules_cached(
             let (section, section_title) = rule_sections
                 .remove(&req.id.to_string())
                 .unwrap_or((None, None));
+            let replaces = crate::extract_marker_replaces(&content, req.marker_span);
+            let depends = crate::extract_marker_depends(&content, req.marker_span, &prefix);
+            let owners = crate::extract_marker_owners(&content, req.marker_span);
+            let tags = crate::extract_marker_tags(&content, req.marker_span);
             extracted.push(crate::ExtractedRule {
                 def: req,
                 source_file: relative_display.clone(),
@@ -1026,6 +1059,10 @@ async fn extract_markdown_rules_cached(
                 column,
                 section,
                 section_title,
+                replaces,
+                depends,
+                owners,
+                tags,
             });
         }
     }
@@ -1189,7 +1226,7 @@ fn classify_reference_against_known_rules(
             RuleIdMatch::Stale => {
                 stale_target = Some(rule_id.clone());
             }
-            RuleIdMatch::NoMatch => {}
+            RuleIdMatch::Renamed | RuleIdMatch::NoMatch => {}
         }
     }
 
@@ -1239,6 +1276,111 @@ fn unknown_rule_message_with_context(
     }
 }
 
+/// Status of rules that stay resolvable but no longer count towards coverage.
+pub const DEPRECATED_STATUS: &str = "deprecated";
+
+pub(crate) fn is_deprecated(rule: &ApiRule) -> bool {
+    rule.status
+        .as_deref()
+        .is_some_and(|s| s.eq_ignore_ascii_case(DEPRECATED_STATUS))
+}
+
+pub(crate) fn deprecated_rule_message(rule_id: &RuleId) -> String {
+    format!("Rule '{rule_id}' is deprecated and no longer counts towards coverage")
+}
+
+/// Find the tombstone of a referenced rule among the specs using `prefix`.
+pub(crate) fn find_removed_rule<'a>(
+    config: &'a ApiConfig,
+    prefix: &str,
+    reference_id: &RuleId,
+) -> Option<&'a ApiRemovedRule> {
+    config
+        .specs
+        .iter()
+        .filter(|s| s.prefix == prefix)
+        .flat_map(|s| &s.removed)
+        .find(|r| r.id == reference_id.base)
+}
+
+// r[impl lifecycle.removed]
+pub(crate) fn removed_rule_message(reference_id: &RuleId, removed: &ApiRemovedRule) -> String {
+    let mut message = format!("Rule '{}' was removed", reference_id.base);
+    if let Some(replacement) = &removed.replaced_by {
+        message.push_str(&format!(" in favor of '{replacement}'"));
+    }
+    if let Some(reason) = &removed.reason {
+        message.push_str(&format!(": {reason}"));
+    }
+    message
+}
+
+/// Replaced rule IDs of the specs using `prefix`, by base, mapped to the rules
+/// that replace them.
+pub(crate) fn aliases_for_prefix(
+    config: &ApiConfig,
+    forward_by_impl: &BTreeMap<ImplKey, ApiSpecForward>,
+    prefix: &str,
+) -> HashMap<String, RuleId> {
+    let spec_names: std::collections::HashSet<&str> = config
+        .specs
+        .iter()
+        .filter(|s| s.prefix == prefix)
+        .map(|s| s.name.as_str())
+        .collect();
+    forward_by_impl
+        .iter()
+        .filter(|((spec_name, _), _)| spec_names.contains(spec_name.as_str()))
+        .flat_map(|(_, forward)| &forward.rules)
+        .flat_map(|rule| {
+            rule.replaces
+                .iter()
+                .map(|alias| (alias.base.clone(), rule.id.clone()))
+        })
+        .collect()
+}
+
+pub(crate) fn renamed_rule_message(reference_id: &RuleId, rule_id: &RuleId) -> String {
+    format!(
+        "Rule '{}' was renamed to '{rule_id}'; update the reference",
+        reference_id.base
+    )
+}
+
+/// r[impl config.spec.owners+2]
+/// Owners of a rule by the spec's `owners` list. As in a CODEOWNERS file,
+/// the last entry whose prefix covers the rule's ID wins.
+fn configured_owners(owners: &[crate::config::OwnersConfig], rule_id: &RuleId) -> Vec<String> {
+    owners
+        .iter()
+        .rev()
+        .find(|entry| crate::thresholds::is_under(&rule_id.base, &entry.prefix))
+        .map(|entry| entry.owners.clone())
+        .unwrap_or_default()
+}
+
+pub(crate) fn impl_in_test_message(verb: &RefVerb) -> String {
+    match verb {
+        RefVerb::Custom(verb) => {
+            format!("'{verb}' reference in test file (the config doesn't allow it in tests)")
+        }
+        _ => "Implementation reference in test file (use 'verify' instead)".to_string(),
+    }
+}
+
+pub(crate) fn needs_review_message(rule: &ApiRule) -> String {
+    let causes: Vec<String> = rule
+        .needs_review
+        .iter()
+        .map(|link| format!("'{}'", link.id))
+        .collect();
+    format!(
+        "Rule '{}' needs review: it depends on {}, which changed since its `depends=` was last updated",
+        rule.id,
+        causes.join(", ")
+    )
+}
+
 fn is_valid_rule_id(id: &RuleId) -> bool {
     let base_id = &id.base;
     for segment in base_id.split('.') {
@@ -1262,12 +1404,184 @@ fn is_valid_rule_id(id: &RuleId) -> bool {
     true
 }
 
-fn detect_circular_dependencies(forward_data: &ApiSpecForward) -> Vec<Vec<RuleId>> {
-    use std::collections::{HashMap, HashSet};
+/// A rule of the project-wide dependency graph: spec name and rule base.
+type RuleNode = (String, String);
+
+/// The prefix of the spec named `spec`, or `""` if there's no such spec.
+fn spec_prefix<'a>(config: &'a ApiConfig, spec: &str) -> &'a str {
+    config
+        .specs
+        .iter()
+        .find(|s| s.name == spec)
+        .map(|s| s.prefix.as_str())
+        .unwrap_or_default()
+}
+
+/// r[impl ref.depends.cross-spec]
+/// Resolve the `depends=` targets of every rule against all specs, and record
+/// the rules depending on each rule. A target is looked up in the rule's own
+/// spec first, then in every spec with the target's prefix.
+fn link_rule_dependencies(
+    config: &ApiConfig,
+    forward_by_impl: &mut BTreeMap<ImplKey, ApiSpecForward>,
+) {
+    let mut bases_by_spec: HashMap<String, HashSet<String>> = HashMap::new();
+    for ((spec, _), forward) in forward_by_impl.iter() {
+        bases_by_spec
+            .entry(spec.clone())
+            .or_default()
+            .extend(forward.rules.iter().map(|r| r.id.base.clone()));
+    }
+    let resolve = |own_spec: &str, prefix: &str, base: &str| {
+        std::iter::once(own_spec)
+            .chain(config.specs.iter().map(|s| s.name.as_str()))
+            .filter(|spec| spec_prefix(config, spec) == prefix)
+            .find(|spec| bases_by_spec.get(*spec).is_some_and(|b| b.contains(base)))
+            .map(String::from)
+    };
+
+    let mut dependents: BTreeMap<RuleNode, Vec<ApiRuleLink>> = BTreeMap::new();
+    for ((spec, _), forward) in forward_by_impl.iter_mut() {
+        for rule in &mut forward.rules {
+            for link in &mut rule.depends_on {
+                link.spec = resolve(spec.as_str(), &link.prefix, &link.id.base);
+                let Some(target) = &link.spec else {
+                    continue;
+                };
+                let links = dependents
+                    .entry((target.clone(), link.id.base.clone()))
+                    .or_default();
+                if !links
+                    .iter()
+                    .any(|l| l.spec.as_ref() == Some(spec) && l.id == rule.id)
+                {
+                    links.push(ApiRuleLink {
+                        prefix: spec_prefix(config, spec).to_string(),
+                        spec: Some(spec.clone()),
+                        id: rule.id.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    for ((spec, _), forward) in forward_by_impl.iter_mut() {
+        for rule in &mut forward.rules {
+            if let Some(links) = dependents.get(&(spec.clone(), rule.id.base.clone())) {
+                rule.dependents = links.clone();
+            }
+        }
+    }
+}
+
+/// r[impl ref.depends.needs-review]
+/// Mark the rules of specs with `propagate_stale` that need review: rules
+/// whose `depends=` names an older version of a rule than the current one, and
+/// rules that depend on a rule needing review. Each gets the bumped rules that
+/// cause it. Runs after [`link_rule_dependencies`].
+fn propagate_needs_review(
+    config: &Config,
+    api_config: &ApiConfig,
+    forward_by_impl: &mut BTreeMap<ImplKey, ApiSpecForward>,
+) {
+    if !config.specs.iter().any(|s| s.propagate_stale) {
+        return;
+    }
+
+    let mut current: HashMap<RuleNode, RuleId> = HashMap::new();
+    let mut edges: HashMap<RuleNode, Vec<(RuleNode, u32)>> = HashMap::new();
+    for ((spec, _), forward) in forward_by_impl.iter() {
+        for rule in &forward.rules {
+            let node = (spec.clone(), rule.id.base.clone());
+            current.insert(node.clone(), rule.id.clone());
+            edges.entry(node).or_insert_with(|| {
+                rule.depends_on
+                    .iter()
+                    .filter_map(|link| {
+                        let target = link.spec.clone()?;
+                        Some(((target, link.id.base.clone()), link.id.version))
+                    })
+                    .collect()
+            });
+        }
+    }
+
+    fn causes(
+        node: &RuleNode,
+        edges: &HashMap<RuleNode, Vec<(RuleNode, u32)>>,
+        current: &HashMap<RuleNode, RuleId>,
+        memo: &mut HashMap<RuleNode, Vec<RuleNode>>,
+        visiting: &mut HashSet<RuleNode>,
+    ) -> Vec<RuleNode> {
+        if let Some(found) = memo.get(node) {
+            return found.clone();
+        }
+        // Cycles are reported by validation; don't follow them here.
+        if !visiting.insert(node.clone()) {
+            return Vec::new();
+        }
+        let mut found: Vec<RuleNode> = Vec::new();
+        for (target, acknowledged) in edges.get(node).into_iter().flatten() {
+            let bumped = current
+                .get(target)
+                .is_some_and(|id| id.version > *acknowledged);
+            let upstream = causes(target, edges, current, memo, visiting);
+            for cause in bumped.then(|| target.clone()).into_iter().chain(upstream) {
+                if !found.contains(&cause) {
+                    found.push(cause);
+                }
+            }
+        }
+        visiting.remove(node);
+        memo.insert(node.clone(), found.clone());
+        found
+    }
+
+    let mut memo = HashMap::new();
+    let mut visiting = HashSet::new();
+    for ((spec, _), forward) in forward_by_impl.iter_mut() {
+        if !config
+            .specs
+            .iter()
+            .any(|s| s.name == *spec && s.propagate_stale)
+        {
+            continue;
+        }
+        for rule in &mut forward.rules {
+            let node = (spec.clone(), rule.id.base.clone());
+            rule.needs_review = causes(&node, &edges, &current, &mut memo, &mut visiting)
+                .into_iter()
+                .map(|cause| ApiRuleLink {
+                    prefix: spec_prefix(api_config, &cause.0).to_string(),
+                    id: current[&cause].clone(),
+                    spec: Some(cause.0),
+                })
+                .collect();
+        }
+    }
+}
 
-    let mut graph: HashMap<RuleId, Vec<RuleId>> = HashMap::new();
-    for rule in &forward_data.rules {
-        graph.entry(rule.id.clone()).or_default();
+/// Cycles of the `depends=` graph across all specs. Each cycle ends with the
+/// rule it starts with.
+fn detect_circular_dependencies(
+    forward_by_impl: &BTreeMap<ImplKey, ApiSpecForward>,
+) -> Vec<Vec<(String, RuleId)>> {
+    let mut graph: BTreeMap<RuleNode, Vec<RuleNode>> = BTreeMap::new();
+    let mut ids: HashMap<RuleNode, RuleId> = HashMap::new();
+    for ((spec, _), forward) in forward_by_impl {
+        for rule in &forward.rules {
+            let node = (spec.clone(), rule.id.base.clone());
+            ids.insert(node.clone(), rule.id.clone());
+            let edges = graph.entry(node).or_default();
+            for link in &rule.depends_on {
+                if let Some(target) = &link.spec {
+                    let target = (target.clone(), link.id.base.clone());
+                    if !edges.contains(&target) {
+                        edges.push(target);
+                    }
+                }
+            }
+        }
     }
 
     let mut cycles = Vec::new();
@@ -1276,12 +1590,12 @@ fn detect_circular_dependencies(forward_data: &ApiSpecForward) -> Vec<Vec<RuleId
     let mut path = Vec::new();
 
     fn dfs(
-        node: &RuleId,
-        graph: &HashMap<RuleId, Vec<RuleId>>,
-        visited: &mut HashSet<RuleId>,
-        rec_stack: &mut HashSet<RuleId>,
-        path: &mut Vec<RuleId>,
-        cycles: &mut Vec<Vec<RuleId>>,
+        node: &RuleNode,
+        graph: &BTreeMap<RuleNode, Vec<RuleNode>>,
+        visited: &mut HashSet<RuleNode>,
+        rec_stack: &mut HashSet<RuleNode>,
+        path: &mut Vec<RuleNode>,
+        cycles: &mut Vec<Vec<RuleNode>>,
     ) {
         visited.insert(node.clone());
         rec_stack.insert(node.clone());
@@ -1293,7 +1607,7 @@ fn detect_circular_dependencies(forward_data: &ApiSpecForward) -> Vec<Vec<RuleId
                     dfs(neighbor, graph, visited, rec_stack, path, cycles);
                 } else if rec_stack.contains(neighbor) {
                     let cycle_start = path.iter().position(|n| n == neighbor).unwrap_or(0);
-                    let mut cycle: Vec<RuleId> = path[cycle_start..].to_vec();
+                    let mut cycle: Vec<RuleNode> = path[cycle_start..].to_vec();
                     cycle.push(neighbor.clone());
                     cycles.push(cycle);
                 }
@@ -1304,10 +1618,10 @@ fn detect_circular_dependencies(forward_data: &ApiSpecForward) -> Vec<Vec<RuleId
         rec_stack.remove(node);
     }
 
-    for node in graph.keys().cloned().collect::<Vec<_>>() {
-        if !visited.contains(&node) {
+    for node in graph.keys() {
+        if !visited.contains(node) {
             dfs(
-                &node,
+                node,
                 &graph,
                 &mut visited,
                 &mut rec_stack,
@@ -1318,6 +1632,17 @@ fn detect_circular_dependencies(forward_data: &ApiSpecForward) -> Vec<Vec<RuleId
     }
 
     cycles
+        .into_iter()
+        .map(|cycle| {
+            cycle
+                .into_iter()
+                .filter_map(|node| {
+                    let id = ids.get(&node)?.clone();
+                    Some((node.0, id))
+                })
+                .collect()
+        })
+        .collect()
 }
 
 fn span_to_range(content: &str, offset: usize, length: usize) -> (u32, u32, u32, u32) {
@@ -1357,11 +1682,14 @@ fn compute_validation_by_impl(
     forward_by_impl: &BTreeMap<ImplKey, ApiSpecForward>,
     reverse_by_impl: &BTreeMap<ImplKey, ApiReverseData>,
     source_reqs_by_file: &BTreeMap<PathBuf, Reqs>,
+    file_contents: &BTreeMap<PathBuf, String>,
     test_files: &std::collections::HashSet<PathBuf>,
 ) -> BTreeMap<ImplKey, ValidationResult> {
     let mut out = BTreeMap::new();
     let known_prefixes: std::collections::HashSet<&str> =
         config.specs.iter().map(|s| s.prefix.as_str()).collect();
+    // r[impl validation.circular-deps+2]
+    let cycles = detect_circular_dependencies(forward_by_impl);
 
     for (impl_key, forward_data) in forward_by_impl {
         let (spec, impl_name) = impl_key;
@@ -1444,7 +1772,61 @@ fn compute_validation_by_impl(
                 });
             }
 
+            for link in rule.depends_on.iter().filter(|l| l.spec.is_none()) {
+                errors.push(ValidationError {
+                    code: ValidationErrorCode::UnknownRequirement,
+                    message: format!(
+                        "Rule '{}' depends on unknown rule '{}[{}]'",
+                        rule.id, link.prefix, link.id
+                    ),
+                    file: rule.source_file.clone(),
+                    line: rule.source_line,
+                    column: rule.source_column,
+                    related_rules: vec![],
+                    reference_rule_id: Some(link.id.clone()),
+                    reference_text: None,
+                });
+            }
+
+            // r[impl lifecycle.deprecated.references]
+            if is_deprecated(rule) {
+                for code_ref in rule
+                    .impl_refs
+                    .iter()
+                    .chain(&rule.verify_refs)
+                    .chain(&rule.depends_refs)
+                    .chain(&rule.other_refs)
+                {
+                    errors.push(ValidationError {
+                        code: ValidationErrorCode::DeprecatedRequirement,
+                        message: deprecated_rule_message(&rule.id),
+                        file: Some(code_ref.file.clone()),
+                        line: Some(code_ref.line),
+                        column: None,
+                        related_rules: vec![rule.id.clone()],
+                        reference_rule_id: Some(rule.id.clone()),
+                        reference_text: None,
+                    });
+                }
+            }
+
+            for renamed_ref in &rule.renamed_refs {
+                errors.push(ValidationError {
+                    code: ValidationErrorCode::RenamedRequirement,
+                    message: renamed_rule_message(&renamed_ref.reference_id, &rule.id),
+                    file: Some(renamed_ref.file.clone()),
+                    line: Some(renamed_ref.line),
+                    column: None,
+                    related_rules: vec![rule.id.clone()],
+                    reference_rule_id: Some(renamed_ref.reference_id.clone()),
+                    reference_text: None,
+                });
+            }
+
             for impl_ref in &rule.impl_refs {
+                if impl_ref.verb.is_some() {
+                    continue;
+                }
                 let ref_path = abs_root.join(&impl_ref.file);
                 if test_files.contains(&ref_path) {
                     errors.push(ValidationError {
@@ -1462,6 +1844,38 @@ fn compute_validation_by_impl(
                     });
                 }
             }
+
+            // r[impl ref.verb.custom.tests]
+            for code_ref in rule
+                .impl_refs
+                .iter()
+                .chain(&rule.verify_refs)
+                .chain(&rule.other_refs)
+            {
+                let Some(verb) = &code_ref.verb else {
+                    continue;
+                };
+                let in_tests = config
+                    .verbs
+                    .iter()
+                    .find(|v| v.name == *verb)
+                    .is_none_or(|v| v.in_tests);
+                if !in_tests && test_files.contains(&abs_root.join(&code_ref.file)) {
+                    errors.push(ValidationError {
+                        code: ValidationErrorCode::ImplInTestFile,
+                        message: format!(
+                            "Test file contains {verb} annotation for '{}' - the config doesn't allow {verb} annotations in test files",
+                            rule.id
+                        ),
+                        file: Some(code_ref.file.clone()),
+                        line: Some(code_ref.line),
+                        column: None,
+                        related_rules: vec![rule.id.clone()],
+                        reference_rule_id: None,
+                        reference_text: None,
+                    });
+                }
+            }
         }
 
         if let Some(reverse_data) = reverse_by_impl.get(impl_key) {
@@ -1488,6 +1902,12 @@ fn compute_validation_by_impl(
                 Vec::new()
             };
 
+            // References to replaced IDs resolve to the rule that replaced
+            // them, and are reported with the rule above.
+            let aliases = current_spec_prefix
+                .map(|prefix| aliases_for_prefix(config, forward_by_impl, prefix))
+                .unwrap_or_default();
+
             let mut available_prefixes: Vec<_> = known_prefixes.iter().copied().collect();
             available_prefixes.sort_unstable();
             let available_prefixes_joined = available_prefixes.join(", ");
@@ -1503,8 +1923,18 @@ fn compute_validation_by_impl(
                 else {
                     continue;
                 };
+                let content = file_contents
+                    .get(&canonical)
+                    .or_else(|| file_contents.get(&file_path));
 
                 for reference in &reqs.references {
+                    // 1-based column of the annotation, for precise locations in reports.
+                    let column = content.map(|c| {
+                        let (_, start_char, _, _) =
+                            span_to_range(c, reference.span.offset, reference.span.length);
+                        start_char as usize + 1
+                    });
+
                     if !known_prefixes.contains(reference.prefix.as_str()) {
                         errors.push(ValidationError {
                             code: ValidationErrorCode::UnknownPrefix,
@@ -1514,7 +1944,7 @@ fn compute_validation_by_impl(
                             ),
                             file: Some(file_entry.path.clone()),
                             line: Some(reference.line),
-                            column: None,
+                            column,
                             related_rules: vec![],
                             reference_rule_id: None,
                             reference_text: None,
@@ -1535,7 +1965,7 @@ fn compute_validation_by_impl(
                                     message,
                                     file: Some(file_entry.path.clone()),
                                     line: Some(reference.line),
-                                    column: None,
+                                    column,
                                     related_rules: vec![current_rule_id],
                                     reference_rule_id: Some(reference.req_id.clone()),
                                     reference_text: None,
@@ -1547,7 +1977,39 @@ fn compute_validation_by_impl(
                                     &known_rule_ids_for_prefix,
                                 ) {
                                     KnownRuleMatch::Exact | KnownRuleMatch::Stale(_) => {}
+                                    KnownRuleMatch::Missing
+                                        if aliases.contains_key(&reference.req_id.base) => {}
                                     KnownRuleMatch::Missing => {
+                                        if let Some(removed) = find_removed_rule(
+                                            config,
+                                            &reference.prefix,
+                                            &reference.req_id,
+                                        ) {
+                                            errors.push(ValidationError {
+                                                code: ValidationErrorCode::RemovedRequirement,
+                                                message: removed_rule_message(
+                                                    &reference.req_id,
+                                                    removed,
+                                                ),
+                                                file: Some(file_entry.path.clone()),
+                                                line: Some(reference.line),
+                                                column,
+                                                related_rules: removed
+                                                    .replaced_by
+                                                    .as_deref()
+                                                    .and_then(parse_rule_id)
+                                                    .into_iter()
+                                                    .collect(),
+                                                reference_rule_id: Some(reference.req_id.clone()),
+                                                reference_text: Some(format!(
+                                                    "{}[{} {}]",
+                                                    reference.prefix,
+                                                    reference.verb,
+                                                    reference.req_id
+                                                )),
+                                            });
+                                            continue;
+                                        }
                                         let message = unknown_rule_message_with_context(
                                             &reference.prefix,
                                             &reference.verb,
@@ -1559,7 +2021,7 @@ fn compute_validation_by_impl(
                                             message,
                                             file: Some(file_entry.path.clone()),
                                             line: Some(reference.line),
-                                            column: None,
+                                            column,
                                             related_rules: vec![],
                                             reference_rule_id: Some(reference.req_id.clone()),
                                             reference_text: Some(format!(
@@ -1576,34 +2038,50 @@ fn compute_validation_by_impl(
             }
         }
 
-        for cycle in detect_circular_dependencies(forward_data) {
+        // Cycles are reported by every spec they pass through.
+        for cycle in cycles
+            .iter()
+            .filter(|cycle| cycle.iter().any(|(s, _)| s == spec))
+        {
             errors.push(ValidationError {
                 code: ValidationErrorCode::CircularDependency,
                 message: format!(
                     "Circular dependency detected: {}",
                     cycle
                         .iter()
-                        .map(ToString::to_string)
+                        .map(|(s, id)| format!("{}[{id}]", spec_prefix(config, s)))
                         .collect::<Vec<_>>()
                         .join(" -> ")
                 ),
                 file: None,
                 line: None,
                 column: None,
-                related_rules: cycle,
+                related_rules: cycle.iter().map(|(_, id)| id.clone()).collect(),
                 reference_rule_id: None,
                 reference_text: None,
             });
         }
 
-        let error_count = errors.len();
+        // References to deprecated rules and replaced IDs are warnings: they
+        // still resolve.
+        let warning_count = errors
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e.code,
+                    ValidationErrorCode::DeprecatedRequirement
+                        | ValidationErrorCode::RenamedRequirement
+                )
+            })
+            .count();
+        let error_count = errors.len() - warning_count;
         out.insert(
             impl_key.clone(),
             ValidationResult {
                 spec: spec.clone(),
                 impl_name: impl_name.clone(),
                 errors,
-                warning_count: 0,
+                warning_count,
                 error_count,
             },
         );
@@ -1612,6 +2090,65 @@ fn compute_validation_by_impl(
     out
 }
 
+/// Evaluate configured coverage thresholds and record violations as
+/// validation errors and as diagnostics on the config file.
+fn apply_thresholds(
+    config: &Config,
+    config_file: &ConfigFile,
+    forward_by_impl: &BTreeMap<ImplKey, ApiSpecForward>,
+    reverse_by_impl: &BTreeMap<ImplKey, ApiReverseData>,
+    validation_by_impl: &mut BTreeMap<ImplKey, ValidationResult>,
+    workspace_diagnostics: &mut Vec<LspFileDiagnostics>,
+) {
+    let violations = crate::thresholds::evaluate(config, forward_by_impl, reverse_by_impl);
+    if violations.is_empty() {
+        return;
+    }
+
+    let config_lines: Vec<&str> = config_file.text.lines().collect();
+    let mut diagnostics = Vec::new();
+
+    for violation in violations {
+        let line = crate::thresholds::locate(&config_file.text, &violation);
+
+        let line_text = config_lines.get(line).copied().unwrap_or_default();
+        let start_char = line_text.len() - line_text.trim_start().len();
+        diagnostics.push(LspDiagnostic {
+            severity: "error".to_string(),
+            code: "coverage-threshold".to_string(),
+            message: format!(
+                "{}/{}: {}",
+                violation.spec, violation.impl_name, violation.message
+            ),
+            start_line: line as u32,
+            start_char: start_char as u32,
+            end_line: line as u32,
+            end_char: line_text.trim_end().len() as u32,
+        });
+
+        let key = (violation.spec, violation.impl_name);
+        if let Some(result) = validation_by_impl.get_mut(&key) {
+            result.errors.push(ValidationError {
+                code: ValidationErrorCode::CoverageThreshold,
+                message: violation.message,
+                file: Some(config_file.path.clone()),
+                line: Some(line + 1),
+                column: None,
+                related_rules: Vec::new(),
+                reference_rule_id: None,
+                reference_text: None,
+            });
+            result.error_count += 1;
+        }
+    }
+
+    workspace_diagnostics.push(LspFileDiagnostics {
+        path: config_file.path.clone(),
+        diagnostics,
+    });
+    workspace_diagnostics.sort_by(|a, b| a.path.cmp(&b.path));
+}
+
 fn compute_workspace_diagnostics(
     abs_root: &Path,
     config: &ApiConfig,
@@ -1643,6 +2180,10 @@ fn compute_workspace_diagnostics(
             }
         }
     }
+    let aliases_by_prefix: HashMap<&str, HashMap<String, RuleId>> = known_prefixes
+        .iter()
+        .map(|&prefix| (prefix, aliases_for_prefix(config, forward_by_impl, prefix)))
+        .collect();
 
     let mut out = Vec::new();
     for (path, reqs) in source_reqs_by_file {
@@ -1674,7 +2215,36 @@ fn compute_workspace_diagnostics(
                 .map(Vec::as_slice)
                 .unwrap_or(&[]);
             match classify_reference_against_known_rules(&reference.req_id, known_for_prefix) {
-                KnownRuleMatch::Exact => {}
+                KnownRuleMatch::Exact => {
+                    if rules_by_id
+                        .get(&reference.req_id)
+                        .is_some_and(is_deprecated)
+                    {
+                        diagnostics.push(LspDiagnostic {
+                            severity: "warning".to_string(),
+                            code: "deprecated".to_string(),
+                            message: deprecated_rule_message(&reference.req_id),
+                            start_line,
+                            start_char,
+                            end_line,
+                            end_char,
+                        });
+                    }
+                    if reference.verb == RefVerb::Impl
+                        && let Some(rule) = rules_by_id.get(&reference.req_id)
+                        && !rule.needs_review.is_empty()
+                    {
+                        diagnostics.push(LspDiagnostic {
+                            severity: "warning".to_string(),
+                            code: "needs-review".to_string(),
+                            message: needs_review_message(rule),
+                            start_line,
+                            start_char,
+                            end_line,
+                            end_char,
+                        });
+                    }
+                }
                 KnownRuleMatch::Stale(current_rule_id) => {
                     let message = stale_diagnostic_message_short(
                         &reference.req_id,
@@ -1691,15 +2261,30 @@ fn compute_workspace_diagnostics(
                     });
                 }
                 KnownRuleMatch::Missing => {
-                    let message = unknown_rule_message_with_context(
-                        &reference.prefix,
-                        &reference.verb,
-                        &reference.req_id,
-                        known_for_prefix,
-                    );
+                    let renamed_to = aliases_by_prefix
+                        .get(reference.prefix.as_str())
+                        .and_then(|aliases| aliases.get(&reference.req_id.base));
+                    let removed = find_removed_rule(config, &reference.prefix, &reference.req_id);
+                    let (code, message) = match (renamed_to, removed) {
+                        (Some(rule_id), _) => {
+                            ("renamed", renamed_rule_message(&reference.req_id, rule_id))
+                        }
+                        (None, Some(removed)) => {
+                            ("removed", removed_rule_message(&reference.req_id, removed))
+                        }
+                        (None, None) => (
+                            "orphaned",
+                            unknown_rule_message_with_context(
+                                &reference.prefix,
+                                &reference.verb,
+                                &reference.req_id,
+                                known_for_prefix,
+                            ),
+                        ),
+                    };
                     diagnostics.push(LspDiagnostic {
                         severity: "warning".to_string(),
-                        code: "orphaned".to_string(),
+                        code: code.to_string(),
                         message,
                         start_line,
                         start_char,
@@ -1709,12 +2294,11 @@ fn compute_workspace_diagnostics(
                 }
             }
 
-            if is_test && reference.verb == RefVerb::Impl {
+            if is_test && !crate::verbs::allowed_in_tests(&reference.verb, &config.verbs) {
                 diagnostics.push(LspDiagnostic {
                     severity: "warning".to_string(),
                     code: "impl-in-test".to_string(),
-                    message: "Implementation reference in test file (use 'verify' instead)"
-                        .to_string(),
+                    message: impl_in_test_message(&reference.verb),
                     start_line,
                     start_char,
                     end_line,
@@ -1768,13 +2352,14 @@ fn compute_impl_output(
     impl_name: String,
     inferred_prefix: &str,
     extracted_rules: &[crate::ExtractedRule],
+    verbs: &[ApiVerb],
     refs: Vec<ReqReference>,
     impl_code_units: BTreeMap<PathBuf, Vec<CodeUnit>>,
 ) -> ImplComputedOutput {
     let impl_start = Instant::now();
     let forward_start = Instant::now();
     struct IndexedRef {
-        verb: RefVerb,
+        role: VerbRole,
         req_id: RuleId,
         code_ref: ApiCodeRef,
         relative_file: String,
@@ -1786,6 +2371,10 @@ fn compute_impl_output(
         if r.prefix != inferred_prefix {
             continue;
         }
+        // References with an undeclared verb are ignored
+        let Some(role) = crate::verbs::role(&r.verb, verbs) else {
+            continue;
+        };
         let canonical_ref = r.file.canonicalize().unwrap_or_else(|_| r.file.clone());
         let relative_display = if let Ok(rel) = canonical_ref.strip_prefix(abs_root) {
             rel.display().to_string()
@@ -1794,11 +2383,17 @@ fn compute_impl_output(
         };
         let idx = indexed_refs.len();
         indexed_refs.push(IndexedRef {
-            verb: r.verb,
+            role,
             req_id: r.req_id.clone(),
             code_ref: ApiCodeRef {
                 file: relative_display.clone(),
                 line: r.line,
+                outcome: None,
+                hits: None,
+                verb: match &r.verb {
+                    RefVerb::Custom(verb) => Some(verb.clone()),
+                    _ => None,
+                },
             },
             relative_file: relative_display,
             line: r.line,
@@ -1817,41 +2412,48 @@ fn compute_impl_output(
         let mut impl_refs = Vec::new();
         let mut verify_refs = Vec::new();
         let mut depends_refs = Vec::new();
+        let mut other_refs = Vec::new();
         let mut stale_refs = Vec::new();
-
-        let candidate_idxs = refs_by_base
-            .get(&rule_id.base)
-            .map(Vec::as_slice)
-            .unwrap_or(&[]);
+        let mut renamed_refs = Vec::new();
+
+        let candidate_idxs = std::iter::once(&rule_id.base)
+            .chain(extracted.replaces.iter().map(|alias| &alias.base))
+            .filter_map(|base| refs_by_base.get(base))
+            .flatten();
+        // Built-in `depends`/`related` references are listed apart from those
+        // of declared verbs that count toward neither coverage
+        let mut push_ref = |entry: &IndexedRef| match entry.role {
+            VerbRole::Impl => impl_refs.push(entry.code_ref.clone()),
+            VerbRole::Verify => verify_refs.push(entry.code_ref.clone()),
+            VerbRole::Other if entry.code_ref.verb.is_some() => {
+                other_refs.push(entry.code_ref.clone())
+            }
+            VerbRole::Other => depends_refs.push(entry.code_ref.clone()),
+        };
         for idx in candidate_idxs {
             let entry = &indexed_refs[*idx];
-            match classify_reference_for_rule(&rule_id, &entry.req_id) {
-                RuleIdMatch::Exact => match entry.verb {
-                    RefVerb::Impl | RefVerb::Define => impl_refs.push(entry.code_ref.clone()),
-                    RefVerb::Verify => verify_refs.push(entry.code_ref.clone()),
-                    RefVerb::Depends | RefVerb::Related => {
-                        depends_refs.push(entry.code_ref.clone())
-                    }
-                },
-                RuleIdMatch::Stale => match entry.verb {
-                    RefVerb::Impl | RefVerb::Define => {
-                        impl_refs.push(entry.code_ref.clone());
-                        stale_refs.push(ApiStaleRef {
-                            file: entry.relative_file.clone(),
-                            line: entry.line,
-                            reference_id: entry.req_id.clone(),
-                        });
-                    }
-                    RefVerb::Verify => {
-                        verify_refs.push(entry.code_ref.clone());
+            match classify_reference_with_aliases(&rule_id, &extracted.replaces, &entry.req_id) {
+                RuleIdMatch::Exact => push_ref(entry),
+                RuleIdMatch::Stale => match entry.role {
+                    VerbRole::Impl | VerbRole::Verify => {
+                        push_ref(entry);
                         stale_refs.push(ApiStaleRef {
                             file: entry.relative_file.clone(),
                             line: entry.line,
                             reference_id: entry.req_id.clone(),
                         });
                     }
-                    RefVerb::Depends | RefVerb::Related => {}
+                    VerbRole::Other => {}
                 },
+                // r[impl ref.alias]
+                RuleIdMatch::Renamed => {
+                    push_ref(entry);
+                    renamed_refs.push(ApiStaleRef {
+                        file: entry.relative_file.clone(),
+                        line: entry.line,
+                        reference_id: entry.req_id.clone(),
+                    });
+                }
                 RuleIdMatch::NoMatch => {}
             }
         }
@@ -1874,8 +2476,32 @@ fn compute_impl_output(
             impl_refs,
             verify_refs,
             depends_refs,
+            other_refs,
             is_stale: !stale_refs.is_empty(),
             stale_refs,
+            excluded: false,
+            replaces: extracted.replaces.clone(),
+            renamed_refs,
+            // Resolved across specs by `link_rule_dependencies`
+            depends_on: extracted
+                .depends
+                .iter()
+                .map(|(prefix, id)| ApiRuleLink {
+                    prefix: prefix.clone(),
+                    spec: None,
+                    id: id.clone(),
+                })
+                .collect(),
+            dependents: Vec::new(),
+            // Set by `test_results::apply`
+            verification: None,
+            // Set by `runtime_coverage::apply`
+            executed: None,
+            // Set by `propagate_needs_review`
+            needs_review: Vec::new(),
+            // Falls back to the spec's `owners` when the marker names none
+            owners: extracted.owners.clone(),
+            tags: extracted.tags.clone(),
         });
     }
     api_rules.sort_by(|a, b| a.id.cmp(&b.id));
@@ -1884,6 +2510,7 @@ fn compute_impl_output(
         .map(|r| search::RuleEntry {
             id: r.id.to_string(),
             raw: r.raw.clone(),
+            tags: r.tags.clone(),
         })
         .collect::<Vec<_>>();
     let forward_elapsed_ms = forward_start.elapsed().as_millis();
@@ -1931,16 +2558,61 @@ fn compute_impl_output(
     }
 }
 
+/// The config file a build's config was read from. Diagnostics about the
+/// config, like threshold violations, point into it.
+#[derive(Debug, Clone)]
+pub struct ConfigFile {
+    /// Path shown in diagnostics, relative to the project root when inside it
+    pub path: String,
+    /// Text of the file, for locating settings in it
+    pub text: String,
+}
+
+impl ConfigFile {
+    pub fn new(project_root: &Path, config_path: &Path, text: String) -> Self {
+        let path = config_path
+            .strip_prefix(project_root)
+            .unwrap_or(config_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        Self { path, text }
+    }
+}
+
+impl Default for ConfigFile {
+    /// The default config location, without text: for configs that weren't
+    /// read from a file.
+    fn default() -> Self {
+        Self {
+            path: crate::DEFAULT_CONFIG_PATH.to_string(),
+            text: String::new(),
+        }
+    }
+}
+
 pub async fn build_dashboard_data(
     project_root: &Path,
     config: &Config,
     version: u64,
     quiet: bool,
+) -> Result<DashboardData> {
+    build_dashboard_data_from_file(project_root, config, &ConfigFile::default(), version, quiet)
+        .await
+}
+
+/// Build dashboard data for a config read from `config_file`.
+pub async fn build_dashboard_data_from_file(
+    project_root: &Path,
+    config: &Config,
+    config_file: &ConfigFile,
+    version: u64,
+    quiet: bool,
 ) -> Result<DashboardData> {
     let mut cache = BuildCache::default();
     build_dashboard_data_with_overlay_and_cache(
         project_root,
         config,
+        config_file,
         version,
         quiet,
         &FileOverlay::new(),
@@ -1953,6 +2625,7 @@ pub async fn build_dashboard_data(
 pub async fn build_dashboard_data_with_overlay(
     project_root: &Path,
     config: &Config,
+    config_file: &ConfigFile,
     version: u64,
     quiet: bool,
     overlay: &FileOverlay,
@@ -1961,6 +2634,7 @@ pub async fn build_dashboard_data_with_overlay(
     build_dashboard_data_with_overlay_and_cache(
         project_root,
         config,
+        config_file,
         version,
         quiet,
         overlay,
@@ -1970,9 +2644,16 @@ pub async fn build_dashboard_data_with_overlay(
     .await
 }
 
+/// Build dashboard data, reusing `cache` for files outside `changed_files`.
+///
+/// `config` is used as is: implementations of `impls_from` must already be
+/// discovered (see [`crate::discover::expand`]), so manifests aren't walked on
+/// every build.
+#[allow(clippy::too_many_arguments)]
 pub async fn build_dashboard_data_with_overlay_and_cache(
     project_root: &Path,
     config: &Config,
+    config_file: &ConfigFile,
     version: u64,
     quiet: bool,
     overlay: &FileOverlay,
@@ -1988,14 +2669,23 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
     let mut api_config = ApiConfig {
         project_root: abs_root.display().to_string(),
         specs: Vec::new(),
+        verbs: crate::verbs::declared(config)?,
     };
 
+    // Declared verbs decide what is a reference, so source files parsed
+    // with other verbs are stale
+    let verbs = crate::verbs::names(&api_config.verbs);
+    if cache.verbs != verbs {
+        cache.source_files.clear();
+        cache.verbs = verbs;
+    }
+
     let mut forward_by_impl: BTreeMap<ImplKey, ApiSpecForward> = BTreeMap::new();
     let mut reverse_by_impl: BTreeMap<ImplKey, ApiReverseData> = BTreeMap::new();
     let mut code_units_by_impl: BTreeMap<ImplKey, BTreeMap<PathBuf, Vec<CodeUnit>>> =
         BTreeMap::new();
     let specs_content_by_impl: BTreeMap<ImplKey, ApiSpecData> = BTreeMap::new();
-    let mut spec_includes_by_name: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut spec_files_by_name: BTreeMap<String, SpecFiles> = BTreeMap::new();
     let mut all_file_contents: BTreeMap<PathBuf, String> = BTreeMap::new();
     let mut all_source_reqs_by_file: BTreeMap<PathBuf, Reqs> = BTreeMap::new();
     let mut all_search_rules: Vec<search::RuleEntry> = Vec::new();
@@ -2058,6 +2748,8 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
         test_files_start.elapsed().as_millis()
     );
 
+    let git_commits = crate::spec_source::locked_commits(project_root, config)?;
+
     for spec_config in &config.specs {
         let spec_start = Instant::now();
         let spec_name = &spec_config.name;
@@ -2100,16 +2792,44 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
                 include_patterns
             );
         }
-        let (extracted_rules, spec_walk_full_scan) = load_rules_from_includes_cached(
-            project_root,
-            &include_patterns,
-            overlay,
-            cache,
-            quiet,
-            changed_files,
-            &mut cache_stats,
-        )
-        .await?;
+        let (extracted_rules, spec_walk_full_scan) =
+            match (&spec_config.git, git_commits.get(spec_name)) {
+                (Some(git), Some(commit)) => {
+                    let key = GitSpecKey {
+                        repo: crate::spec_source::repo_path(project_root, git),
+                        commit: commit.clone(),
+                        include: include_patterns.clone(),
+                    };
+                    let rules = match cache.git_specs.get(&key) {
+                        Some(rules) => rules.clone(),
+                        None => {
+                            let rules = crate::spec_source::load_rules(
+                                project_root,
+                                spec_config,
+                                git,
+                                commit,
+                                quiet,
+                            )
+                            .await?;
+                            cache.git_specs.insert(key, rules.clone());
+                            rules
+                        }
+                    };
+                    (rules, false)
+                }
+                _ => {
+                    load_rules_from_includes_cached(
+                        project_root,
+                        &include_patterns,
+                        overlay,
+                        cache,
+                        quiet,
+                        changed_files,
+                        &mut cache_stats,
+                    )
+                    .await?
+                }
+            };
         total_extracted_rules += extracted_rules.len();
 
         let unique_prefixes: BTreeSet<String> =
@@ -2148,8 +2868,26 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
             source: Some(include_patterns.join(", ")),
             source_url: spec_config.source_url.clone(),
             implementations: spec_config.impls.iter().map(|i| i.name.clone()).collect(),
+            impls_from: spec_config.impls_from.clone(),
+            removed: spec_config
+                .removed
+                .iter()
+                .map(|r| ApiRemovedRule {
+                    id: r.id.clone(),
+                    replaced_by: r.replaced_by.clone(),
+                    reason: r.reason.clone(),
+                })
+                .collect(),
         });
-        spec_includes_by_name.insert(spec_name.clone(), include_patterns.clone());
+        let spec_files = match (&spec_config.git, git_commits.get(spec_name)) {
+            (Some(git), Some(commit)) => SpecFiles::Git {
+                source: git.clone(),
+                commit: commit.clone(),
+                include: include_patterns.clone(),
+            },
+            _ => SpecFiles::Includes(include_patterns.clone()),
+        };
+        spec_files_by_name.insert(spec_name.clone(), spec_files);
 
         // Build data for each implementation
         struct ImplComputeTaskMeta {
@@ -2222,6 +2960,7 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
             let inferred_prefix_cloned = inferred_prefix.clone();
             let extracted_rules_cloned = extracted_rules.clone();
             let impl_name_cloned = impl_name.clone();
+            let verbs_cloned = api_config.verbs.clone();
             impl_compute_tasks.push(tokio::task::spawn_blocking(move || {
                 compute_impl_output(
                     &abs_root_cloned,
@@ -2229,6 +2968,7 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
                     impl_name_cloned,
                     &inferred_prefix_cloned,
                     &extracted_rules_cloned,
+                    &verbs_cloned,
                     refs,
                     impl_code_units,
                 )
@@ -2277,11 +3017,26 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
                 out.reverse_elapsed_ms
             );
 
+            let mut rules = out.api_rules;
+            // r[impl config.spec.exclude-statuses]
+            // r[impl lifecycle.deprecated]
+            for rule in &mut rules {
+                rule.excluded = rule.status.as_deref().is_some_and(|status| {
+                    status.eq_ignore_ascii_case(DEPRECATED_STATUS)
+                        || spec_config
+                            .exclude_statuses
+                            .iter()
+                            .any(|s| s.eq_ignore_ascii_case(status))
+                });
+                if rule.owners.is_empty() {
+                    rule.owners = configured_owners(&spec_config.owners, &rule.id);
+                }
+            }
             forward_by_impl.insert(
                 meta.impl_key.clone(),
                 ApiSpecForward {
                     name: spec_name.clone(),
-                    rules: out.api_rules,
+                    rules,
                 },
             );
             reverse_by_impl.insert(meta.impl_key.clone(), out.reverse_data);
@@ -2295,6 +3050,41 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
         );
     }
 
+    link_rule_dependencies(&api_config, &mut forward_by_impl);
+    propagate_needs_review(config, &api_config, &mut forward_by_impl);
+
+    // r[impl config.impl.results]
+    for spec_config in &config.specs {
+        for impl_config in spec_config.impls.iter().filter(|i| !i.results.is_empty()) {
+            let key = (spec_config.name.clone(), impl_config.name.clone());
+            let results = crate::test_results::load_configured(&abs_root, &impl_config.results);
+            if let (Some(forward), Some(code_units)) =
+                (forward_by_impl.get_mut(&key), code_units_by_impl.get(&key))
+            {
+                crate::test_results::apply(forward, code_units, &results);
+            }
+        }
+    }
+
+    // r[impl config.impl.runtime-coverage]
+    for spec_config in &config.specs {
+        for impl_config in spec_config
+            .impls
+            .iter()
+            .filter(|i| !i.runtime_coverage.is_empty())
+        {
+            let key = (spec_config.name.clone(), impl_config.name.clone());
+            let hits =
+                crate::runtime_coverage::load_configured(&abs_root, &impl_config.runtime_coverage);
+            if let (Some(forward), Some(code_units)) = (
+                forward_by_impl.get_mut(&key),
+                code_units_by_impl.get_mut(&key),
+            ) {
+                crate::runtime_coverage::apply(forward, code_units, &abs_root, &hits);
+            }
+        }
+    }
+
     // Deduplicate search rules by ID
     all_search_rules.sort_by(|a, b| a.id.cmp(&b.id));
     all_search_rules.dedup_by(|a, b| a.id == b.id);
@@ -2317,15 +3107,16 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
         content_hash ^= simple_hash(&format!("{:?}:{}", key, json));
     }
 
-    let validation_by_impl = compute_validation_by_impl(
+    let mut validation_by_impl = compute_validation_by_impl(
         &abs_root,
         &api_config,
         &forward_by_impl,
         &reverse_by_impl,
         &all_source_reqs_by_file,
+        &all_file_contents,
         &test_files,
     );
-    let workspace_diagnostics = compute_workspace_diagnostics(
+    let mut workspace_diagnostics = compute_workspace_diagnostics(
         &abs_root,
         &api_config,
         &forward_by_impl,
@@ -2333,6 +3124,15 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
         &all_file_contents,
         &test_files,
     );
+    // r[impl config.thresholds]
+    apply_thresholds(
+        config,
+        config_file,
+        &forward_by_impl,
+        &reverse_by_impl,
+        &mut validation_by_impl,
+        &mut workspace_diagnostics,
+    );
 
     let elapsed = build_start.elapsed();
     info!(
@@ -2358,7 +3158,7 @@ pub async fn build_dashboard_data_with_overlay_and_cache(
         reverse_by_impl,
         code_units_by_impl,
         specs_content_by_impl,
-        spec_includes_by_name,
+        spec_files_by_name,
         search_files: all_file_contents,
         source_reqs_by_file: all_source_reqs_by_file,
         search_rules: all_search_rules,
@@ -2381,17 +3181,56 @@ fn simple_hash(s: &str) -> u64 {
     hash
 }
 
+/// The project's markdown files matching `patterns`, as `(relative_path, content)`.
+async fn read_spec_files(
+    root: &Path,
+    patterns: &[String],
+    overlay: &FileOverlay,
+) -> Vec<(String, String)> {
+    use ignore::WalkBuilder;
+
+    let mut files = Vec::new();
+    let walker = WalkBuilder::new(root)
+        .follow_links(true)
+        .hidden(false)
+        .git_ignore(true)
+        .build();
+
+    for entry in walker.flatten() {
+        let path = entry.path();
+
+        if path.extension().is_none_or(|ext| ext != "md") {
+            continue;
+        }
+
+        let relative = path.strip_prefix(root).unwrap_or(path);
+
+        // Check if path matches any of the patterns
+        let matches_any = patterns.iter().any(|p| {
+            globset::Glob::new(p)
+                .map(|g| g.compile_matcher().is_match(relative))
+                .unwrap_or(false)
+        });
+        if !matches_any {
+            continue;
+        }
+
+        if let Ok(content) = read_file_with_overlay(path, overlay).await {
+            files.push((relative.to_string_lossy().to_string(), content));
+        }
+    }
+    files
+}
+
 async fn load_spec_content(
     root: &Path,
-    patterns: &[&str],
+    spec_files: &SpecFiles,
     spec_name: &str,
     impl_name: &str,
     coverage: &BTreeMap<String, RuleCoverage>,
     specs_content: &mut BTreeMap<String, ApiSpecData>,
     overlay: &FileOverlay,
 ) -> Result<()> {
-    use ignore::WalkBuilder;
-
     // Shared source file tracker for rule handler
     let current_source_file = Arc::new(Mutex::new(String::new()));
 
@@ -2418,42 +3257,25 @@ async fn load_spec_content(
         .with_inline_code_handler(inline_code_handler);
 
     // Collect all matching files with their content and weight
-    let mut files: Vec<(String, String, i32)> = Vec::new(); // (relative_path, content, weight)
-
-    let walker = WalkBuilder::new(root)
-        .follow_links(true)
-        .hidden(false)
-        .git_ignore(true)
-        .build();
-
-    for entry in walker.flatten() {
-        let path = entry.path();
-
-        if path.extension().is_none_or(|ext| ext != "md") {
-            continue;
-        }
-
-        let relative = path.strip_prefix(root).unwrap_or(path);
-
-        // Check if path matches any of the patterns
-        let matches_any = patterns.iter().any(|p| {
-            globset::Glob::new(p)
-                .map(|g| g.compile_matcher().is_match(relative))
-                .unwrap_or(false)
-        });
-        if !matches_any {
-            continue;
-        }
-
-        if let Ok(content) = read_file_with_overlay(path, overlay).await {
+    let contents = match spec_files {
+        SpecFiles::Includes(patterns) => read_spec_files(root, patterns, overlay).await,
+        SpecFiles::Git {
+            source,
+            commit,
+            include,
+        } => crate::spec_source::read_files(root, include, source, commit)?,
+    };
+    let mut files: Vec<(String, String, i32)> = contents // (relative_path, content, weight)
+        .into_iter()
+        .map(|(path, content)| {
             // Parse frontmatter to get weight
             let weight = match parse_frontmatter(&content) {
                 Ok((fm, _)) => fm.weight,
                 Err(_) => 0, // Default weight if no frontmatter
             };
-            files.push((relative.to_string_lossy().to_string(), content, weight));
-        }
-    }
+            (path, content, weight)
+        })
+        .collect();
 
     // Sort by weight
     files.sort_by_key(|(_, _, weight)| *weight);
@@ -2511,7 +3333,7 @@ async fn load_spec_content(
 
 pub async fn render_spec_content_for_impl(
     project_root: &Path,
-    include_patterns: &[String],
+    spec_files: &SpecFiles,
     spec_name: &str,
     impl_name: &str,
     forward: &ApiSpecForward,
@@ -2541,11 +3363,10 @@ pub async fn render_spec_content_for_impl(
         );
     }
 
-    let include_pattern_refs: Vec<&str> = include_patterns.iter().map(|s| s.as_str()).collect();
     let mut map = BTreeMap::new();
     load_spec_content(
         project_root,
-        &include_pattern_refs,
+        spec_files,
         spec_name,
         impl_name,
         &coverage,
@@ -0,0 +1,467 @@
+//! `tracey diff` implementation.
+//!
+//! Builds dashboard data for two git revisions and reports the coverage delta
+//! between them. Like `bump`, this reads trees straight from the git object
+//! database and never touches the worktree: the files tracey would scan at
+//! each revision are written to a temporary directory and analyzed there.
+
+use eyre::{Result, WrapErr, bail, eyre};
+use owo_colors::OwoColorize;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::bump::git_capture;
+use crate::config::Config;
+use crate::data::{DashboardData, build_dashboard_data};
+use crate::server::{Delta, ImplDelta};
+
+/// Coverage delta between two git revisions.
+#[derive(Debug, Clone)]
+pub struct RevisionDiff {
+    pub base: String,
+    pub head: String,
+    pub delta: Delta,
+}
+
+/// Parse a `base..head` or `base...head` revision range.
+///
+/// An empty side defaults to `HEAD`, and a single revision is compared
+/// against `HEAD`. With three dots, the base is the merge base of both sides,
+/// matching what a pull request shows.
+pub fn resolve_range(project_root: &Path, range: &str) -> Result<(String, String)> {
+    let (base, head, merge_base) = if let Some((base, head)) = range.split_once("...") {
+        (base, head, true)
+    } else if let Some((base, head)) = range.split_once("..") {
+        (base, head, false)
+    } else {
+        (range, "", false)
+    };
+
+    let or_head = |rev: &str| {
+        if rev.is_empty() {
+            "HEAD".to_string()
+        } else {
+            rev.to_string()
+        }
+    };
+    let (base, head) = (or_head(base), or_head(head));
+
+    let base = if merge_base {
+        git_capture(project_root, &["merge-base", &base, &head])?
+            .trim()
+            .to_string()
+    } else {
+        base
+    };
+
+    for rev in [&base, &head] {
+        git_capture(
+            project_root,
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{rev}^{{commit}}"),
+            ],
+        )
+        .wrap_err_with(|| format!("unknown revision: {rev}"))?;
+    }
+
+    Ok((base, head))
+}
+
+/// Whether a project-relative path could be scanned by any spec or impl.
+fn is_scanned(config: &Config, matchers: &globset::GlobSet, path: &str) -> bool {
+    // An impl without include patterns scans everything.
+    let scans_everything = config
+        .specs
+        .iter()
+        .flat_map(|s| &s.impls)
+        .any(|i| i.include.is_empty());
+    scans_everything || matchers.is_match(path)
+}
+
+fn include_matchers(config: &Config) -> Result<globset::GlobSet> {
+    let mut builder = globset::GlobSetBuilder::new();
+    let patterns = config.specs.iter().flat_map(|s| {
+        // Git spec sources are read from their own repository.
+        let spec_include = if s.git.is_some() {
+            &[][..]
+        } else {
+            &s.include[..]
+        };
+        spec_include.iter().chain(
+            s.impls
+                .iter()
+                .flat_map(|i| i.include.iter().chain(&i.test_include)),
+        )
+    });
+    for pattern in patterns {
+        // Paths outside the project root are not part of this repository's history.
+        if pattern.starts_with("../") {
+            continue;
+        }
+        builder.add(
+            globset::Glob::new(pattern)
+                .wrap_err_with(|| format!("invalid glob pattern: {pattern}"))?,
+        );
+    }
+    builder.build().wrap_err("failed to build glob set")
+}
+
+/// Read many blobs at a revision with a single `git cat-file --batch`.
+///
+/// Paths are relative to `project_root`. Missing objects are skipped.
+pub(crate) fn git_cat_files(
+    project_root: &Path,
+    revision: &str,
+    paths: &[String],
+) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut child = std::process::Command::new("git")
+        .args(["cat-file", "--batch"])
+        .current_dir(project_root)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .wrap_err("failed to run git cat-file")?;
+
+    // Feed requests from a separate thread so a full stdout pipe can't
+    // deadlock against a full stdin pipe.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let requests: String = paths
+        .iter()
+        .map(|p| format!("{revision}:./{p}\n"))
+        .collect();
+    let writer = std::thread::spawn(move || stdin.write_all(requests.as_bytes()));
+
+    let mut output = Vec::new();
+    child
+        .stdout
+        .take()
+        .expect("stdout is piped")
+        .read_to_end(&mut output)
+        .wrap_err("failed to read git cat-file output")?;
+    writer
+        .join()
+        .map_err(|_| eyre!("git cat-file writer panicked"))?
+        .wrap_err("failed to write to git cat-file")?;
+    child.wait().wrap_err("failed to wait for git cat-file")?;
+
+    // Each response is `<oid> <type> <size>\n<content>\n`, or `<name> missing\n`.
+    let mut blobs = Vec::new();
+    let mut rest = output.as_slice();
+    for path in paths {
+        let Some(newline) = rest.iter().position(|&b| b == b'\n') else {
+            bail!("truncated git cat-file output");
+        };
+        let header = String::from_utf8_lossy(&rest[..newline]).into_owned();
+        rest = &rest[newline + 1..];
+
+        let fields: Vec<&str> = header.split(' ').collect();
+        if fields.last() == Some(&"missing") {
+            continue;
+        }
+        let [_, kind, size] = fields[..] else {
+            bail!("unexpected git cat-file header: {header}");
+        };
+        let size: usize = size
+            .parse()
+            .wrap_err_with(|| format!("unexpected git cat-file header: {header}"))?;
+        if rest.len() < size + 1 {
+            bail!("truncated git cat-file output");
+        }
+        if kind == "blob" {
+            blobs.push((path.clone(), rest[..size].to_vec()));
+        }
+        rest = &rest[size + 1..];
+    }
+
+    Ok(blobs)
+}
+
+/// Load the config as it was at `revision`, if the file existed there.
+fn config_at_revision(
+    project_root: &Path,
+    revision: &str,
+    config_path: &Path,
+) -> Result<Option<Config>> {
+    let relative = config_path
+        .strip_prefix(project_root)
+        .unwrap_or(config_path);
+    let Some(content) = crate::bump::git_cat_file(
+        project_root,
+        revision,
+        &format!("./{}", relative.to_string_lossy()),
+    )?
+    else {
+        return Ok(None);
+    };
+    let config = crate::config::parse(&content)
+        .wrap_err_with(|| format!("Failed to parse config file at {revision}"))?;
+    Ok(Some(config))
+}
+
+/// Write files as they were at a git revision under a snapshot directory.
+fn write_snapshot(
+    project_root: &Path,
+    revision: &str,
+    snapshot: &Path,
+    paths: &[String],
+) -> Result<()> {
+    for (path, content) in git_cat_files(project_root, revision, paths)? {
+        let dest: PathBuf = snapshot.join(&path);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .wrap_err_with(|| format!("failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&dest, content)
+            .wrap_err_with(|| format!("failed to write {}", dest.display()))?;
+    }
+    Ok(())
+}
+
+/// Build dashboard data for a git revision without touching the worktree.
+///
+/// Uses the config file as it was at that revision, falling back to
+/// `fallback_config` when the revision has none.
+pub async fn build_at_revision(
+    project_root: &Path,
+    config_path: &Path,
+    fallback_config: &Config,
+    revision: &str,
+) -> Result<DashboardData> {
+    let mut config = config_at_revision(project_root, revision, config_path)?
+        .unwrap_or_else(|| fallback_config.clone());
+
+    let listing = git_capture(
+        project_root,
+        &["ls-tree", "-r", "-z", "--name-only", revision],
+    )?;
+    let snapshot = tempfile::tempdir().wrap_err("failed to create temporary directory")?;
+
+    // Implementations are discovered from the manifests of the revision.
+    if config.specs.iter().any(|s| s.impls_from.is_some()) {
+        let manifests: Vec<String> = listing
+            .split('\0')
+            .filter(|p| crate::discover::is_manifest(Path::new(p)))
+            .map(String::from)
+            .collect();
+        write_snapshot(project_root, revision, snapshot.path(), &manifests)?;
+        config = crate::discover::expand(&config, snapshot.path())?;
+    }
+
+    let matchers = include_matchers(&config)?;
+    let paths: Vec<String> = listing
+        .split('\0')
+        .filter(|p| !p.is_empty() && is_scanned(&config, &matchers, p))
+        .map(String::from)
+        .collect();
+    write_snapshot(project_root, revision, snapshot.path(), &paths)?;
+
+    crate::spec_source::relocate_into_snapshot(
+        project_root,
+        revision,
+        snapshot.path(),
+        &mut config,
+    )?;
+
+    build_dashboard_data(snapshot.path(), &config, 1, true)
+        .await
+        .wrap_err_with(|| format!("failed to analyze revision {revision}"))
+}
+
+/// Compute the coverage delta between two revisions.
+pub async fn diff(
+    project_root: &Path,
+    config_path: &Path,
+    config: &Config,
+    base: &str,
+    head: &str,
+) -> Result<RevisionDiff> {
+    let old = build_at_revision(project_root, config_path, config, base).await?;
+    let new = build_at_revision(project_root, config_path, config, head).await?;
+    Ok(RevisionDiff {
+        base: base.to_string(),
+        head: head.to_string(),
+        delta: Delta::compute(&old, &new),
+    })
+}
+
+fn signed(change: f64) -> String {
+    if change >= 0.0 {
+        format!("+{change:.1}%")
+    } else {
+        format!("{change:.1}%")
+    }
+}
+
+/// The `before → after (change)` metric lines shared by both formats.
+fn metrics(delta: &ImplDelta) -> [(&'static str, f64, f64); 3] {
+    [
+        (
+            "impl",
+            delta.prev_stats.impl_percent,
+            delta.curr_stats.impl_percent,
+        ),
+        (
+            "verify",
+            delta.prev_stats.verify_percent,
+            delta.curr_stats.verify_percent,
+        ),
+        (
+            "reverse",
+            delta.prev_reverse.percent(),
+            delta.curr_reverse.percent(),
+        ),
+    ]
+}
+
+impl RevisionDiff {
+    fn short(rev: &str) -> &str {
+        if rev.len() == 40 && rev.chars().all(|c| c.is_ascii_hexdigit()) {
+            &rev[..12]
+        } else {
+            rev
+        }
+    }
+
+    pub fn format_text(&self) -> String {
+        let mut out = format!(
+            "Coverage changes {}..{}\n\n",
+            Self::short(&self.base),
+            Self::short(&self.head)
+        );
+
+        for (key, delta) in &self.delta.by_impl {
+            out.push_str(&format!("{}\n", key.bold()));
+            for (name, before, after) in metrics(delta) {
+                out.push_str(&format!(
+                    "  {:<8}{:>6.1}% → {:>5.1}% ({})\n",
+                    name,
+                    before,
+                    after,
+                    signed(after - before)
+                ));
+            }
+
+            if delta.is_empty() {
+                out.push_str("  (no rule changes)\n\n");
+                continue;
+            }
+
+            for id in &delta.added_rules {
+                out.push_str(&format!("  {} {} (added)\n", "+".green(), id));
+            }
+            for id in &delta.removed_rules {
+                out.push_str(&format!("  {} {} (removed)\n", "-".red(), id));
+            }
+            for (old, new) in &delta.bumped_rules {
+                out.push_str(&format!("  {} {} → {} (bumped)\n", "~".yellow(), old, new));
+            }
+            for change in &delta.newly_covered {
+                out.push_str(&format!(
+                    "  {} {} → {}:{} ({})\n",
+                    "✓".green(),
+                    change.rule_id,
+                    change.file,
+                    change.line,
+                    change.ref_type
+                ));
+            }
+            for id in &delta.newly_uncovered {
+                out.push_str(&format!("  {} {} (coverage lost)\n", "✗".red(), id));
+            }
+            for id in &delta.newly_stale {
+                out.push_str(&format!("  {} {} (now stale)\n", "⚠".yellow(), id));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Markdown report, suitable for a pull request comment.
+    pub fn format_markdown(&self) -> String {
+        let mut out = format!(
+            "## Coverage changes `{}..{}`\n\n",
+            Self::short(&self.base),
+            Self::short(&self.head)
+        );
+
+        for (key, delta) in &self.delta.by_impl {
+            out.push_str(&format!("### {key}\n\n"));
+            out.push_str("| Coverage | Before | After | Change |\n");
+            out.push_str("|----------|-------:|------:|-------:|\n");
+            for (name, before, after) in metrics(delta) {
+                out.push_str(&format!(
+                    "| {} | {:.1}% | {:.1}% | {} |\n",
+                    name,
+                    before,
+                    after,
+                    signed(after - before)
+                ));
+            }
+            out.push('\n');
+
+            let mut list = |title: &str, items: Vec<String>| {
+                if !items.is_empty() {
+                    out.push_str(&format!("**{title}** ({})\n\n", items.len()));
+                    for item in items {
+                        out.push_str(&format!("- {item}\n"));
+                    }
+                    out.push('\n');
+                }
+            };
+            list(
+                "Added rules",
+                delta
+                    .added_rules
+                    .iter()
+                    .map(|id| format!("`{id}`"))
+                    .collect(),
+            );
+            list(
+                "Removed rules",
+                delta
+                    .removed_rules
+                    .iter()
+                    .map(|id| format!("`{id}`"))
+                    .collect(),
+            );
+            list(
+                "Bumped rules",
+                delta
+                    .bumped_rules
+                    .iter()
+                    .map(|(old, new)| format!("`{old}` → `{new}`"))
+                    .collect(),
+            );
+            list(
+                "Newly covered",
+                delta
+                    .newly_covered
+                    .iter()
+                    .map(|c| format!("`{}` → `{}:{}` ({})", c.rule_id, c.file, c.line, c.ref_type))
+                    .collect(),
+            );
+            list(
+                "Coverage lost",
+                delta
+                    .newly_uncovered
+                    .iter()
+                    .map(|id| format!("`{id}`"))
+                    .collect(),
+            );
+            list(
+                "Newly stale",
+                delta
+                    .newly_stale
+                    .iter()
+                    .map(|id| format!("`{id}`"))
+                    .collect(),
+            );
+        }
+
+        out
+    }
+}
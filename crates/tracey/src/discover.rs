@@ -0,0 +1,490 @@
+//! Implementations discovered from the packages of the project's workspace,
+//! for specs with `impls_from` set.
+//!
+//! Each package becomes an impl named after it, scanning its sources and
+//! treating its test directories as test files. Only the few manifest keys
+//! needed to find the packages are read.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use eyre::{Result, WrapErr, bail};
+
+use crate::config::{Config, Impl};
+
+/// Where a spec's implementations are discovered from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImplsFrom {
+    /// Members of a Cargo workspace
+    Cargo,
+    /// npm (or yarn/pnpm) `package.json` workspaces
+    Npm,
+    /// Modules of a Go workspace
+    Go,
+}
+
+impl ImplsFrom {
+    /// The kind named by an `impls_from` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "cargo" => Some(Self::Cargo),
+            "npm" => Some(Self::Npm),
+            "go" => Some(Self::Go),
+            _ => None,
+        }
+    }
+
+    /// Manifest at the project root listing the packages.
+    fn root_manifest(self) -> &'static str {
+        match self {
+            Self::Cargo => "Cargo.toml",
+            Self::Npm => "package.json",
+            Self::Go => "go.work",
+        }
+    }
+}
+
+/// Whether a path is a manifest implementations may be discovered from.
+pub fn is_manifest(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(|n| n.to_str()),
+        Some("Cargo.toml" | "package.json" | "go.work" | "go.mod")
+    )
+}
+
+/// A package of the workspace.
+#[derive(Debug, Clone)]
+struct Package {
+    name: String,
+    /// Directory relative to the project root, with `/` separators; empty
+    /// for the root itself
+    dir: String,
+    /// Manifest declaring the package
+    manifest: PathBuf,
+}
+
+/// r[impl config.spec.impls-from]
+/// The config with the discovered implementations added to each spec with
+/// `impls_from`. Impls listed in the config keep precedence over discovered
+/// ones of the same name, so expanding an expanded config changes nothing.
+pub fn expand(config: &Config, project_root: &Path) -> Result<Config> {
+    let mut config = config.clone();
+    for spec in &mut config.specs {
+        let Some(kind) = impls_from(&spec.name, spec.impls_from.as_deref())? else {
+            continue;
+        };
+        for package in packages(kind, project_root)? {
+            if spec.impls.iter().any(|i| i.name == package.name) {
+                continue;
+            }
+            spec.impls.push(package_impl(kind, &package));
+        }
+    }
+    Ok(config)
+}
+
+/// r[impl server.watch.manifests]
+/// Manifests the implementations of the config were discovered from, for
+/// the file watcher. Includes the root manifest even when it can't be read,
+/// so creating or fixing it is noticed.
+pub fn manifests(config: &Config, project_root: &Path) -> BTreeSet<PathBuf> {
+    let mut manifests = BTreeSet::new();
+    for spec in &config.specs {
+        let Ok(Some(kind)) = impls_from(&spec.name, spec.impls_from.as_deref()) else {
+            continue;
+        };
+        manifests.insert(project_root.join(kind.root_manifest()));
+        if kind == ImplsFrom::Go {
+            manifests.insert(project_root.join("go.mod"));
+        }
+        if let Ok(packages) = packages(kind, project_root) {
+            manifests.extend(packages.into_iter().map(|p| p.manifest));
+        }
+    }
+    manifests
+}
+
+fn impls_from(spec: &str, value: Option<&str>) -> Result<Option<ImplsFrom>> {
+    match value {
+        None => Ok(None),
+        Some(value) => match ImplsFrom::parse(value) {
+            Some(kind) => Ok(Some(kind)),
+            None => bail!(
+                "invalid `impls_from` for spec `{spec}`: `{value}` (expected `cargo`, `npm` or `go`)"
+            ),
+        },
+    }
+}
+
+fn packages(kind: ImplsFrom, project_root: &Path) -> Result<Vec<Package>> {
+    let packages = match kind {
+        ImplsFrom::Cargo => cargo_packages(project_root)?,
+        ImplsFrom::Npm => npm_packages(project_root)?,
+        ImplsFrom::Go => go_packages(project_root)?,
+    };
+    for (i, package) in packages.iter().enumerate() {
+        if let Some(other) = packages[..i].iter().find(|p| p.name == package.name) {
+            bail!(
+                "packages in `{}` and `{}` are both named `{}`",
+                display_dir(&other.dir),
+                display_dir(&package.dir),
+                package.name
+            );
+        }
+    }
+    Ok(packages)
+}
+
+fn package_impl(kind: ImplsFrom, package: &Package) -> Impl {
+    let (include, test_include): (&[&str], &[&str]) = match kind {
+        // r[impl config.spec.impls-from.cargo]
+        ImplsFrom::Cargo => (
+            &["src/**", "tests/**", "benches/**"],
+            &["tests/**", "benches/**"],
+        ),
+        // r[impl config.spec.impls-from.npm]
+        ImplsFrom::Npm => (
+            &["src/**", "test/**", "tests/**"],
+            &["test/**", "tests/**", "src/**/*.test.*", "src/**/*.spec.*"],
+        ),
+        // r[impl config.spec.impls-from.go]
+        ImplsFrom::Go => (&["**"], &["**/*_test.go"]),
+    };
+    let patterns = |patterns: &[&str]| -> Vec<String> {
+        patterns
+            .iter()
+            .map(|pattern| match package.dir.as_str() {
+                "" => pattern.to_string(),
+                dir => format!("{dir}/{pattern}"),
+            })
+            .collect()
+    };
+    Impl {
+        name: package.name.clone(),
+        include: patterns(include),
+        test_include: patterns(test_include),
+        ..Default::default()
+    }
+}
+
+fn cargo_packages(project_root: &Path) -> Result<Vec<Package>> {
+    let manifest_path = project_root.join("Cargo.toml");
+    let root = CargoManifest::read(&manifest_path)?;
+
+    let mut packages = Vec::new();
+    if let Some(name) = root.package_name {
+        packages.push(Package {
+            name,
+            dir: String::new(),
+            manifest: manifest_path,
+        });
+    }
+    for dir in member_dirs(project_root, &root.members, &root.exclude, "Cargo.toml")? {
+        let manifest = project_root.join(&dir).join("Cargo.toml");
+        let name = CargoManifest::read(&manifest)?
+            .package_name
+            .unwrap_or_else(|| dir_name(&dir));
+        packages.push(Package {
+            name,
+            dir,
+            manifest,
+        });
+    }
+    Ok(packages)
+}
+
+fn npm_packages(project_root: &Path) -> Result<Vec<Package>> {
+    let manifest_path = project_root.join("package.json");
+    let root = read_package_json(&manifest_path)?;
+
+    // `workspaces` is either a list of patterns or, with yarn, an object
+    // with a `packages` list.
+    let workspaces = root
+        .get("workspaces")
+        .map(|w| w.get("packages").unwrap_or(w))
+        .and_then(|w| w.as_array())
+        .map(|patterns| {
+            patterns
+                .iter()
+                .filter_map(|p| p.as_str())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        });
+    let Some(workspaces) = workspaces else {
+        return Ok(vec![Package {
+            name: package_json_name(&root).unwrap_or_else(|| root_dir_name(project_root)),
+            dir: String::new(),
+            manifest: manifest_path,
+        }]);
+    };
+
+    let (exclude, members): (Vec<String>, Vec<String>) =
+        workspaces.into_iter().partition(|p| p.starts_with('!'));
+    let exclude: Vec<String> = exclude
+        .iter()
+        .map(|p| p.trim_start_matches('!').to_string())
+        .collect();
+
+    let mut packages = Vec::new();
+    for dir in member_dirs(project_root, &members, &exclude, "package.json")? {
+        let manifest = project_root.join(&dir).join("package.json");
+        let name =
+            package_json_name(&read_package_json(&manifest)?).unwrap_or_else(|| dir_name(&dir));
+        packages.push(Package {
+            name,
+            dir,
+            manifest,
+        });
+    }
+    Ok(packages)
+}
+
+fn go_packages(project_root: &Path) -> Result<Vec<Package>> {
+    let work_path = project_root.join("go.work");
+    let dirs = if work_path.is_file() {
+        let content = std::fs::read_to_string(&work_path)
+            .wrap_err_with(|| format!("failed to read {}", work_path.display()))?;
+        go_work_uses(&content)
+    } else if project_root.join("go.mod").is_file() {
+        vec![String::new()]
+    } else {
+        bail!(
+            "`impls_from go` needs a go.work or go.mod in {}",
+            project_root.display()
+        );
+    };
+
+    let mut packages = Vec::new();
+    for dir in dirs {
+        if dir.starts_with("..") {
+            // Outside the project, so out of reach of include patterns
+            continue;
+        }
+        let manifest = project_root.join(&dir).join("go.mod");
+        let content = std::fs::read_to_string(&manifest)
+            .wrap_err_with(|| format!("failed to read {}", manifest.display()))?;
+        let name = go_module_name(&content).unwrap_or_else(|| match dir.as_str() {
+            "" => root_dir_name(project_root),
+            dir => dir_name(dir),
+        });
+        packages.push(Package {
+            name,
+            dir,
+            manifest,
+        });
+    }
+    Ok(packages)
+}
+
+/// Directories, relative to the project root, matching the member patterns
+/// but none of the exclude patterns, and holding a manifest.
+fn member_dirs(
+    project_root: &Path,
+    members: &[String],
+    exclude: &[String],
+    manifest: &str,
+) -> Result<Vec<String>> {
+    let matcher = |patterns: &[String]| -> Result<globset::GlobSet> {
+        let mut builder = globset::GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = globset::GlobBuilder::new(&normalize_dir(pattern))
+                .literal_separator(true)
+                .build()
+                .wrap_err_with(|| format!("invalid workspace member pattern `{pattern}`"))?;
+            builder.add(glob);
+        }
+        Ok(builder.build()?)
+    };
+    let members_set = matcher(members)?;
+    let exclude_set = matcher(exclude)?;
+
+    let walker = ignore::WalkBuilder::new(project_root)
+        .hidden(true)
+        .git_ignore(true)
+        .filter_entry(|entry| {
+            !matches!(
+                entry.file_name().to_str(),
+                Some("node_modules" | "target" | "vendor")
+            )
+        })
+        .build();
+    let mut dirs: Vec<String> = walker
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_dir()))
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(project_root).ok()?;
+            let dir = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            (!dir.is_empty()
+                && members_set.is_match(&dir)
+                && !exclude_set.is_match(&dir)
+                && entry.path().join(manifest).is_file())
+            .then_some(dir)
+        })
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// `./crates/foo/` as `crates/foo`, and `.` as the empty string.
+fn normalize_dir(dir: &str) -> String {
+    let dir = dir.trim().trim_end_matches('/');
+    let dir = dir.strip_prefix("./").unwrap_or(dir);
+    if dir == "." { "" } else { dir }.to_string()
+}
+
+fn display_dir(dir: &str) -> &str {
+    if dir.is_empty() { "." } else { dir }
+}
+
+fn dir_name(dir: &str) -> String {
+    dir.rsplit('/').next().unwrap_or(dir).to_string()
+}
+
+fn root_dir_name(project_root: &Path) -> String {
+    project_root
+        .canonicalize()
+        .ok()
+        .and_then(|root| root.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "root".to_string())
+}
+
+/// The keys of a `Cargo.toml` that name the package and list the workspace
+/// members, read without a full TOML parser.
+#[derive(Debug, Default)]
+struct CargoManifest {
+    package_name: Option<String>,
+    members: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl CargoManifest {
+    fn read(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    fn parse(content: &str) -> Self {
+        let mut manifest = Self::default();
+        let mut table = "";
+        let mut lines = content.lines();
+        while let Some(line) = lines.next() {
+            let line = strip_toml_comment(line).trim();
+            if let Some(header) = line.strip_prefix('[') {
+                table = header.trim_end_matches(']').trim();
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            // Arrays may span several lines.
+            let mut value = value.trim().to_string();
+            if value.starts_with('[') {
+                while !value.contains(']') {
+                    let Some(next) = lines.next() else { break };
+                    value.push(' ');
+                    value.push_str(strip_toml_comment(next));
+                }
+            }
+            match (table, key.trim()) {
+                ("package", "name") => manifest.package_name = toml_string(&value),
+                ("workspace", "members") => manifest.members = toml_strings(&value),
+                ("workspace", "exclude") => manifest.exclude = toml_strings(&value),
+                _ => {}
+            }
+        }
+        manifest
+    }
+}
+
+fn strip_toml_comment(line: &str) -> &str {
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn toml_string(value: &str) -> Option<String> {
+    let value = value.trim();
+    let unquoted = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))?;
+    Some(unquoted.to_string())
+}
+
+fn toml_strings(value: &str) -> Vec<String> {
+    let inner = value
+        .trim()
+        .trim_start_matches('[')
+        .trim_end()
+        .trim_end_matches(']');
+    inner.split(',').filter_map(toml_string).collect()
+}
+
+fn read_package_json(path: &Path) -> Result<serde_json::Value> {
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+    serde_json::from_str(&content).wrap_err_with(|| format!("failed to parse {}", path.display()))
+}
+
+fn package_json_name(package: &serde_json::Value) -> Option<String> {
+    package.get("name")?.as_str().map(str::to_string)
+}
+
+/// Directories of the `use` directives of a `go.work`, in their single-line
+/// and block forms.
+fn go_work_uses(content: &str) -> Vec<String> {
+    let mut dirs = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if in_block {
+            match line {
+                ")" => in_block = false,
+                "" => {}
+                dir => dirs.push(normalize_dir(dir.trim_matches('"'))),
+            }
+            continue;
+        }
+        let Some(rest) = line.strip_prefix("use") else {
+            continue;
+        };
+        match rest.trim() {
+            "(" => in_block = true,
+            dir if rest.starts_with(char::is_whitespace) && !dir.is_empty() => {
+                dirs.push(normalize_dir(dir.trim_matches('"')))
+            }
+            _ => {}
+        }
+    }
+    dirs
+}
+
+/// Last element of the module path of a `go.mod`, skipping a major version
+/// suffix: `example.com/shop/cart/v2` is `cart`.
+fn go_module_name(content: &str) -> Option<String> {
+    let path = content.lines().find_map(|line| {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        line.strip_prefix("module ")
+    })?;
+    let mut elements = path.trim().trim_matches('"').rsplit('/');
+    let last = elements.next()?;
+    let is_major_version = last
+        .strip_prefix('v')
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+    match (is_major_version, elements.next()) {
+        (true, Some(previous)) => Some(previous.to_string()),
+        _ => Some(last.to_string()),
+    }
+}
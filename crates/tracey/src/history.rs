@@ -0,0 +1,265 @@
+//! Coverage history.
+//!
+//! `tracey history record` stores the coverage of a commit in
+//! `.tracey/history/<commit>.json`, one file per commit, so recording the same
+//! commit twice replaces its entry. Commits are analyzed from the git object
+//! database (see [`build_at_revision`]), so uncommitted changes never end up
+//! in the history. `tracey query trend` and `/api/history` read it back.
+
+use eyre::{Result, WrapErr};
+use std::path::{Path, PathBuf};
+
+use tracey_api::{
+    ApiHistory, ApiHistoryEntry, ApiHistoryImpl, ApiHistorySection, ApiHistoryStats, ApiRule,
+};
+
+use crate::bump::git_capture;
+use crate::config::Config;
+use crate::data::DashboardData;
+use crate::diff::build_at_revision;
+use crate::server::{CoverageStats, sections_in_spec_order};
+
+/// Directory holding the history, relative to the project root.
+pub const HISTORY_DIR: &str = ".tracey/history";
+
+fn stats<'r>(rules: impl IntoIterator<Item = &'r ApiRule>) -> ApiHistoryStats {
+    let stats = CoverageStats::from_rule_refs(rules);
+    ApiHistoryStats {
+        total_rules: stats.total_rules,
+        impl_covered: stats.impl_covered,
+        verify_covered: stats.verify_covered,
+        stale: stats.stale_covered,
+        total_units: 0,
+        covered_units: 0,
+    }
+}
+
+/// Coverage of every spec/impl pair, with per-section breakdowns.
+pub fn snapshot(data: &DashboardData) -> Vec<ApiHistoryImpl> {
+    data.forward_by_impl
+        .iter()
+        .map(|(key, forward)| {
+            let mut total = stats(&forward.rules);
+            if let Some(reverse) = data.reverse_by_impl.get(key) {
+                total.total_units = reverse.total_units;
+                total.covered_units = reverse.covered_units;
+            }
+
+            ApiHistoryImpl {
+                spec: key.0.clone(),
+                impl_name: key.1.clone(),
+                stats: total,
+                sections: sections_in_spec_order(&forward.rules)
+                    .into_iter()
+                    .map(|(title, rules)| ApiHistorySection {
+                        title,
+                        stats: stats(rules),
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Commits to record: a single revision, or every commit of a `base..head`
+/// range, oldest first.
+pub fn resolve_commits(project_root: &Path, revisions: &str) -> Result<Vec<String>> {
+    let output = if revisions.contains("..") {
+        git_capture(project_root, &["rev-list", "--reverse", revisions])?
+    } else {
+        git_capture(
+            project_root,
+            &["rev-parse", "--verify", &format!("{revisions}^{{commit}}")],
+        )
+        .wrap_err_with(|| format!("unknown revision: {revisions}"))?
+    };
+    Ok(output.lines().map(|l| l.trim().to_string()).collect())
+}
+
+fn entry_path(project_root: &Path, commit: &str) -> PathBuf {
+    project_root
+        .join(HISTORY_DIR)
+        .join(format!("{commit}.json"))
+}
+
+/// Whether a commit already has a history entry.
+pub fn is_recorded(project_root: &Path, commit: &str) -> bool {
+    entry_path(project_root, commit).exists()
+}
+
+/// r[impl history.record]
+/// Analyze a commit and store its coverage in the history.
+pub async fn record(
+    project_root: &Path,
+    config_path: &Path,
+    config: &Config,
+    commit: &str,
+) -> Result<ApiHistoryEntry> {
+    let data = build_at_revision(project_root, config_path, config, commit).await?;
+
+    let meta = git_capture(
+        project_root,
+        &["show", "-s", "--format=%ct%x00%cs%x00%s", commit],
+    )?;
+    let mut fields = meta.trim_end().splitn(3, '\0');
+    let timestamp = fields
+        .next()
+        .and_then(|t| t.parse().ok())
+        .unwrap_or_default();
+    let date = fields.next().unwrap_or_default().to_string();
+    let subject = fields.next().unwrap_or_default().to_string();
+
+    let entry = ApiHistoryEntry {
+        commit: commit.to_string(),
+        date,
+        timestamp,
+        subject,
+        impls: snapshot(&data),
+    };
+
+    crate::daemon::ensure_tracey_dir(project_root)?;
+    let path = entry_path(project_root, commit);
+    std::fs::create_dir_all(project_root.join(HISTORY_DIR))
+        .wrap_err_with(|| format!("failed to create {HISTORY_DIR}"))?;
+    let json = facet_json::to_string(&entry).expect("JSON serialization failed");
+    std::fs::write(&path, json).wrap_err_with(|| format!("failed to write {}", path.display()))?;
+
+    Ok(entry)
+}
+
+/// Load the recorded history, oldest commit first.
+///
+/// Entries are filtered down to the given spec and impl, when set, and
+/// `limit` keeps only the most recent entries. Unreadable entries are skipped.
+pub fn load(
+    project_root: &Path,
+    spec: Option<&str>,
+    impl_name: Option<&str>,
+    limit: Option<usize>,
+) -> Result<ApiHistory> {
+    let dir = project_root.join(HISTORY_DIR);
+    if !dir.exists() {
+        return Ok(ApiHistory::default());
+    }
+
+    let mut entries = Vec::new();
+    for file in std::fs::read_dir(&dir).wrap_err_with(|| format!("failed to read {HISTORY_DIR}"))? {
+        let path = file?.path();
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                facet_json::from_str::<ApiHistoryEntry>(&json).map_err(|e| e.to_string())
+            });
+        match parsed {
+            Ok(mut entry) => {
+                entry.impls.retain(|i| {
+                    spec.is_none_or(|s| i.spec == s) && impl_name.is_none_or(|n| i.impl_name == n)
+                });
+                if !entry.impls.is_empty() {
+                    entries.push(entry);
+                }
+            }
+            Err(e) => tracing::warn!("skipping history entry {}: {e}", path.display()),
+        }
+    }
+
+    entries.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.commit.cmp(&b.commit))
+    });
+    if let Some(limit) = limit
+        && entries.len() > limit
+    {
+        entries.drain(..entries.len() - limit);
+    }
+
+    Ok(ApiHistory { entries })
+}
+
+fn percent(count: usize, total: usize) -> f64 {
+    if total > 0 {
+        count as f64 / total as f64 * 100.0
+    } else {
+        0.0
+    }
+}
+
+fn signed(change: i64) -> String {
+    if change >= 0 {
+        format!("+{change}")
+    } else {
+        change.to_string()
+    }
+}
+
+/// r[impl history.trend]
+/// Render the history as one markdown table per spec/impl pair, with the
+/// change between the first and last entry.
+pub fn format_trend(history: &ApiHistory) -> String {
+    if history.entries.is_empty() {
+        return "No coverage history recorded. Run `tracey history record` to record the \
+                current commit.\n"
+            .to_string();
+    }
+
+    let mut pairs: Vec<(&str, &str)> = Vec::new();
+    for entry in &history.entries {
+        for i in &entry.impls {
+            if !pairs.contains(&(i.spec.as_str(), i.impl_name.as_str())) {
+                pairs.push((&i.spec, &i.impl_name));
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for (spec, impl_name) in pairs {
+        let rows: Vec<(&ApiHistoryEntry, &ApiHistoryStats)> = history
+            .entries
+            .iter()
+            .filter_map(|e| {
+                e.impls
+                    .iter()
+                    .find(|i| i.spec == spec && i.impl_name == impl_name)
+                    .map(|i| (e, &i.stats))
+            })
+            .collect();
+
+        out.push_str(&format!("## {spec}/{impl_name}\n\n"));
+        out.push_str("| Commit | Date | Rules | Impl | Verify | Stale | Subject |\n");
+        out.push_str("|---|---|---|---|---|---|---|\n");
+        for (entry, s) in &rows {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} ({:.1}%) | {} ({:.1}%) | {} | {} |\n",
+                &entry.commit[..entry.commit.len().min(10)],
+                entry.date,
+                s.total_rules,
+                s.impl_covered,
+                percent(s.impl_covered, s.total_rules),
+                s.verify_covered,
+                percent(s.verify_covered, s.total_rules),
+                s.stale,
+                entry.subject.replace('|', "\\|"),
+            ));
+        }
+
+        if let (Some((_, first)), Some((_, last))) = (rows.first(), rows.last())
+            && rows.len() > 1
+        {
+            let change = |a: usize, b: usize| signed(b as i64 - a as i64);
+            out.push_str(&format!(
+                "\nOver {} commits: {} rules, {} implemented, {} verified, {} stale.\n",
+                rows.len(),
+                change(first.total_rules, last.total_rules),
+                change(first.impl_covered, last.impl_covered),
+                change(first.verify_covered, last.verify_covered),
+                change(first.stale, last.stale),
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,264 @@
+//! Impact analysis: which requirements does a change touch?
+//!
+//! Changed line ranges come from `git diff -U0` and are mapped onto the code
+//! units of every spec/impl pair. The rules referenced by touched units are
+//! affected by the change, and their `verify` references are the tests to run.
+
+use eyre::Result;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tracey_core::code_units::CodeUnit;
+use tracey_proto::{ChangedFile, ImpactResponse, ImpactedRule, ImpactedUnit, LineRange};
+
+use crate::bump::git_capture;
+use crate::data::DashboardData;
+
+/// Which changes to analyze.
+#[derive(Debug, Clone, Default)]
+pub enum ChangeSource {
+    /// Uncommitted changes, staged or not (`git diff HEAD`)
+    #[default]
+    WorkingTree,
+    /// Staged changes (`git diff --cached`)
+    Staged,
+    /// Changes between two revisions, as accepted by `tracey diff`
+    Range(String),
+}
+
+/// Parse the `+start,count` side of a `@@` hunk header.
+fn parse_hunk(line: &str) -> Option<LineRange> {
+    let new_side = line.split_whitespace().find(|part| part.starts_with('+'))?;
+    let mut parts = new_side[1..].splitn(2, ',');
+    let start: usize = parts.next()?.parse().ok()?;
+    let count: usize = match parts.next() {
+        Some(count) => count.parse().ok()?,
+        None => 1,
+    };
+    // A pure deletion has no new lines; it happened right after `start`.
+    let start = start.max(1);
+    Some(LineRange {
+        start,
+        end: start + count.saturating_sub(1),
+    })
+}
+
+/// Parse the output of `git diff -U0 --no-prefix`. Deleted files are skipped,
+/// since they have no lines left to map.
+pub fn parse_diff(diff: &str) -> Vec<ChangedFile> {
+    let mut files: Vec<ChangedFile> = Vec::new();
+    let mut current: Option<usize> = None;
+    // Added lines may start with `+++` too, so only look for the new file
+    // name in the header of each file's diff.
+    let mut in_header = false;
+    for line in diff.lines() {
+        if line.starts_with("diff ") {
+            in_header = true;
+            current = None;
+        } else if in_header && let Some(path) = line.strip_prefix("+++ ") {
+            in_header = false;
+            let path = path.trim_end_matches('\t');
+            current = if path == "/dev/null" {
+                None
+            } else {
+                files.push(ChangedFile {
+                    path: path.to_string(),
+                    ranges: Vec::new(),
+                });
+                Some(files.len() - 1)
+            };
+        } else if line.starts_with("@@")
+            && let Some(index) = current
+            && let Some(range) = parse_hunk(line)
+        {
+            files[index].ranges.push(range);
+        }
+    }
+    files.retain(|f| !f.ranges.is_empty());
+    files
+}
+
+/// The revision the changed lines of `source` refer to, or `None` for the
+/// worktree. Staged lines refer to the index, which is written out as a tree
+/// object so it can be read like any revision.
+pub fn changed_revision(project_root: &Path, source: &ChangeSource) -> Result<Option<String>> {
+    match source {
+        ChangeSource::WorkingTree => Ok(None),
+        ChangeSource::Staged => {
+            let tree = git_capture(project_root, &["write-tree"])?;
+            Ok(Some(tree.trim().to_string()))
+        }
+        ChangeSource::Range(range) => {
+            let (_, head) = crate::diff::resolve_range(project_root, range)?;
+            Ok(Some(head))
+        }
+    }
+}
+
+/// Collect the changed lines of `source` with `git diff`.
+pub fn changed_lines(project_root: &Path, source: &ChangeSource) -> Result<Vec<ChangedFile>> {
+    let mut args = vec![
+        "-c".to_string(),
+        "core.quotePath=false".to_string(),
+        "diff".to_string(),
+        "-U0".to_string(),
+        "--no-prefix".to_string(),
+        "--no-color".to_string(),
+        "--no-ext-diff".to_string(),
+    ];
+    match source {
+        ChangeSource::WorkingTree => args.push("HEAD".to_string()),
+        ChangeSource::Staged => args.push("--cached".to_string()),
+        ChangeSource::Range(range) => {
+            let (base, head) = crate::diff::resolve_range(project_root, range)?;
+            args.extend([base, head]);
+        }
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    Ok(parse_diff(&git_capture(project_root, &args)?))
+}
+
+fn unit(file: &str, unit: &CodeUnit) -> ImpactedUnit {
+    ImpactedUnit {
+        file: file.to_string(),
+        kind: format!("{:?}", unit.kind).to_lowercase(),
+        name: unit.name.clone(),
+        start_line: unit.start_line,
+        end_line: unit.end_line,
+    }
+}
+
+/// Whether a code unit overlaps any of the changed ranges.
+fn touched(unit: &CodeUnit, ranges: &[LineRange]) -> bool {
+    ranges
+        .iter()
+        .any(|r| r.start <= unit.end_line && r.end >= unit.start_line)
+}
+
+/// r[impl impact.changes+2]
+/// Map changed lines onto code units and collect the rules they reference.
+///
+/// `data` must be built from the side of the diff the lines refer to (see
+/// [`changed_revision`]). Code unit paths are absolute, so changed files are
+/// matched by suffix; this also works for data built from a snapshot of
+/// another revision.
+pub fn analyze(data: &DashboardData, files: &[ChangedFile]) -> ImpactResponse {
+    let mut rules: BTreeMap<(String, String, String), ImpactedRule> = BTreeMap::new();
+    let mut unmapped: BTreeMap<(String, usize), ImpactedUnit> = BTreeMap::new();
+
+    for ((spec, impl_name), units_by_file) in &data.code_units_by_impl {
+        let Some(forward) = data.forward_by_impl.get(&(spec.clone(), impl_name.clone())) else {
+            continue;
+        };
+        for file in files {
+            let Some(units) = units_by_file
+                .iter()
+                .find(|(path, _)| path.ends_with(&file.path))
+                .map(|(_, units)| units)
+            else {
+                continue;
+            };
+            let touched: Vec<&CodeUnit> =
+                units.iter().filter(|u| touched(u, &file.ranges)).collect();
+            for &code_unit in &touched {
+                if code_unit.req_refs.is_empty() {
+                    // An enclosing unit (e.g. a module) is only unmapped if
+                    // none of the changed units inside it are reported.
+                    let encloses_other = touched.iter().any(|other| {
+                        !std::ptr::eq(*other, code_unit)
+                            && code_unit.start_line <= other.start_line
+                            && other.end_line <= code_unit.end_line
+                    });
+                    if encloses_other {
+                        continue;
+                    }
+                    unmapped
+                        .entry((file.path.clone(), code_unit.start_line))
+                        .or_insert_with(|| unit(&file.path, code_unit));
+                    continue;
+                }
+                for req in &code_unit.req_refs {
+                    // Stale references still put the current rule at risk.
+                    let Some(rule) = forward.rules.iter().find(|r| r.id.base == req.base) else {
+                        continue;
+                    };
+                    let entry = rules
+                        .entry((spec.clone(), impl_name.clone(), rule.id.base.clone()))
+                        .or_insert_with(|| ImpactedRule {
+                            spec: spec.clone(),
+                            impl_name: impl_name.clone(),
+                            id: rule.id.clone(),
+                            units: Vec::new(),
+                            verify_refs: rule.verify_refs.clone(),
+                        });
+                    if !entry
+                        .units
+                        .iter()
+                        .any(|u| u.file == file.path && u.start_line == code_unit.start_line)
+                    {
+                        entry.units.push(unit(&file.path, code_unit));
+                    }
+                }
+            }
+        }
+    }
+
+    ImpactResponse {
+        changed_files: files.len(),
+        rules: rules.into_values().collect(),
+        unmapped_units: unmapped.into_values().collect(),
+    }
+}
+
+fn describe(unit: &ImpactedUnit) -> String {
+    match &unit.name {
+        Some(name) => format!(
+            "{} `{name}` ({}:{}-{})",
+            unit.kind, unit.file, unit.start_line, unit.end_line
+        ),
+        None => format!(
+            "{} ({}:{}-{})",
+            unit.kind, unit.file, unit.start_line, unit.end_line
+        ),
+    }
+}
+
+/// r[impl impact.report]
+/// Render an impact report as markdown: affected rules grouped by spec/impl,
+/// each with the changed units and the tests to run.
+pub fn format_impact(impact: &ImpactResponse) -> String {
+    let mut out = format!(
+        "{} rule(s) affected by changes to {} file(s)\n\n",
+        impact.rules.len(),
+        impact.changed_files
+    );
+
+    let mut pair: Option<(&str, &str)> = None;
+    for rule in &impact.rules {
+        if pair != Some((&rule.spec, &rule.impl_name)) {
+            pair = Some((&rule.spec, &rule.impl_name));
+            out.push_str(&format!("## {}/{}\n\n", rule.spec, rule.impl_name));
+        }
+        out.push_str(&format!("### {}\n", rule.id));
+        for unit in &rule.units {
+            out.push_str(&format!("  - changed: {}\n", describe(unit)));
+        }
+        if rule.verify_refs.is_empty() {
+            out.push_str("  - no verification references\n");
+        }
+        for r in &rule.verify_refs {
+            out.push_str(&format!("  - verify: {}:{}\n", r.file, r.line));
+        }
+        out.push('\n');
+    }
+
+    if !impact.unmapped_units.is_empty() {
+        out.push_str("## Changed code without rule references\n\n");
+        for unit in &impact.unmapped_units {
+            out.push_str(&format!("  - {}\n", describe(unit)));
+        }
+        out.push('\n');
+    }
+
+    out
+}
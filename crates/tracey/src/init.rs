@@ -0,0 +1,361 @@
+//! `tracey init` implementation.
+//!
+//! Proposes a first config from the repository layout: markdown files with
+//! requirement markers become specs (one per marker prefix), and source files
+//! tracey can scan become one impl per language. Test globs are proposed from
+//! common naming conventions.
+
+use eyre::{Result, WrapErr};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use tracey_core::{RefVerb, Reqs, SUPPORTED_EXTENSIONS};
+
+use crate::config::Config;
+
+/// Directories that never hold sources worth scanning, even when they are not
+/// gitignored.
+const SKIPPED_DIRS: &[&str] = &[".git", "target", "node_modules", "vendor"];
+
+/// A config proposed by [`propose`].
+#[derive(Debug, Clone, Default)]
+pub struct Proposal {
+    pub specs: Vec<ProposedSpec>,
+}
+
+/// A spec found in the repository.
+#[derive(Debug, Clone)]
+pub struct ProposedSpec {
+    pub name: String,
+    /// Marker prefix used by the spec's rules (e.g. `r`)
+    pub prefix: String,
+    pub rule_count: usize,
+    pub include: Vec<String>,
+    pub impls: Vec<ProposedImpl>,
+}
+
+/// Source files of one language.
+#[derive(Debug, Clone)]
+pub struct ProposedImpl {
+    pub name: String,
+    pub file_count: usize,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub test_include: Vec<String>,
+}
+
+/// Impl name for a file extension. Extensions of the same language share an
+/// impl; anything not listed here gets an impl named after the extension.
+fn language(ext: &str) -> &str {
+    match ext {
+        "rs" => "rust",
+        "ts" | "tsx" => "typescript",
+        "js" | "jsx" => "javascript",
+        "py" => "python",
+        "go" => "go",
+        "c" | "h" => "c",
+        "cpp" | "hpp" | "cc" | "cxx" => "cpp",
+        "m" | "mm" => "objc",
+        "kt" | "kts" => "kotlin",
+        "cs" => "csharp",
+        "rb" => "ruby",
+        "r" | "R" => "r",
+        "hs" | "lhs" => "haskell",
+        "ex" | "exs" => "elixir",
+        "erl" | "hrl" => "erlang",
+        "clj" | "cljs" | "cljc" | "edn" => "clojure",
+        "fs" | "fsi" | "fsx" => "fsharp",
+        "vb" | "vbs" => "visualbasic",
+        "cob" | "cbl" | "cpy" => "cobol",
+        "ps1" | "psm1" | "psd1" => "powershell",
+        "ml" | "mli" => "ocaml",
+        "sh" | "bash" | "zsh" => "shell",
+        "asm" | "s" | "S" => "assembly",
+        "pl" | "pm" => "perl",
+        other => other,
+    }
+}
+
+/// Test globs matching `path` under common conventions: `tests/` and
+/// `__tests__/` directories, `*_test.go`, `test_*.py`, `*_test.py`, and
+/// `*.spec.*` / `*.test.*` for JavaScript and TypeScript.
+fn test_globs(path: &str, ext: &str) -> Vec<String> {
+    let mut globs = Vec::new();
+    let (dirs, file_name) = path.rsplit_once('/').unwrap_or(("", path));
+    for dir in ["tests", "__tests__"] {
+        if dirs.split('/').any(|c| c == dir) {
+            globs.push(format!("**/{dir}/**/*.{ext}"));
+        }
+    }
+    match ext {
+        "go" if file_name.ends_with("_test.go") => globs.push("**/*_test.go".to_string()),
+        "py" if file_name.starts_with("test_") => globs.push("**/test_*.py".to_string()),
+        "py" if file_name.ends_with("_test.py") => globs.push("**/*_test.py".to_string()),
+        "ts" | "tsx" | "js" | "jsx" => {
+            for kind in ["spec", "test"] {
+                if file_name.ends_with(&format!(".{kind}.{ext}")) {
+                    globs.push(format!("**/*.{kind}.{ext}"));
+                }
+            }
+        }
+        _ => {}
+    }
+    globs
+}
+
+/// Whether `dir` (a relative directory, `""` for the root) contains `path`.
+fn contains(dir: &str, path: &str) -> bool {
+    dir.is_empty() || path.starts_with(&format!("{dir}/"))
+}
+
+/// Include globs for one spec's files, widening each file to its directory
+/// when no other spec's files live there.
+fn spec_globs(files: &BTreeSet<String>, other_files: &[&String]) -> Vec<String> {
+    let mut dirs: BTreeSet<&str> = BTreeSet::new();
+    let mut globs = Vec::new();
+    for file in files {
+        let dir = file.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+        if dir.is_empty() || other_files.iter().any(|f| contains(dir, f)) {
+            globs.push(file.clone());
+        } else {
+            dirs.insert(dir);
+        }
+    }
+    // Drop directories nested in another proposed directory.
+    let top: Vec<&str> = dirs
+        .iter()
+        .copied()
+        .filter(|d| !dirs.iter().any(|other| other != d && contains(other, d)))
+        .collect();
+    globs.extend(top.iter().map(|d| format!("{d}/**/*.md")));
+    globs.sort();
+    globs
+}
+
+/// Group source files by language and derive include, exclude and test globs.
+fn propose_impls(root: &Path, sources: &[(String, String)]) -> Result<Vec<ProposedImpl>> {
+    let mut by_language: BTreeMap<&str, Vec<&(String, String)>> = BTreeMap::new();
+    for source in sources {
+        by_language
+            .entry(language(&source.1))
+            .or_default()
+            .push(source);
+    }
+
+    let mut impls = Vec::new();
+    for (name, files) in by_language {
+        let mut include = BTreeSet::new();
+        let mut exclude = BTreeSet::new();
+        let mut tests: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+        for (path, ext) in &files {
+            match path.split_once('/') {
+                Some((top, _)) => include.insert(format!("{top}/**/*.{ext}")),
+                None => include.insert(format!("*.{ext}")),
+            };
+            // Fixtures usually contain annotations meant for tests, not for
+            // the project's own coverage.
+            if path.split('/').any(|c| c == "fixtures") {
+                exclude.insert("**/fixtures/**".to_string());
+            }
+            for glob in test_globs(path, ext) {
+                tests.entry(glob).or_default().push(path);
+            }
+        }
+
+        // Test files may only contain `verify` references, so a convention
+        // that matches a file with `impl` references is not proposed.
+        let mut test_include = Vec::new();
+        for (glob, paths) in tests {
+            let mut has_impl = false;
+            for path in paths {
+                let content = std::fs::read_to_string(root.join(path))
+                    .wrap_err_with(|| format!("failed to read {path}"))?;
+                let reqs = Reqs::extract_from_content(Path::new(path), &content, &[]);
+                if reqs
+                    .references
+                    .iter()
+                    .any(|r| matches!(r.verb, RefVerb::Impl))
+                {
+                    has_impl = true;
+                    break;
+                }
+            }
+            if !has_impl {
+                test_include.push(glob);
+            }
+        }
+
+        impls.push(ProposedImpl {
+            name: name.to_string(),
+            file_count: files.len(),
+            include: include.into_iter().collect(),
+            exclude: exclude.into_iter().collect(),
+            test_include,
+        });
+    }
+    Ok(impls)
+}
+
+/// Scan `root` and propose a config.
+///
+/// Every spec gets every proposed impl; unneeded ones can be deleted from the
+/// written config.
+pub async fn propose(root: &Path) -> Result<Proposal> {
+    let rules = crate::load_rules_from_globs(root, &["**/*.md"], true).await?;
+
+    let mut spec_files: BTreeMap<String, (BTreeSet<String>, usize)> = BTreeMap::new();
+    for rule in &rules {
+        let entry = spec_files.entry(rule.prefix.clone()).or_default();
+        entry.0.insert(rule.source_file.replace('\\', "/"));
+        entry.1 += 1;
+    }
+
+    let mut sources: Vec<(String, String)> = Vec::new();
+    let walker = ignore::WalkBuilder::new(root)
+        .follow_links(true)
+        .hidden(false)
+        .git_ignore(true)
+        .filter_entry(|entry| {
+            !entry.file_type().is_some_and(|ft| ft.is_dir())
+                || !SKIPPED_DIRS
+                    .iter()
+                    .any(|d| entry.file_name() == std::ffi::OsStr::new(d))
+        })
+        .build();
+    for entry in walker.flatten() {
+        if !entry.file_type().is_some_and(|ft| ft.is_file()) {
+            continue;
+        }
+        let path = entry.path();
+        let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+            continue;
+        };
+        if !SUPPORTED_EXTENSIONS.contains(&ext) {
+            continue;
+        }
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        sources.push((
+            relative.to_string_lossy().replace('\\', "/"),
+            ext.to_string(),
+        ));
+    }
+    sources.sort();
+
+    let impls = propose_impls(root, &sources)?;
+
+    let project_name = root
+        .canonicalize()
+        .ok()
+        .and_then(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "spec".to_string());
+
+    let mut specs = Vec::new();
+    for (prefix, (files, rule_count)) in &spec_files {
+        let other_files: Vec<&String> = spec_files
+            .iter()
+            .filter(|(p, _)| *p != prefix)
+            .flat_map(|(_, (files, _))| files)
+            .collect();
+        specs.push(ProposedSpec {
+            // `r` is the conventional prefix, so it says nothing about the spec.
+            name: if prefix == "r" {
+                project_name.clone()
+            } else {
+                prefix.clone()
+            },
+            prefix: prefix.clone(),
+            rule_count: *rule_count,
+            include: spec_globs(files, &other_files),
+            impls: impls.clone(),
+        });
+    }
+
+    Ok(Proposal { specs })
+}
+
+/// Quote a value unless it can be written as a bare styx scalar.
+fn scalar(value: &str) -> String {
+    let bare = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./*+:".contains(c));
+    if bare {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+fn push_list(out: &mut String, indent: &str, key: &str, values: &[String]) {
+    match values {
+        [] => {}
+        [value] => out.push_str(&format!("{indent}{key} ({})\n", scalar(value))),
+        values => {
+            out.push_str(&format!("{indent}{key} (\n"));
+            for value in values {
+                out.push_str(&format!("{indent}    {}\n", scalar(value)));
+            }
+            out.push_str(&format!("{indent})\n"));
+        }
+    }
+}
+
+impl Proposal {
+    /// Render the proposal as a commented `config.styx`.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str("@schema {id crate:tracey-config@1, cli tracey}\n\n");
+        out.push_str("// Generated by `tracey init`. Review the globs below, then run\n");
+        out.push_str("// `tracey query status` to see coverage.\n\n");
+        out.push_str("specs (\n");
+        for (i, spec) in self.specs.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                "    // {} rule(s) with the `{}[...]` marker\n",
+                spec.rule_count, spec.prefix
+            ));
+            out.push_str("    {\n");
+            out.push_str(&format!("        name {}\n", scalar(&spec.name)));
+            push_list(&mut out, "        ", "include", &spec.include);
+            out.push_str("        impls (\n");
+            for (j, impl_) in spec.impls.iter().enumerate() {
+                if j > 0 {
+                    out.push('\n');
+                }
+                out.push_str(&format!(
+                    "            // {} source file(s)\n",
+                    impl_.file_count
+                ));
+                out.push_str("            {\n");
+                out.push_str(&format!("                name {}\n", scalar(&impl_.name)));
+                push_list(&mut out, "                ", "include", &impl_.include);
+                push_list(&mut out, "                ", "exclude", &impl_.exclude);
+                if !impl_.test_include.is_empty() {
+                    out.push_str(
+                        "                // Test files may only contain `verify` references\n",
+                    );
+                    push_list(
+                        &mut out,
+                        "                ",
+                        "test_include",
+                        &impl_.test_include,
+                    );
+                }
+                out.push_str("            }\n");
+            }
+            out.push_str("        )\n");
+            out.push_str("    }\n");
+        }
+        out.push_str(")\n");
+        out
+    }
+}
+
+/// Parse a rendered config against the config schema.
+pub fn validate(config_text: &str) -> Result<Config> {
+    crate::config::parse(config_text).wrap_err("generated config does not match the config schema")
+}
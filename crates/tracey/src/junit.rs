@@ -0,0 +1,212 @@
+//! JUnit XML export of requirement coverage.
+//!
+//! Each requirement becomes a `<testcase>`, grouped into one `<testsuite>` per
+//! spec section, so requirement coverage shows up in CI tooling that only
+//! understands test reports:
+//!
+//! - excluded requirements (deprecated, or with a status listed in
+//!   `exclude_statuses`) are `<skipped>`, since they don't count for coverage
+//! - stale requirements (code points at an older version) are `<error>`s
+//! - uncovered requirements (no `impl` references) are `<failure>`s
+//! - untested requirements (no `verify` references) are `<skipped>`, or
+//!   `<failure>`s when [`JunitOptions::fail_untested`] is set
+//! - everything else passes
+
+use std::fmt::Write as _;
+
+use tracey_api::{ApiRule, ApiSpecForward};
+
+use crate::data::DashboardData;
+use crate::server::sections_in_spec_order;
+
+/// Options for the JUnit exporter.
+#[derive(Debug, Clone, Default)]
+pub struct JunitOptions {
+    /// Report untested requirements as failures instead of skipped.
+    pub fail_untested: bool,
+}
+
+/// Outcome of a single requirement test case.
+enum Outcome {
+    Passed,
+    Skipped(String),
+    /// Failure type (`uncovered` or `untested`) and message.
+    Failure(&'static str, String),
+    Error(String),
+}
+
+fn outcome(rule: &ApiRule, options: &JunitOptions) -> Outcome {
+    if rule.excluded {
+        let status = rule.status.as_deref().unwrap_or_default();
+        return Outcome::Skipped(format!("Excluded from coverage (status: {status})"));
+    }
+
+    if rule.is_stale {
+        let mut message = String::from("References point to an older version of this rule:");
+        for stale in &rule.stale_refs {
+            let _ = write!(
+                message,
+                "\n  {}:{} references {}",
+                stale.file, stale.line, stale.reference_id
+            );
+        }
+        return Outcome::Error(message);
+    }
+
+    if rule.impl_refs.is_empty() {
+        return Outcome::Failure("uncovered", "No implementation references".to_string());
+    }
+
+    if rule.verify_refs.is_empty() {
+        let message = "No verification references".to_string();
+        return if options.fail_untested {
+            Outcome::Failure("untested", message)
+        } else {
+            Outcome::Skipped(message)
+        };
+    }
+
+    Outcome::Passed
+}
+
+/// Per-suite counts, as required by the `<testsuite>` attributes.
+#[derive(Default)]
+struct Counts {
+    tests: usize,
+    failures: usize,
+    errors: usize,
+    skipped: usize,
+}
+
+impl Counts {
+    fn add(&mut self, outcome: &Outcome) {
+        self.tests += 1;
+        match outcome {
+            Outcome::Passed => {}
+            Outcome::Skipped(_) => self.skipped += 1,
+            Outcome::Failure(..) => self.failures += 1,
+            Outcome::Error(_) => self.errors += 1,
+        }
+    }
+
+    fn merge(&mut self, other: &Counts) {
+        self.tests += other.tests;
+        self.failures += other.failures;
+        self.errors += other.errors;
+        self.skipped += other.skipped;
+    }
+}
+
+/// Render one `<testsuite>` per section of a spec/impl pair, in spec order.
+fn render_suites(
+    spec: &str,
+    impl_name: &str,
+    forward: &ApiSpecForward,
+    options: &JunitOptions,
+    totals: &mut Counts,
+) -> String {
+    let mut out = String::new();
+    for (title, rules) in sections_in_spec_order(&forward.rules) {
+        let mut counts = Counts::default();
+        let mut cases = String::new();
+
+        for rule in rules {
+            let outcome = outcome(rule, options);
+            counts.add(&outcome);
+
+            let _ = write!(
+                cases,
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                xml_escape(&format!("{spec}.{impl_name}")),
+                xml_escape(&rule.id.to_string())
+            );
+            if let Some(file) = &rule.source_file {
+                let _ = write!(cases, " file=\"{}\"", xml_escape(file));
+            }
+            if let Some(line) = rule.source_line {
+                let _ = write!(cases, " line=\"{line}\"");
+            }
+
+            match &outcome {
+                Outcome::Passed => cases.push_str("/>\n"),
+                Outcome::Skipped(message) => {
+                    let _ = writeln!(
+                        cases,
+                        ">\n      <skipped message=\"{}\"/>",
+                        xml_escape(message)
+                    );
+                    cases.push_str("    </testcase>\n");
+                }
+                Outcome::Failure(kind, message) => {
+                    let _ = writeln!(
+                        cases,
+                        ">\n      <failure type=\"{}\" message=\"{}\"/>",
+                        kind,
+                        xml_escape(message)
+                    );
+                    cases.push_str("    </testcase>\n");
+                }
+                Outcome::Error(message) => {
+                    let summary = message.lines().next().unwrap_or_default();
+                    let _ = writeln!(
+                        cases,
+                        ">\n      <error type=\"stale\" message=\"{}\">{}</error>",
+                        xml_escape(summary),
+                        xml_escape(message)
+                    );
+                    cases.push_str("    </testcase>\n");
+                }
+            }
+        }
+
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+            xml_escape(&format!("{spec}/{impl_name}: {title}")),
+            counts.tests,
+            counts.failures,
+            counts.errors,
+            counts.skipped
+        );
+        out.push_str(&cases);
+        out.push_str("  </testsuite>\n");
+
+        totals.merge(&counts);
+    }
+
+    out
+}
+
+/// Render every spec/impl pair in the dashboard data as a JUnit XML report.
+pub fn render_junit(data: &DashboardData, options: &JunitOptions) -> String {
+    let mut totals = Counts::default();
+    let mut suites = String::new();
+
+    for ((spec, impl_name), forward) in &data.forward_by_impl {
+        suites.push_str(&render_suites(
+            spec,
+            impl_name,
+            forward,
+            options,
+            &mut totals,
+        ));
+    }
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"tracey\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+        totals.tests, totals.failures, totals.errors, totals.skipped
+    );
+    out.push_str(&suites);
+    out.push_str("</testsuites>\n");
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
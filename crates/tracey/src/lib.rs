@@ -3,19 +3,36 @@
 //! This library exposes the core functionality of tracey for testing
 //! and embedding purposes.
 
+pub mod badge;
 pub mod bridge;
 pub mod bump;
+pub mod check;
 pub mod config;
 pub mod daemon;
 pub mod data;
+pub mod diff;
+pub mod discover;
+pub mod history;
+pub mod impact;
+pub mod init;
+pub mod junit;
+pub mod matrix;
+pub mod output;
 pub(crate) mod rule_suggestions;
+pub mod runtime_coverage;
+pub mod sarif;
 pub mod search;
 pub mod server;
+pub mod site;
+pub mod spec_source;
+pub mod test_results;
+pub mod thresholds;
+pub mod verbs;
 pub mod vite;
 
 use config::Config;
 use eyre::{Result, WrapErr};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracey_core::ReqDefinition;
 
 // Re-export from marq for rule extraction
@@ -34,6 +51,15 @@ pub struct ExtractedRule {
     pub section: Option<String>,
     /// Section title (heading text) that this rule belongs to
     pub section_title: Option<String>,
+    /// Rule IDs this rule replaces (`replaces=` in its marker)
+    pub replaces: Vec<tracey_core::RuleId>,
+    /// Rules this rule depends on (`depends=` in its marker), as
+    /// `(prefix, id)`; unqualified IDs have the rule's own prefix
+    pub depends: Vec<(String, tracey_core::RuleId)>,
+    /// Owners of this rule (`owner=` in its marker), e.g. "@payments"
+    pub owners: Vec<String>,
+    /// Tags of this rule (`tags=` in its marker), e.g. "security"
+    pub tags: Vec<String>,
 }
 
 /// Compute 1-indexed column from byte offset in content
@@ -57,6 +83,152 @@ fn extract_marker_prefix(content: &str, marker_span: marq::SourceSpan) -> Option
     Some(prefix.to_string())
 }
 
+/// The text of a requirement marker, e.g. `r[auth.session replaces=auth.token]`.
+fn marker_text(content: &str, marker_span: marq::SourceSpan) -> &str {
+    let start = marker_span.offset;
+    start
+        .checked_add(marker_span.length)
+        .and_then(|end| content.get(start..end))
+        .unwrap_or_default()
+}
+
+/// Comma-separated values of a `name=` attribute of a requirement marker.
+fn marker_attribute<'a>(
+    content: &'a str,
+    marker_span: marq::SourceSpan,
+    name: &str,
+) -> Vec<&'a str> {
+    tracey_core::marker_attribute(marker_text(content, marker_span), name)
+}
+
+/// Parse the `replaces=` attribute of a requirement marker, e.g.
+/// `r[auth.session replaces=auth.token,auth.cookie]`.
+///
+/// r[impl markdown.syntax.replaces]
+pub(crate) fn extract_marker_replaces(
+    content: &str,
+    marker_span: marq::SourceSpan,
+) -> Vec<tracey_core::RuleId> {
+    tracey_core::marker_replaces(marker_text(content, marker_span))
+}
+
+/// Parse the `depends=` attribute of a requirement marker, e.g.
+/// `r[msg.frame depends=msg.header,t[transport.framing]]`. Targets in other
+/// specs are qualified with their prefix; the others get `own_prefix`.
+///
+/// r[impl markdown.syntax.depends]
+pub(crate) fn extract_marker_depends(
+    content: &str,
+    marker_span: marq::SourceSpan,
+    own_prefix: &str,
+) -> Vec<(String, tracey_core::RuleId)> {
+    marker_attribute(content, marker_span, "depends")
+        .into_iter()
+        .filter_map(|target| {
+            // The closing brackets of the last target were trimmed with the
+            // marker's own.
+            let (prefix, id) = match target.split_once('[') {
+                Some((prefix, id)) => (prefix, id.trim_end_matches(']')),
+                None => (own_prefix, target),
+            };
+            Some((prefix.to_string(), tracey_core::parse_rule_id(id)?))
+        })
+        .collect()
+}
+
+/// Parse the `owner=` attribute of a requirement marker, e.g.
+/// `r[pay.refund owner=@payments,@alice]`.
+///
+/// r[impl markdown.syntax.owner]
+pub(crate) fn extract_marker_owners(content: &str, marker_span: marq::SourceSpan) -> Vec<String> {
+    marker_attribute(content, marker_span, "owner")
+        .into_iter()
+        .filter(|owner| !owner.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Parse the `tags=` attribute of a requirement marker, e.g.
+/// `r[auth.login tags=security,compliance-gdpr]`.
+///
+/// r[impl markdown.syntax.tags]
+pub(crate) fn extract_marker_tags(content: &str, marker_span: marq::SourceSpan) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in marker_attribute(content, marker_span, "tags") {
+        if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Extract the rules of one markdown document, with their sections.
+/// `display_path` is recorded as the rules' source file.
+pub(crate) async fn extract_rules_from_markdown(
+    content: &str,
+    display_path: &str,
+) -> Result<Vec<ExtractedRule>> {
+    use marq::DocElement;
+    use std::collections::HashMap;
+
+    let doc = render(content, &RenderOptions::default())
+        .await
+        .map_err(|e| eyre::eyre!("{}", e))?;
+
+    // Build a mapping from rule ID to section info by processing elements in order
+    let mut rule_sections: HashMap<String, (Option<String>, Option<String>)> = HashMap::new();
+    let mut current_section: Option<(String, String)> = None; // (slug, title)
+
+    for element in &doc.elements {
+        match element {
+            DocElement::Heading(h) => {
+                current_section = Some((h.id.clone(), h.title.clone()));
+            }
+            DocElement::Req(r) => {
+                if let Some((slug, title)) = &current_section {
+                    rule_sections
+                        .insert(r.id.to_string(), (Some(slug.clone()), Some(title.clone())));
+                }
+            }
+            DocElement::Paragraph(_) => {}
+        }
+    }
+
+    // Add requirements with their source file, computed column, and section
+    let mut rules = Vec::with_capacity(doc.reqs.len());
+    for req in doc.reqs {
+        let column = Some(compute_column(content, req.span.offset));
+        let prefix = extract_marker_prefix(content, req.marker_span).ok_or_else(|| {
+            eyre::eyre!(
+                "Failed to determine requirement marker prefix in {} at line {}",
+                display_path,
+                req.line
+            )
+        })?;
+        let (section, section_title) = rule_sections
+            .remove(&req.id.to_string())
+            .unwrap_or((None, None));
+        let replaces = extract_marker_replaces(content, req.marker_span);
+        let depends = extract_marker_depends(content, req.marker_span, &prefix);
+        let owners = extract_marker_owners(content, req.marker_span);
+        let tags = extract_marker_tags(content, req.marker_span);
+        rules.push(ExtractedRule {
+            def: req,
+            source_file: display_path.to_string(),
+            prefix,
+            column,
+            section,
+            section_title,
+            replaces,
+            depends,
+            owners,
+            tags,
+        });
+    }
+
+    Ok(rules)
+}
+
 /// Load rules from markdown files matching a glob pattern.
 ///
 /// marq implements markdown rule extraction:
@@ -152,16 +324,16 @@ pub async fn load_rules_from_glob(
         let content = std::fs::read_to_string(path)
             .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
 
-        let doc = render(&content, &RenderOptions::default())
+        let extracted = extract_rules_from_markdown(&content, &display_path)
             .await
-            .map_err(|e| eyre::eyre!("Failed to process {}: {}", path.display(), e))?;
+            .wrap_err_with(|| format!("Failed to process {}", path.display()))?;
 
-        if !doc.reqs.is_empty() {
+        if !extracted.is_empty() {
             if !quiet {
                 eprintln!(
                     "   {} {} requirements from {}",
                     "Found".green(),
-                    doc.reqs.len(),
+                    extracted.len(),
                     display_path
                 );
             }
@@ -169,63 +341,17 @@ pub async fn load_rules_from_glob(
             // Check for duplicates
             // r[impl markdown.duplicates.same-file] - caught when marq returns duplicate reqs from single file
             // r[impl markdown.duplicates.cross-file] - caught via seen_ids persisting across files
-            for req in &doc.reqs {
-                let req_id = req.id.to_string();
+            for rule in extracted {
+                let req_id = rule.def.id.to_string();
                 if seen_ids.contains(&req_id) {
                     eyre::bail!(
                         "Duplicate requirement '{}' found in {}",
-                        req.id.red(),
+                        rule.def.id.red(),
                         display_path
                     );
                 }
                 seen_ids.insert(req_id);
-            }
-
-            // Build a mapping from rule ID to section info by processing elements in order
-            use marq::DocElement;
-            use std::collections::HashMap;
-            let mut rule_sections: HashMap<String, (Option<String>, Option<String>)> =
-                HashMap::new();
-            let mut current_section: Option<(String, String)> = None; // (slug, title)
-
-            for element in &doc.elements {
-                match element {
-                    DocElement::Heading(h) => {
-                        current_section = Some((h.id.clone(), h.title.clone()));
-                    }
-                    DocElement::Req(r) => {
-                        if let Some((slug, title)) = &current_section {
-                            rule_sections.insert(
-                                r.id.to_string(),
-                                (Some(slug.clone()), Some(title.clone())),
-                            );
-                        }
-                    }
-                    DocElement::Paragraph(_) => {}
-                }
-            }
-
-            // Add requirements with their source file, computed column, and section
-            for req in doc.reqs {
-                let column = Some(compute_column(&content, req.span.offset));
-                let prefix = extract_marker_prefix(&content, req.marker_span).ok_or_else(|| {
-                    eyre::eyre!(
-                        "Failed to determine requirement marker prefix in {} at line {}",
-                        display_path,
-                        req.line
-                    )
-                })?;
-                let (section, section_title) = rule_sections
-                    .remove(&req.id.to_string())
-                    .unwrap_or((None, None));
-                rules.push(ExtractedRule {
-                    def: req,
-                    source_file: display_path.clone(),
-                    prefix,
-                    column,
-                    section,
-                    section_title,
-                });
+                rules.push(rule);
             }
         }
     }
@@ -282,7 +408,22 @@ pub fn find_project_root() -> Result<PathBuf> {
     }
 }
 
+pub use config::DEFAULT_CONFIG_PATH;
+
 pub fn load_config(path: &PathBuf) -> Result<Config> {
+    Ok(read_config(path)?.0)
+}
+
+/// Load the config for a build, along with the file it was read from so
+/// diagnostics about the config point into the right file. Implementations
+/// of `impls_from` are discovered here, once.
+pub fn load_config_file(project_root: &Path, path: &PathBuf) -> Result<(Config, data::ConfigFile)> {
+    let (config, content) = read_config(path)?;
+    let config = discover::expand(&config, project_root)?;
+    Ok((config, data::ConfigFile::new(project_root, path, content)))
+}
+
+fn read_config(path: &PathBuf) -> Result<(Config, String)> {
     if !path.exists() {
         eyre::bail!(
             "Config file not found at {}\n\n\
@@ -306,10 +447,10 @@ pub fn load_config(path: &PathBuf) -> Result<Config> {
     let content = std::fs::read_to_string(path)
         .wrap_err_with(|| format!("Failed to read config file: {}", path.display()))?;
 
-    let config: Config = facet_styx::from_str(&content)
+    let config = config::parse(&content)
         .wrap_err_with(|| format!("Failed to parse config file: {}", path.display()))?;
 
-    Ok(config)
+    Ok((config, content))
 }
 
 /// r[impl config.optional]
@@ -321,7 +462,7 @@ pub fn load_config_or_default(path: &PathBuf) -> Config {
     }
 
     match std::fs::read_to_string(path) {
-        Ok(content) => facet_styx::from_str(&content).unwrap_or_default(),
+        Ok(content) => config::parse(&content).unwrap_or_default(),
         Err(_) => Config::default(),
     }
 }
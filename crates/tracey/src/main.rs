@@ -126,6 +126,31 @@ enum Command {
         #[facet(args::positional, default)]
         root: Option<PathBuf>,
 
+        /// Output format: text, json, markdown, html or sarif (default: text)
+        #[facet(args::named, default)]
+        format: Option<String>,
+
+        /// Only consider rules of this RFC 2119 level: must, should or may
+        #[facet(args::named, default)]
+        level: Option<String>,
+
+        /// Only consider rules with this status, e.g. stable or draft
+        #[facet(args::named, default)]
+        status: Option<String>,
+
+        /// Only consider rules owned by this owner, e.g. @payments
+        #[facet(args::named, default)]
+        owner: Option<String>,
+
+        /// Only consider rules with this tag, e.g. security
+        #[facet(args::named, default)]
+        tag: Option<String>,
+
+        /// Test results (JUnit XML, libtest JSON or `go test -json` output, or a
+        /// directory of them) deciding whether verify references pass
+        #[facet(args::named, default)]
+        results: Option<PathBuf>,
+
         /// Query command to run
         #[facet(args::subcommand)]
         query: QueryCommand,
@@ -153,6 +178,183 @@ enum Command {
         #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
         config: PathBuf,
     },
+
+    /// Export coverage data in formats consumed by other tools (no daemon required)
+    Export {
+        /// Project root directory (default: current directory)
+        #[facet(args::positional, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// Write to this file instead of stdout
+        #[facet(args::named, args::short = 'o', default)]
+        output: Option<PathBuf>,
+
+        /// Export format to produce
+        #[facet(args::subcommand)]
+        export: ExportCommand,
+    },
+
+    /// Show coverage changes between two git revisions (no daemon required)
+    Diff {
+        /// Project root directory (default: current directory)
+        #[facet(args::named, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// Output format: text or markdown (default: text)
+        #[facet(args::named, default)]
+        format: Option<String>,
+
+        /// Revision range: `base..head`, `base...head` (from the merge base) or `base`
+        #[facet(args::positional)]
+        range: String,
+    },
+
+    /// List the requirements a change touches and the tests to run (no daemon required)
+    Impact {
+        /// Revision range: `base..head`, `base...head` (from the merge base) or
+        /// `base`. Uncommitted changes are analyzed if omitted.
+        #[facet(args::positional, default)]
+        revisions: Option<String>,
+
+        /// Project root directory (default: current directory)
+        #[facet(args::named, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// Only analyze staged changes
+        #[facet(args::named, default)]
+        staged: bool,
+
+        /// Output format: text, markdown or json (default: text)
+        #[facet(args::named, default)]
+        format: Option<String>,
+    },
+
+    /// Export the dashboard as a static site, browsable without a server
+    ExportSite {
+        /// Directory to write the site to
+        #[facet(args::positional)]
+        dir: PathBuf,
+
+        /// Project root directory (default: current directory)
+        #[facet(args::named, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// URL the site is served from, e.g. `/docs/coverage/` (default: /)
+        #[facet(args::named, default)]
+        base_url: Option<String>,
+    },
+
+    /// Check coverage thresholds without a daemon and exit non-zero on failure.
+    /// Designed for CI pipelines.
+    Check {
+        /// Project root directory (default: current directory)
+        #[facet(args::positional, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// Minimum implementation coverage percentage (0-100)
+        #[facet(args::named, default)]
+        min_impl: Option<f64>,
+
+        /// Minimum verification coverage percentage (0-100)
+        #[facet(args::named, default)]
+        min_verify: Option<f64>,
+
+        /// Fail if any rule has stale references
+        #[facet(args::named, default)]
+        fail_on_stale: bool,
+
+        /// Fail if validation reports any errors
+        #[facet(args::named, default)]
+        fail_on_validation_errors: bool,
+    },
+
+    /// Record coverage history for `tracey query trend` (no daemon required)
+    History {
+        /// History action to perform
+        #[facet(args::subcommand)]
+        action: HistoryAction,
+    },
+
+    /// Manage specs read from another git repository (no daemon required)
+    Spec {
+        /// Spec action to perform
+        #[facet(args::subcommand)]
+        action: SpecAction,
+    },
+
+    /// Write a coverage badge (SVG or shields.io endpoint JSON, no daemon required)
+    Badge {
+        /// Project root directory (default: current directory)
+        #[facet(args::positional, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// Spec name. Optional if only one spec matches.
+        #[facet(args::named, default)]
+        spec: Option<String>,
+
+        /// Implementation name. Optional if only one implementation matches.
+        #[facet(args::named, rename = "impl", default)]
+        impl_name: Option<String>,
+
+        /// Coverage to show: impl (default), verify or reverse
+        #[facet(args::named, default)]
+        metric: Option<String>,
+
+        /// Badge format: svg (default) or json (shields.io endpoint)
+        #[facet(args::named, default)]
+        format: Option<String>,
+
+        /// Text on the left side of the badge (default: "<metric> coverage")
+        #[facet(args::named, default)]
+        label: Option<String>,
+
+        /// Write to this file instead of stdout
+        #[facet(args::named, args::short = 'o', default)]
+        output: Option<PathBuf>,
+    },
+
+    /// Create a config file from the repository layout
+    Init {
+        /// Project root directory (default: current directory)
+        #[facet(args::positional, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// Write the proposed config without asking
+        #[facet(args::named, args::short = 'y', default)]
+        yes: bool,
+
+        /// Overwrite an existing config file
+        #[facet(args::named, default)]
+        force: bool,
+    },
 }
 
 /// Skill subcommands
@@ -171,6 +373,37 @@ enum SkillAction {
     },
 }
 
+/// Export subcommands
+#[derive(Debug, facet::Facet)]
+#[repr(u8)]
+enum ExportCommand {
+    /// JUnit XML report with one test case per requirement
+    Junit {
+        /// Report untested requirements as failures instead of skipped
+        #[facet(args::named, default)]
+        fail_untested: bool,
+    },
+
+    /// Traceability matrix with one row per requirement
+    Matrix {
+        /// Field separator: csv (default) or tsv
+        #[facet(args::named, default)]
+        format: Option<String>,
+
+        /// Add a column with the rule text
+        #[facet(args::named, default)]
+        include_text: bool,
+
+        /// Add a column per implementation listing stale references
+        #[facet(args::named, default)]
+        include_stale: bool,
+
+        /// Add a column per implementation listing depends references
+        #[facet(args::named, default)]
+        include_depends: bool,
+    },
+}
+
 #[derive(Debug, facet::Facet)]
 #[repr(u8)]
 enum QueryCommand {
@@ -237,6 +470,61 @@ enum QueryCommand {
         #[facet(args::named, default)]
         spec_impl: Option<String>,
     },
+
+    /// Show recorded coverage over time (see `tracey history record`)
+    Trend {
+        /// Spec/impl to show (e.g., "my-spec/rust"). Shows all if omitted.
+        #[facet(args::named, default)]
+        spec_impl: Option<String>,
+
+        /// Only show the most recent N commits
+        #[facet(args::named, default)]
+        limit: Option<usize>,
+    },
+}
+
+/// History subcommands
+#[derive(Debug, facet::Facet)]
+#[repr(u8)]
+enum HistoryAction {
+    /// Record the coverage of a commit (default: HEAD), or of every commit in a `base..head` range
+    Record {
+        /// Revision or revision range to record
+        #[facet(args::positional, default)]
+        revisions: Option<String>,
+
+        /// Project root directory (default: current directory)
+        #[facet(args::named, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+
+        /// Record commits that already have an entry again
+        #[facet(args::named, default)]
+        force: bool,
+    },
+}
+
+/// Spec subcommands
+#[derive(Debug, facet::Facet)]
+#[repr(u8)]
+enum SpecAction {
+    /// Advance the pinned commits of git spec sources and report which rules changed
+    Update {
+        /// Spec to update (default: every spec read from git)
+        #[facet(args::positional, default)]
+        name: Option<String>,
+
+        /// Project root directory (default: current directory)
+        #[facet(args::named, default)]
+        root: Option<PathBuf>,
+
+        /// Path to config file
+        #[facet(args::named, args::short = 'c', default = ".config/tracey/config.styx")]
+        config: PathBuf,
+    },
 }
 
 // Embed the config schema for zero-execution discovery by styx tooling
@@ -378,11 +666,374 @@ async fn main() -> Result<()> {
             Ok(())
         }
 
+        // r[impl cli.export]
+        Command::Export {
+            root,
+            config,
+            output,
+            export,
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            let (cfg, config_file) = tracey::load_config_file(&project_root, &config_path)?;
+            let data = tracey::data::build_dashboard_data_from_file(
+                &project_root,
+                &cfg,
+                &config_file,
+                1,
+                true,
+            )
+            .await?;
+
+            let rendered = match export {
+                ExportCommand::Junit { fail_untested } => {
+                    let options = tracey::junit::JunitOptions { fail_untested };
+                    tracey::junit::render_junit(&data, &options)
+                }
+                // r[impl cli.export.matrix]
+                ExportCommand::Matrix {
+                    format,
+                    include_text,
+                    include_stale,
+                    include_depends,
+                } => {
+                    let options = tracey::matrix::MatrixOptions {
+                        format: match format {
+                            Some(f) => f.parse()?,
+                            None => tracey::matrix::MatrixFormat::default(),
+                        },
+                        include_text,
+                        include_stale,
+                        include_depends,
+                    };
+                    tracey::matrix::render_matrix(&data, &options)
+                }
+            };
+
+            match output {
+                Some(path) => std::fs::write(&path, rendered)
+                    .wrap_err_with(|| format!("failed to write {}", path.display()))?,
+                None => print!("{rendered}"),
+            }
+            Ok(())
+        }
+
+        // r[impl cli.export-site+2]
+        Command::ExportSite {
+            dir,
+            root,
+            config,
+            base_url,
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            let (cfg, config_file) = tracey::load_config_file(&project_root, &config_path)?;
+            let base_url = base_url.as_deref().unwrap_or("/");
+            let summary =
+                tracey::site::export_site(&project_root, &cfg, &config_file, &dir, base_url)
+                    .await?;
+            println!(
+                "Exported {} spec/impl pair(s) and {} file(s) to {}",
+                summary.impls,
+                summary.files,
+                dir.display()
+            );
+            Ok(())
+        }
+
+        // r[impl cli.check]
+        Command::Check {
+            root,
+            config,
+            min_impl,
+            min_verify,
+            fail_on_stale,
+            fail_on_validation_errors,
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            let (cfg, config_file) = tracey::load_config_file(&project_root, &config_path)?;
+            let options = tracey::check::CheckOptions {
+                min_impl,
+                min_verify,
+                fail_on_stale,
+                fail_on_validation_errors,
+            };
+            let report = tracey::check::check(&project_root, &cfg, &config_file, &options).await?;
+            print!("{}", report.format_text());
+            if !report.passed() {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+
+        // r[impl cli.diff]
+        Command::Diff {
+            root,
+            config,
+            format,
+            range,
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            let cfg = tracey::load_config(&config_path)?;
+            let format = match format {
+                Some(f) => f.parse::<tracey::output::OutputFormat>()?,
+                None => tracey::output::OutputFormat::default(),
+            };
+            let (base, head) = tracey::diff::resolve_range(&project_root, &range)?;
+            let diff = tracey::diff::diff(&project_root, &config_path, &cfg, &base, &head).await?;
+            match format {
+                tracey::output::OutputFormat::Text => print!("{}", diff.format_text()),
+                tracey::output::OutputFormat::Markdown => print!("{}", diff.format_markdown()),
+                _ => {
+                    return Err(eyre!(
+                        "`tracey diff` only supports --format text or markdown"
+                    ));
+                }
+            }
+            Ok(())
+        }
+
+        // r[impl cli.impact]
+        Command::Impact {
+            revisions,
+            root,
+            config,
+            staged,
+            format,
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            let cfg = tracey::load_config(&config_path)?;
+            let format = match format {
+                Some(f) => f.parse::<tracey::output::OutputFormat>()?,
+                None => tracey::output::OutputFormat::default(),
+            };
+            if staged && revisions.is_some() {
+                return Err(eyre!("--staged cannot be combined with a revision range"));
+            }
+
+            let source = match revisions {
+                Some(range) => tracey::impact::ChangeSource::Range(range),
+                None if staged => tracey::impact::ChangeSource::Staged,
+                None => tracey::impact::ChangeSource::WorkingTree,
+            };
+            // Changed lines refer to the new side of the diff, so a range is
+            // analyzed at its head revision and staged changes at the index.
+            let data = match tracey::impact::changed_revision(&project_root, &source)? {
+                Some(revision) => {
+                    tracey::diff::build_at_revision(&project_root, &config_path, &cfg, &revision)
+                        .await?
+                }
+                None => {
+                    let cfg = tracey::discover::expand(&cfg, &project_root)?;
+                    tracey::data::build_dashboard_data(&project_root, &cfg, 1, true).await?
+                }
+            };
+            let files = tracey::impact::changed_lines(&project_root, &source)?;
+            let impact = tracey::impact::analyze(&data, &files);
+            match format {
+                tracey::output::OutputFormat::Text | tracey::output::OutputFormat::Markdown => {
+                    print!("{}", tracey::impact::format_impact(&impact))
+                }
+                tracey::output::OutputFormat::Json => {
+                    println!("{}", tracey::output::to_json(&impact))
+                }
+                _ => {
+                    return Err(eyre!(
+                        "`tracey impact` only supports --format text, markdown or json"
+                    ));
+                }
+            }
+            Ok(())
+        }
+
+        Command::History {
+            action:
+                HistoryAction::Record {
+                    revisions,
+                    root,
+                    config,
+                    force,
+                },
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            let cfg = tracey::load_config(&config_path)?;
+            let revisions = revisions.unwrap_or_else(|| "HEAD".to_string());
+            let commits = tracey::history::resolve_commits(&project_root, &revisions)?;
+            for commit in commits {
+                let short = &commit[..commit.len().min(10)];
+                if !force && tracey::history::is_recorded(&project_root, &commit) {
+                    println!("{} {short} (already recorded)", "Skipped".dimmed());
+                    continue;
+                }
+                let entry =
+                    tracey::history::record(&project_root, &config_path, &cfg, &commit).await?;
+                println!("{} {short} {}", "Recorded".green(), entry.subject);
+            }
+            Ok(())
+        }
+
+        // r[impl cli.spec.update]
+        Command::Spec {
+            action: SpecAction::Update { name, root, config },
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let cfg = tracey::load_config(&project_root.join(&config))?;
+            let updates = tracey::spec_source::update(&project_root, &cfg, name.as_deref()).await?;
+            print!("{}", tracey::spec_source::format_updates(&updates));
+            Ok(())
+        }
+
+        // r[impl cli.badge]
+        Command::Badge {
+            root,
+            config,
+            spec,
+            impl_name,
+            metric,
+            format,
+            label,
+            output,
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            let (cfg, config_file) = tracey::load_config_file(&project_root, &config_path)?;
+            let metric = match metric {
+                Some(m) => m.parse()?,
+                None => tracey::badge::BadgeMetric::default(),
+            };
+            let format = match format {
+                Some(f) => f.parse()?,
+                None => tracey::badge::BadgeFormat::default(),
+            };
+            let data = tracey::data::build_dashboard_data_from_file(
+                &project_root,
+                &cfg,
+                &config_file,
+                1,
+                true,
+            )
+            .await?;
+            let mut badge =
+                tracey::badge::badge_for(&data, spec.as_deref(), impl_name.as_deref(), metric)?;
+            if let Some(label) = label {
+                badge.label = label;
+            }
+
+            let rendered = badge.render(format);
+            match output {
+                Some(path) => std::fs::write(&path, rendered)
+                    .wrap_err_with(|| format!("failed to write {}", path.display()))?,
+                None => print!("{rendered}"),
+            }
+            Ok(())
+        }
+
+        // r[impl cli.init]
+        Command::Init {
+            root,
+            config,
+            yes,
+            force,
+        } => {
+            let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let config_path = project_root.join(&config);
+            if config_path.exists() && !force {
+                return Err(eyre!(
+                    "{} already exists (use --force to overwrite it)",
+                    config_path.display()
+                ));
+            }
+
+            let proposal = tracey::init::propose(&project_root).await?;
+            if proposal.specs.is_empty() {
+                return Err(eyre!(
+                    "No markdown files with requirement markers (like `r[auth.login]`) \
+                     found under {}",
+                    project_root.display()
+                ));
+            }
+            let text = proposal.render();
+            tracey::init::validate(&text)?;
+
+            if !yes {
+                use std::io::{BufRead, IsTerminal, Write};
+                if !std::io::stdin().is_terminal() {
+                    return Err(eyre!(
+                        "stdin is not a terminal; pass --yes to write the config"
+                    ));
+                }
+                println!("{text}");
+                print!("Write this config to {}? [Y/n] ", config_path.display());
+                std::io::stdout().flush()?;
+                let mut answer = String::new();
+                std::io::stdin().lock().read_line(&mut answer)?;
+                if !matches!(answer.trim(), "" | "y" | "Y" | "yes") {
+                    println!("Aborted.");
+                    return Ok(());
+                }
+            }
+
+            if let Some(parent) = config_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .wrap_err_with(|| format!("Failed to create {}", parent.display()))?;
+            }
+            std::fs::write(&config_path, &text)
+                .wrap_err_with(|| format!("Failed to write {}", config_path.display()))?;
+            println!(
+                "{} {} ({} spec(s))",
+                "Wrote".green(),
+                config_path.display(),
+                proposal.specs.len()
+            );
+            Ok(())
+        }
+
         // r[impl daemon.cli.query]
-        Command::Query { root, query } => {
+        Command::Query {
+            root,
+            format,
+            level,
+            status,
+            owner,
+            tag,
+            results,
+            query,
+        } => {
             let project_root = root.unwrap_or_else(|| find_project_root().unwrap_or_default());
+            let format = match format {
+                Some(f) => f.parse::<tracey::output::OutputFormat>()?,
+                None => tracey::output::OutputFormat::default(),
+            };
+            if format == tracey::output::OutputFormat::Sarif
+                && !matches!(query, QueryCommand::Validate { .. })
+            {
+                return Err(eyre!(
+                    "--format sarif is only supported by `tracey query validate`"
+                ));
+            }
+            if let Some(level) = &level
+                && !tracey::server::LEVELS.contains(&level.to_lowercase().as_str())
+            {
+                return Err(eyre!(
+                    "unknown level '{level}' (expected must, should or may)"
+                ));
+            }
+            // r[impl query.results]
+            let results = match results {
+                Some(path) => tracey::test_results::load(&path)?,
+                None => Vec::new(),
+            };
             let query_client =
-                bridge::query::QueryClient::new(project_root, bridge::query::Caller::Cli);
+                bridge::query::QueryClient::new(project_root, bridge::query::Caller::Cli)
+                    .with_format(format)
+                    .with_filter(level, status)
+                    .with_owner(owner)
+                    .with_tag(tag)
+                    .with_results(results);
             init_tracing(TracingConfig {
                 log_file: None,
                 enable_console: true,
@@ -414,6 +1065,9 @@ async fn main() -> Result<()> {
                 }
                 QueryCommand::Rule { rule_ids } => query_client.rules(&rule_ids).await,
                 QueryCommand::Config => query_client.config().await,
+                QueryCommand::Trend { spec_impl, limit } => {
+                    query_client.trend(spec_impl.as_deref(), limit).await
+                }
                 QueryCommand::Validate { spec_impl } => {
                     query_client.validate(spec_impl.as_deref()).await
                 }
@@ -0,0 +1,161 @@
+//! Traceability matrix export (CSV/TSV).
+//!
+//! One row per requirement of every spec, with the requirement's metadata
+//! followed by an `impl` and a `verify` column for every implementation,
+//! holding `file:line` lists. Implementations are matched by name across
+//! specs, so a spec without a given implementation leaves its cells empty.
+
+use std::collections::BTreeMap;
+
+use tracey_api::{ApiCodeRef, ApiRule};
+
+use crate::data::DashboardData;
+
+/// Field separator of the exported matrix.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MatrixFormat {
+    #[default]
+    Csv,
+    Tsv,
+}
+
+impl std::str::FromStr for MatrixFormat {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            _ => Err(eyre::eyre!(
+                "unknown matrix format '{s}' (expected csv or tsv)"
+            )),
+        }
+    }
+}
+
+/// Options for the matrix exporter.
+#[derive(Debug, Clone, Default)]
+pub struct MatrixOptions {
+    pub format: MatrixFormat,
+    /// Add a `text` column with the rule's markdown source.
+    pub include_text: bool,
+    /// Add a `stale` column per implementation listing stale references.
+    pub include_stale: bool,
+    /// Add a `depends` column per implementation.
+    pub include_depends: bool,
+}
+
+/// Escape one field. CSV follows RFC 4180; TSV cannot quote, so tabs and
+/// line breaks are replaced by spaces.
+fn field(value: &str, format: MatrixFormat) -> String {
+    match format {
+        MatrixFormat::Csv => {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        }
+        MatrixFormat::Tsv => value.replace("\r\n", " ").replace(['\t', '\n', '\r'], " "),
+    }
+}
+
+fn refs(refs: &[ApiCodeRef]) -> String {
+    refs.iter()
+        .map(|r| format!("{}:{}", r.file, r.line))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn stale_refs(rule: &ApiRule) -> String {
+    rule.stale_refs
+        .iter()
+        .map(|r| format!("{}:{} ({})", r.file, r.line, r.reference_id))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Render the traceability matrix for every spec in the dashboard data.
+pub fn render_matrix(data: &DashboardData, options: &MatrixOptions) -> String {
+    // Implementation columns, in order of first appearance.
+    let mut impl_names: Vec<&str> = Vec::new();
+    for (_, impl_name) in data.forward_by_impl.keys() {
+        if !impl_names.contains(&impl_name.as_str()) {
+            impl_names.push(impl_name);
+        }
+    }
+
+    // Rules of each spec, in spec order, with their data per implementation.
+    let mut specs: BTreeMap<&str, Vec<(&ApiRule, BTreeMap<&str, &ApiRule>)>> = BTreeMap::new();
+    for ((spec, impl_name), forward) in &data.forward_by_impl {
+        let rows = specs.entry(spec.as_str()).or_default();
+        for rule in &forward.rules {
+            match rows.iter_mut().find(|(r, _)| r.id == rule.id) {
+                Some((_, by_impl)) => {
+                    by_impl.insert(impl_name.as_str(), rule);
+                }
+                None => rows.push((rule, BTreeMap::from([(impl_name.as_str(), rule)]))),
+            }
+        }
+    }
+
+    let sep = match options.format {
+        MatrixFormat::Csv => ",",
+        MatrixFormat::Tsv => "\t",
+    };
+
+    let mut header: Vec<String> = ["spec", "id", "version", "section", "level", "status"]
+        .map(String::from)
+        .to_vec();
+    if options.include_text {
+        header.push("text".to_string());
+    }
+    for impl_name in &impl_names {
+        header.push(format!("{impl_name} impl"));
+        header.push(format!("{impl_name} verify"));
+        if options.include_depends {
+            header.push(format!("{impl_name} depends"));
+        }
+        if options.include_stale {
+            header.push(format!("{impl_name} stale"));
+        }
+    }
+
+    let mut out = String::new();
+    let mut push_row = |cells: Vec<String>| {
+        let cells: Vec<String> = cells.iter().map(|c| field(c, options.format)).collect();
+        out.push_str(&cells.join(sep));
+        out.push('\n');
+    };
+    push_row(header);
+
+    for (spec, rows) in specs {
+        for (rule, by_impl) in rows {
+            let mut cells = vec![
+                spec.to_string(),
+                rule.id.base.clone(),
+                rule.id.version.to_string(),
+                rule.section_title.clone().unwrap_or_default(),
+                rule.level.clone().unwrap_or_default(),
+                rule.status.clone().unwrap_or_default(),
+            ];
+            if options.include_text {
+                cells.push(rule.raw.trim().to_string());
+            }
+            for impl_name in &impl_names {
+                let rule = by_impl.get(impl_name).copied();
+                cells.push(rule.map(|r| refs(&r.impl_refs)).unwrap_or_default());
+                cells.push(rule.map(|r| refs(&r.verify_refs)).unwrap_or_default());
+                if options.include_depends {
+                    cells.push(rule.map(|r| refs(&r.depends_refs)).unwrap_or_default());
+                }
+                if options.include_stale {
+                    cells.push(rule.map(stale_refs).unwrap_or_default());
+                }
+            }
+            push_row(cells);
+        }
+    }
+
+    out
+}
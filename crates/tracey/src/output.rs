@@ -1,27 +1,54 @@
-//! Output formatting for coverage reports
+//! Output formatting for coverage reports and query results
 
 use facet::Facet;
 use owo_colors::OwoColorize;
 use tracey_core::{CoverageReport, RefVerb};
 
 /// Output format
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum OutputFormat {
     #[default]
     Text,
     Json,
     Markdown,
     Html,
+    /// SARIF 2.1.0, only meaningful for validation results.
+    Sarif,
 }
 
-impl OutputFormat {
-    pub fn from_str(s: &str) -> Option<Self> {
+impl std::str::FromStr for OutputFormat {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "text" => Some(Self::Text),
-            "json" => Some(Self::Json),
-            "markdown" | "md" => Some(Self::Markdown),
-            "html" => Some(Self::Html),
-            _ => None,
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            "sarif" => Ok(Self::Sarif),
+            _ => Err(eyre::eyre!(
+                "unknown output format '{s}' (expected text, json, markdown, html or sarif)"
+            )),
+        }
+    }
+}
+
+/// How a coverage percentage is rated: good from 80%, warn from 50%.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageRating {
+    Good,
+    Warn,
+    Bad,
+}
+
+impl CoverageRating {
+    pub fn from_percent(percent: f64) -> Self {
+        if percent >= 80.0 {
+            Self::Good
+        } else if percent >= 50.0 {
+            Self::Warn
+        } else {
+            Self::Bad
         }
     }
 }
@@ -30,12 +57,56 @@ impl OutputFormat {
 pub fn render_report(report: &CoverageReport, format: OutputFormat, verbose: bool) -> String {
     match format {
         OutputFormat::Text => render_text(report, verbose),
-        OutputFormat::Json => render_json(report),
+        // SARIF describes validation findings, not coverage; use plain JSON.
+        OutputFormat::Json | OutputFormat::Sarif => render_json(report),
         OutputFormat::Markdown => render_markdown(report, verbose),
         OutputFormat::Html => render_html(report, verbose),
     }
 }
 
+/// Serialize a structured response as pretty-printed JSON.
+pub fn to_json<'a, T: Facet<'a>>(value: &T) -> String {
+    facet_json::to_string_pretty(value).expect("JSON serialization failed")
+}
+
+#[derive(Facet)]
+struct JsonError {
+    error: String,
+}
+
+/// Serialize an error message as `{"error": "..."}`.
+pub fn json_error(message: impl std::fmt::Display) -> String {
+    to_json(&JsonError {
+        error: message.to_string(),
+    })
+}
+
+/// Wrap an HTML fragment in a standalone document.
+pub fn html_document(title: &str, body: &str) -> String {
+    let mut output = html_head(title);
+    output.push_str(body);
+    output.push_str("\n</body>\n</html>\n");
+    output
+}
+
+/// Render markdown query output as a standalone HTML document.
+///
+/// Falls back to a preformatted block if marq fails to render the markdown.
+pub async fn markdown_to_html(title: &str, markdown: &str) -> String {
+    let body = match marq::render(markdown, &marq::RenderOptions::default()).await {
+        Ok(doc) => doc.html,
+        Err(_) => format!("<pre>{}</pre>", html_escape(markdown)),
+    };
+    html_document(title, &body)
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 fn render_text(report: &CoverageReport, verbose: bool) -> String {
     let mut output = String::new();
 
@@ -50,12 +121,10 @@ fn render_text(report: &CoverageReport, verbose: bool) -> String {
     // Coverage summary
     let percent = report.coverage_percent();
     let percent_str = format!("{:.1}%", percent);
-    let color_percent = if percent >= 80.0 {
-        percent_str.green().to_string()
-    } else if percent >= 50.0 {
-        percent_str.yellow().to_string()
-    } else {
-        percent_str.red().to_string()
+    let color_percent = match CoverageRating::from_percent(percent) {
+        CoverageRating::Good => percent_str.green().to_string(),
+        CoverageRating::Warn => percent_str.yellow().to_string(),
+        CoverageRating::Bad => percent_str.red().to_string(),
     };
 
     output.push_str(&format!(
@@ -65,14 +134,22 @@ fn render_text(report: &CoverageReport, verbose: bool) -> String {
         report.total_rules
     ));
 
-    // Show verb breakdown
-    let verb_order = [
+    // Show verb breakdown, custom verbs last
+    let mut custom_verbs: Vec<RefVerb> = report
+        .references_by_verb
+        .keys()
+        .filter(|verb| matches!(verb, RefVerb::Custom(_)))
+        .cloned()
+        .collect();
+    custom_verbs.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    let mut verb_order = vec![
         RefVerb::Define,
         RefVerb::Impl,
         RefVerb::Verify,
         RefVerb::Depends,
         RefVerb::Related,
     ];
+    verb_order.extend(custom_verbs);
     let mut verb_counts: Vec<(&str, usize)> = Vec::new();
     for verb in &verb_order {
         if let Some(by_rule) = report.references_by_verb.get(verb) {
@@ -108,7 +185,7 @@ fn render_text(report: &CoverageReport, verbose: bool) -> String {
                 r.file.display(),
                 r.line,
                 r.verb.as_str().dimmed(),
-                r.rule_id.yellow()
+                r.req_id.yellow()
             ));
         }
         output.push('\n');
@@ -146,6 +223,7 @@ fn render_text(report: &CoverageReport, verbose: bool) -> String {
                     RefVerb::Verify => "✓",
                     RefVerb::Depends => "→",
                     RefVerb::Related => "~",
+                    RefVerb::Custom(_) => "·",
                 };
                 let verb_color = match verb {
                     RefVerb::Define => verb.as_str().blue().to_string(),
@@ -153,6 +231,7 @@ fn render_text(report: &CoverageReport, verbose: bool) -> String {
                     RefVerb::Verify => verb.as_str().cyan().to_string(),
                     RefVerb::Depends => verb.as_str().magenta().to_string(),
                     RefVerb::Related => verb.as_str().dimmed().to_string(),
+                    RefVerb::Custom(_) => verb.as_str().yellow().to_string(),
                 };
 
                 output.push_str(&format!(
@@ -266,7 +345,7 @@ fn render_markdown(report: &CoverageReport, verbose: bool) -> String {
                 r.file.display(),
                 r.line,
                 r.verb.as_str(),
-                r.rule_id
+                r.req_id
             ));
         }
         output.push('\n');
@@ -300,32 +379,15 @@ fn render_markdown(report: &CoverageReport, verbose: bool) -> String {
 }
 
 fn render_html(report: &CoverageReport, verbose: bool) -> String {
-    let mut output = String::new();
-
-    output.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
-    output.push_str("<meta charset=\"utf-8\">\n");
-    output.push_str(&format!(
-        "<title>{} Coverage Report</title>\n",
-        report.spec_name
-    ));
-    output.push_str("<style>\n");
-    output.push_str("body { font-family: system-ui, sans-serif; max-width: 800px; margin: 2rem auto; padding: 0 1rem; }\n");
-    output.push_str(".good { color: green; }\n");
-    output.push_str(".warn { color: orange; }\n");
-    output.push_str(".bad { color: red; }\n");
-    output.push_str("code { background: #f0f0f0; padding: 0.2em 0.4em; border-radius: 3px; }\n");
-    output.push_str("</style>\n");
-    output.push_str("</head>\n<body>\n");
+    let mut output = html_head(&format!("{} Coverage Report", report.spec_name));
 
     output.push_str(&format!("<h1>{} Coverage Report</h1>\n", report.spec_name));
 
     let percent = report.coverage_percent();
-    let class = if percent >= 80.0 {
-        "good"
-    } else if percent >= 50.0 {
-        "warn"
-    } else {
-        "bad"
+    let class = match CoverageRating::from_percent(percent) {
+        CoverageRating::Good => "good",
+        CoverageRating::Warn => "warn",
+        CoverageRating::Bad => "bad",
     };
     output.push_str(&format!(
         "<p><strong>Coverage:</strong> <span class=\"{}\">{:.1}%</span> ({}/{} rules)</p>\n",
@@ -344,7 +406,7 @@ fn render_html(report: &CoverageReport, verbose: bool) -> String {
                 r.file.display(),
                 r.line,
                 r.verb.as_str(),
-                r.rule_id
+                r.req_id
             ));
         }
         output.push_str("</ul>\n");
@@ -380,3 +442,22 @@ fn render_html(report: &CoverageReport, verbose: bool) -> String {
     output.push_str("</body>\n</html>\n");
     output
 }
+
+/// Opening of a standalone HTML document, up to and including `<body>`.
+fn html_head(title: &str) -> String {
+    let mut output = String::new();
+
+    output.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
+    output.push_str("<meta charset=\"utf-8\">\n");
+    output.push_str(&format!("<title>{}</title>\n", title));
+    output.push_str("<style>\n");
+    output.push_str("body { font-family: system-ui, sans-serif; max-width: 800px; margin: 2rem auto; padding: 0 1rem; }\n");
+    output.push_str(".good { color: green; }\n");
+    output.push_str(".warn { color: orange; }\n");
+    output.push_str(".bad { color: red; }\n");
+    output.push_str("code { background: #f0f0f0; padding: 0.2em 0.4em; border-radius: 3px; }\n");
+    output.push_str("</style>\n");
+    output.push_str("</head>\n<body>\n");
+
+    output
+}
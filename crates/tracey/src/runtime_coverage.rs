@@ -0,0 +1,200 @@
+//! Runtime coverage: does the test suite ever run the code implementing a
+//! rule?
+//!
+//! Coverage files are lcov tracefiles (`cargo llvm-cov --lcov`, grcov,
+//! c8, coverage.py's `lcov` report, ...) or `llvm-cov export` JSON
+//! (`cargo llvm-cov --json`). Line hit counts are attached to every code unit
+//! whose lines they cover, and every `impl` reference gets the hit count of
+//! the code unit it belongs to.
+
+use eyre::{Result, WrapErr, bail};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use tracey_core::code_units::CodeUnit;
+
+use crate::data::ApiSpecForward;
+use crate::test_results::{enclosing_unit, same_file};
+
+/// Hit counts by line (1-indexed), by file as named in the coverage data.
+pub type LineHits = BTreeMap<String, BTreeMap<usize, u64>>;
+
+/// r[impl runtime-coverage.formats]
+/// Parse a coverage file, detecting its format from the content.
+pub fn parse(content: &str) -> Result<LineHits> {
+    let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+    let hits = if trimmed.starts_with('{') {
+        parse_llvm_cov(trimmed)?
+    } else {
+        parse_lcov(content)
+    };
+    if hits.is_empty() && !trimmed.is_empty() {
+        bail!("no coverage data found (expected an lcov tracefile or `llvm-cov export` JSON)");
+    }
+    Ok(hits)
+}
+
+/// Read coverage from a file, or from every `.info`, `.lcov` and `.json`
+/// file of a directory. Hits of the same line in several files add up.
+pub fn load(path: &Path) -> Result<LineHits> {
+    let files = if path.is_dir() {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .wrap_err_with(|| format!("failed to read {}", path.display()))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|ext| ext == "info" || ext == "lcov" || ext == "json")
+            })
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut hits = LineHits::new();
+    for file in files {
+        let content = std::fs::read_to_string(&file)
+            .wrap_err_with(|| format!("failed to read {}", file.display()))?;
+        let parsed =
+            parse(&content).wrap_err_with(|| format!("failed to parse {}", file.display()))?;
+        merge(&mut hits, parsed);
+    }
+    Ok(hits)
+}
+
+/// Load the `runtime_coverage` of an implementation's config, relative to the
+/// project root. Files that don't exist yet are skipped, as are files that
+/// can't be parsed.
+pub fn load_configured(project_root: &Path, paths: &[String]) -> LineHits {
+    let mut hits = LineHits::new();
+    for path in paths {
+        let path = project_root.join(path);
+        if !path.exists() {
+            continue;
+        }
+        match load(&path) {
+            Ok(loaded) => merge(&mut hits, loaded),
+            Err(e) => tracing::warn!("skipping runtime coverage {}: {e:?}", path.display()),
+        }
+    }
+    hits
+}
+
+fn merge(into: &mut LineHits, from: LineHits) {
+    for (file, lines) in from {
+        let into = into.entry(file).or_default();
+        for (line, count) in lines {
+            let hits = into.entry(line).or_default();
+            *hits = hits.saturating_add(count);
+        }
+    }
+}
+
+/// `SF:` and `DA:` records of an lcov tracefile.
+fn parse_lcov(content: &str) -> LineHits {
+    let mut hits = LineHits::new();
+    let mut current: Option<&str> = None;
+    for line in content.lines().map(str::trim) {
+        if let Some(file) = line.strip_prefix("SF:") {
+            hits.entry(file.to_string()).or_default();
+            current = Some(file);
+        } else if line == "end_of_record" {
+            current = None;
+        } else if let Some(record) = line.strip_prefix("DA:")
+            && let Some(lines) = current.and_then(|file| hits.get_mut(file))
+        {
+            // DA:<line>,<hits>[,<checksum>]; hits may be written as a float.
+            let mut fields = record.split(',');
+            let line = fields.next().and_then(|l| l.parse::<usize>().ok());
+            let count = fields.next().and_then(|c| c.parse::<f64>().ok());
+            if let (Some(line), Some(count)) = (line, count) {
+                let hits = lines.entry(line).or_default();
+                *hits = hits.saturating_add(count as u64);
+            }
+        }
+    }
+    hits
+}
+
+/// Segments of an `llvm-cov export` JSON document. A segment is
+/// `[line, column, count, has_count, is_region_entry, is_gap_region]`; a
+/// line's hit count is the highest count of a segment starting on it.
+fn parse_llvm_cov(json: &str) -> Result<LineHits> {
+    use serde_json::Value;
+
+    let export: Value = serde_json::from_str(json).wrap_err("invalid JSON")?;
+    let Some(data) = export.get("data").and_then(Value::as_array) else {
+        bail!("not an `llvm-cov export` document (no `data` array)");
+    };
+
+    let mut hits = LineHits::new();
+    let files = data
+        .iter()
+        .filter_map(|export| export.get("files").and_then(Value::as_array))
+        .flatten();
+    for file in files {
+        let Some(filename) = file.get("filename").and_then(Value::as_str) else {
+            continue;
+        };
+        let lines = hits.entry(filename.to_string()).or_default();
+        let segments = file
+            .get("segments")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_array);
+        for segment in segments {
+            let line = segment.first().and_then(Value::as_u64);
+            let count = segment.get(2).and_then(Value::as_u64);
+            let has_count = segment.get(3).and_then(Value::as_bool);
+            if let (Some(line), Some(count), Some(true)) = (line, count, has_count) {
+                let hits = lines.entry(line as usize).or_default();
+                *hits = (*hits).max(count);
+            }
+        }
+    }
+    Ok(hits)
+}
+
+/// r[impl runtime-coverage.units]
+/// Set the hit counts of the code units of a spec/impl pair and of its
+/// `impl` references, and whether each rule with covered references was
+/// executed.
+pub fn apply(
+    forward: &mut ApiSpecForward,
+    code_units: &mut BTreeMap<PathBuf, Vec<CodeUnit>>,
+    project_root: &Path,
+    hits: &LineHits,
+) {
+    for (path, units) in code_units.iter_mut() {
+        let relative = path.strip_prefix(project_root).unwrap_or(path);
+        let relative = relative.to_string_lossy();
+        let Some(lines) = hits
+            .iter()
+            .find(|(file, _)| same_file(file, &relative))
+            .map(|(_, lines)| lines)
+        else {
+            continue;
+        };
+        for unit in units {
+            unit.hits = lines
+                .range(unit.start_line..=unit.end_line)
+                .map(|(_, count)| *count)
+                .max();
+        }
+    }
+
+    // r[impl runtime-coverage.executed]
+    for rule in &mut forward.rules {
+        for code_ref in &mut rule.impl_refs {
+            code_ref.hits = code_units
+                .iter()
+                .find(|(path, _)| path.ends_with(&code_ref.file))
+                .and_then(|(_, units)| enclosing_unit(units, code_ref.line, &rule.id.base))
+                .and_then(|unit| unit.hits);
+        }
+        let mut hits = rule.impl_refs.iter().filter_map(|r| r.hits).peekable();
+        rule.executed = hits.peek().is_some().then(|| hits.any(|count| count > 0));
+    }
+}
@@ -0,0 +1,338 @@
+//! SARIF 2.1.0 export of validation results.
+//!
+//! Code-scanning UIs (GitHub code scanning, IDE SARIF viewers) consume SARIF
+//! to show findings inline in code review. Each `ValidationErrorCode` becomes
+//! a SARIF rule with help text, and each `ValidationError` becomes a result
+//! located at the offending annotation.
+
+use facet::Facet;
+use tracey_api::{ValidationError, ValidationErrorCode, ValidationResult};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Every validation code, in the order they are listed as SARIF rules.
+const ALL_CODES: [ValidationErrorCode; 11] = [
+    ValidationErrorCode::CircularDependency,
+    ValidationErrorCode::InvalidNaming,
+    ValidationErrorCode::UnknownRequirement,
+    ValidationErrorCode::StaleRequirement,
+    ValidationErrorCode::DuplicateRequirement,
+    ValidationErrorCode::UnknownPrefix,
+    ValidationErrorCode::ImplInTestFile,
+    ValidationErrorCode::CoverageThreshold,
+    ValidationErrorCode::DeprecatedRequirement,
+    ValidationErrorCode::RemovedRequirement,
+    ValidationErrorCode::RenamedRequirement,
+];
+
+#[derive(Facet)]
+struct SarifLog {
+    #[facet(rename = "$schema")]
+    schema: String,
+    version: String,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Facet)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Facet)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct SarifDriver {
+    name: String,
+    version: String,
+    information_uri: String,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    name: String,
+    short_description: SarifMessage,
+    full_description: SarifMessage,
+    help: SarifMessage,
+    default_configuration: SarifConfiguration,
+}
+
+#[derive(Facet)]
+struct SarifConfiguration {
+    level: String,
+}
+
+#[derive(Facet)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: String,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    properties: SarifResultProperties,
+}
+
+/// Extra context attached to each result so findings from different
+/// spec/impl pairs can be told apart.
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct SarifResultProperties {
+    spec: String,
+    impl_name: String,
+    related_rules: Vec<String>,
+}
+
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[facet(skip_serializing_if = Option::is_none)]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Facet)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    #[facet(skip_serializing_if = Option::is_none)]
+    start_column: Option<usize>,
+    #[facet(skip_serializing_if = Option::is_none)]
+    end_column: Option<usize>,
+}
+
+/// Stable SARIF rule ID for a validation code.
+pub fn rule_id(code: ValidationErrorCode) -> &'static str {
+    match code {
+        ValidationErrorCode::CircularDependency => "circular-dependency",
+        ValidationErrorCode::InvalidNaming => "invalid-naming",
+        ValidationErrorCode::UnknownRequirement => "unknown-requirement",
+        ValidationErrorCode::StaleRequirement => "stale-requirement",
+        ValidationErrorCode::DuplicateRequirement => "duplicate-requirement",
+        ValidationErrorCode::UnknownPrefix => "unknown-prefix",
+        ValidationErrorCode::ImplInTestFile => "impl-in-test-file",
+        ValidationErrorCode::CoverageThreshold => "coverage-threshold",
+        ValidationErrorCode::DeprecatedRequirement => "deprecated-requirement",
+        ValidationErrorCode::RemovedRequirement => "removed-requirement",
+        ValidationErrorCode::RenamedRequirement => "renamed-requirement",
+    }
+}
+
+fn rule_name(code: ValidationErrorCode) -> &'static str {
+    match code {
+        ValidationErrorCode::CircularDependency => "CircularDependency",
+        ValidationErrorCode::InvalidNaming => "InvalidNaming",
+        ValidationErrorCode::UnknownRequirement => "UnknownRequirement",
+        ValidationErrorCode::StaleRequirement => "StaleRequirement",
+        ValidationErrorCode::DuplicateRequirement => "DuplicateRequirement",
+        ValidationErrorCode::UnknownPrefix => "UnknownPrefix",
+        ValidationErrorCode::ImplInTestFile => "ImplInTestFile",
+        ValidationErrorCode::CoverageThreshold => "CoverageThreshold",
+        ValidationErrorCode::DeprecatedRequirement => "DeprecatedRequirement",
+        ValidationErrorCode::RemovedRequirement => "RemovedRequirement",
+        ValidationErrorCode::RenamedRequirement => "RenamedRequirement",
+    }
+}
+
+fn short_description(code: ValidationErrorCode) -> &'static str {
+    match code {
+        ValidationErrorCode::CircularDependency => "Circular dependency between requirements",
+        ValidationErrorCode::InvalidNaming => "Requirement ID violates naming conventions",
+        ValidationErrorCode::UnknownRequirement => "Reference to an unknown requirement",
+        ValidationErrorCode::StaleRequirement => "Reference to an older requirement version",
+        ValidationErrorCode::DuplicateRequirement => "Requirement ID defined more than once",
+        ValidationErrorCode::UnknownPrefix => "Reference uses an unknown spec prefix",
+        ValidationErrorCode::ImplInTestFile => "Impl annotation in a test file",
+        ValidationErrorCode::CoverageThreshold => "Coverage below a configured threshold",
+        ValidationErrorCode::DeprecatedRequirement => "Reference to a deprecated requirement",
+        ValidationErrorCode::RemovedRequirement => "Reference to a removed requirement",
+        ValidationErrorCode::RenamedRequirement => "Reference to a renamed requirement",
+    }
+}
+
+fn help_text(code: ValidationErrorCode) -> &'static str {
+    match code {
+        ValidationErrorCode::CircularDependency => {
+            "Requirements form a cycle through `depends` references. Break the cycle by \
+             removing or redirecting one of the `depends` annotations."
+        }
+        ValidationErrorCode::InvalidNaming => {
+            "Requirement IDs must be dot-separated segments that start with a lowercase \
+             letter and contain only lowercase letters, digits and hyphens."
+        }
+        ValidationErrorCode::UnknownRequirement => {
+            "The annotation references a requirement that is not defined in any spec using \
+             this prefix. Fix the typo, or add the requirement to the spec."
+        }
+        ValidationErrorCode::StaleRequirement => {
+            "The spec text changed and the requirement version was bumped since this code \
+             was annotated. Review the code against the new requirement text, then update \
+             the annotation to the current version."
+        }
+        ValidationErrorCode::DuplicateRequirement => {
+            "The same requirement ID is defined more than once in the spec. Rename one of \
+             the definitions so every requirement has a unique ID."
+        }
+        ValidationErrorCode::UnknownPrefix => {
+            "The annotation uses a prefix that does not belong to any configured spec. Check \
+             the prefix or add the spec to `.config/tracey/config.styx`."
+        }
+        ValidationErrorCode::ImplInTestFile => {
+            "Test files may only contain `verify` annotations. Move the `impl` annotation to \
+             the implementation, or change it to `verify`."
+        }
+        ValidationErrorCode::CoverageThreshold => {
+            "Coverage for this spec/impl pair (or a rule ID prefix within it) is below a \
+             threshold set in the `thresholds` block of `.config/tracey/config.styx`. Add \
+             the missing annotations, or adjust the threshold."
+        }
+        ValidationErrorCode::DeprecatedRequirement => {
+            "The annotation references a requirement marked `status=deprecated`. It still \
+             resolves but no longer counts towards coverage. Migrate the code to the \
+             requirement that supersedes it, or drop the annotation."
+        }
+        ValidationErrorCode::RemovedRequirement => {
+            "The annotation references a requirement that was removed from the spec and is \
+             listed in its `removed` config. Switch to the replacement named in the message, \
+             or drop the annotation."
+        }
+        ValidationErrorCode::RenamedRequirement => {
+            "The annotation uses an ID that a requirement replaces with `replaces=`. It \
+             still counts towards the new requirement's coverage; update the annotation to \
+             the new ID."
+        }
+    }
+}
+
+/// SARIF level for a validation code. Stale, deprecated and renamed
+/// references are warnings: the code still points at a real requirement, it
+/// just needs review.
+fn level(code: ValidationErrorCode) -> &'static str {
+    match code {
+        ValidationErrorCode::StaleRequirement
+        | ValidationErrorCode::DeprecatedRequirement
+        | ValidationErrorCode::RenamedRequirement => "warning",
+        _ => "error",
+    }
+}
+
+fn sarif_result(result: &ValidationResult, error: &ValidationError) -> SarifResult {
+    let rule_index = ALL_CODES
+        .iter()
+        .position(|c| *c == error.code)
+        .unwrap_or_default();
+
+    let locations = match &error.file {
+        Some(file) => {
+            let region = error.line.map(|line| SarifRegion {
+                start_line: line,
+                start_column: error.column,
+                end_column: match (error.column, &error.reference_text) {
+                    (Some(col), Some(text)) => Some(col + text.chars().count()),
+                    _ => None,
+                },
+            });
+            vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation {
+                        uri: file.replace('\\', "/"),
+                    },
+                    region,
+                },
+            }]
+        }
+        None => Vec::new(),
+    };
+
+    SarifResult {
+        rule_id: rule_id(error.code).to_string(),
+        rule_index,
+        level: level(error.code).to_string(),
+        message: SarifMessage {
+            text: error.message.clone(),
+        },
+        locations,
+        properties: SarifResultProperties {
+            spec: result.spec.clone(),
+            impl_name: result.impl_name.clone(),
+            related_rules: error
+                .related_rules
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        },
+    }
+}
+
+/// Render validation results for one or more spec/impl pairs as a SARIF log.
+///
+/// File URIs are relative to the project root, which code-scanning tools
+/// resolve against the repository checkout.
+pub fn render_sarif(results: &[ValidationResult]) -> String {
+    let rules = ALL_CODES
+        .iter()
+        .map(|&code| SarifRule {
+            id: rule_id(code).to_string(),
+            name: rule_name(code).to_string(),
+            short_description: SarifMessage {
+                text: short_description(code).to_string(),
+            },
+            full_description: SarifMessage {
+                text: help_text(code).to_string(),
+            },
+            help: SarifMessage {
+                text: help_text(code).to_string(),
+            },
+            default_configuration: SarifConfiguration {
+                level: level(code).to_string(),
+            },
+        })
+        .collect();
+
+    let sarif_results = results
+        .iter()
+        .flat_map(|result| result.errors.iter().map(|e| sarif_result(result, e)))
+        .collect();
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA.to_string(),
+        version: SARIF_VERSION.to_string(),
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "tracey".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                    information_uri: "https://github.com/bearcove/tracey".to_string(),
+                    rules,
+                },
+            },
+            results: sarif_results,
+        }],
+    };
+
+    crate::output::to_json(&log)
+}
@@ -45,6 +45,8 @@ pub struct RuleEntry {
     pub id: String,
     /// Raw markdown source (without r[...] marker)
     pub raw: String,
+    /// Tags of the rule, searchable as `tag:<tag>`
+    pub tags: Vec<String>,
 }
 
 /// Search index abstraction
@@ -130,6 +132,14 @@ mod tantivy_impl {
             let content_field = schema_builder.add_text_field("content", text_options);
             // "rule_id" field: searchable rule ID with dot-separated parts (not stored)
             let rule_id_field = schema_builder.add_text_field("rule_id", rule_id_options);
+            // "tag" field: tags of rules, e.g. `tag:security` (not stored).
+            // Positions let `tag:compliance-gdpr` match as a phrase.
+            let tag_options = TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer("default")
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            );
+            let tag_field = schema_builder.add_text_field("tag", tag_options);
             let schema = schema_builder.build();
 
             // Create index in RAM (small enough for most projects)
@@ -176,14 +186,19 @@ mod tantivy_impl {
 
             // Index rules - use raw markdown directly (rule_id_field handles ID search)
             // r[impl dashboard.search.render-requirements]
+            // r[impl dashboard.search.tags]
             for rule in rules {
-                index_writer.add_document(doc!(
+                let mut document = doc!(
                     kind_field => "rule",
                     id_field => rule.id.clone(),
                     line_field => 0u64,
                     content_field => rule.raw.clone(),
                     rule_id_field => rule.id.clone(),
-                ))?;
+                );
+                for tag in &rule.tags {
+                    document.add_text(tag_field, tag);
+                }
+                index_writer.add_document(document)?;
             }
 
             index_writer.commit()?;
@@ -195,8 +210,9 @@ mod tantivy_impl {
 
             // Search both content and rule_id fields, with rule_id boosted higher
             let mut query_parser =
-                QueryParser::for_index(&index, vec![content_field, rule_id_field]);
+                QueryParser::for_index(&index, vec![content_field, rule_id_field, tag_field]);
             query_parser.set_field_boost(rule_id_field, 5.0); // Boost rule ID matches
+            query_parser.set_field_boost(tag_field, 2.0);
 
             Ok(Self {
                 index,
@@ -351,6 +367,7 @@ struct SimpleEntry {
     id: String,
     line: usize,
     content: String,
+    tags: Vec<String>,
 }
 
 /// Simple substring search fallback when tantivy is not available
@@ -392,6 +409,7 @@ impl SimpleIndex {
                         id: relative.clone(),
                         line: line_num,
                         content: content_with_context,
+                        tags: Vec::new(),
                     });
                 }
             }
@@ -404,6 +422,7 @@ impl SimpleIndex {
                 id: rule.id.clone(),
                 line: 0,
                 content: rule.raw.clone(),
+                tags: rule.tags.clone(),
             });
         }
 
@@ -414,14 +433,21 @@ impl SimpleIndex {
 impl SearchIndex for SimpleIndex {
     fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
         let query_lower = query.to_lowercase();
+        let tag_query = query.strip_prefix("tag:");
 
         let mut results: Vec<SearchResult> = self
             .entries
             .iter()
-            // Match against both content and id (for rule ID searches)
-            .filter(|e| {
-                e.content.to_lowercase().contains(&query_lower)
-                    || e.id.to_lowercase().contains(&query_lower)
+            // Match against content, id (for rule ID searches) and tags; a
+            // `tag:` query only matches tags
+            // r[impl dashboard.search.tags]
+            .filter(|e| match tag_query {
+                Some(tag) => e.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+                None => {
+                    e.content.to_lowercase().contains(&query_lower)
+                        || e.id.to_lowercase().contains(&query_lower)
+                        || e.tags.iter().any(|t| t.to_lowercase() == query_lower)
+                }
             })
             .take(limit)
             .map(|e| {
@@ -11,7 +11,9 @@
 use std::collections::BTreeMap;
 use tracey_core::RuleId;
 
-use crate::data::{ApiCodeRef, ApiFileEntry, ApiRule, DashboardData, ImplKey};
+use crate::data::{
+    ApiCodeRef, ApiFileEntry, ApiRule, ApiSpecForward, DashboardData, ImplKey, TestOutcome,
+};
 
 // ============================================================================
 // Delta Tracking
@@ -42,10 +44,32 @@ pub struct CoverageStats {
     pub fully_covered: usize, // both impl and verify
     pub impl_percent: f64,
     pub verify_percent: f64,
+    /// Rules left out of the numbers above by the spec's `exclude_statuses`.
+    pub excluded: usize,
+    /// Whether test results were ingested, so the counts below are known.
+    pub has_results: bool,
+    /// Rules whose verifying tests passed.
+    pub verify_passing: usize,
+    /// Rules with a failing verifying test.
+    pub verify_failing: usize,
+    /// Whether runtime coverage data covers any `impl` reference, so
+    /// `impl_executed` is known.
+    pub has_runtime_coverage: bool,
+    /// Rules whose implementing code ran at least once.
+    pub impl_executed: usize,
 }
 
 impl CoverageStats {
     pub fn from_rules(rules: &[ApiRule]) -> Self {
+        Self::from_rule_refs(rules.iter())
+    }
+
+    /// Coverage of `rules`. Rules excluded by the spec's `exclude_statuses`
+    /// are only counted in `excluded`.
+    pub fn from_rule_refs<'r>(rules: impl IntoIterator<Item = &'r ApiRule>) -> Self {
+        let (excluded, rules): (Vec<&ApiRule>, Vec<&ApiRule>) =
+            rules.into_iter().partition(|r| r.excluded);
+        let excluded = excluded.len();
         let total = rules.len();
         // A rule is stale if is_stale is set; stale rules are NOT counted as impl_covered.
         let stale_covered = rules.iter().filter(|r| r.is_stale).count();
@@ -58,9 +82,21 @@ impl CoverageStats {
             .iter()
             .filter(|r| !r.is_stale && !r.impl_refs.is_empty() && !r.verify_refs.is_empty())
             .count();
+        // r[impl results.verification]
+        let has_results = rules.iter().any(|r| r.verification.is_some());
+        let verification = |outcome| {
+            rules
+                .iter()
+                .filter(|r| r.verification == Some(outcome))
+                .count()
+        };
+        // r[impl runtime-coverage.executed]
+        let has_runtime_coverage = rules.iter().any(|r| r.executed.is_some());
+        let impl_executed = rules.iter().filter(|r| r.executed == Some(true)).count();
 
         Self {
             total_rules: total,
+            excluded,
             impl_covered,
             stale_covered,
             verify_covered,
@@ -75,6 +111,185 @@ impl CoverageStats {
             } else {
                 0.0
             },
+            has_results,
+            verify_passing: verification(TestOutcome::Passed),
+            verify_failing: verification(TestOutcome::Failed),
+            has_runtime_coverage,
+            impl_executed,
+        }
+    }
+}
+
+/// RFC 2119 requirement levels, strongest first.
+pub const LEVELS: [&str; 3] = ["must", "should", "may"];
+
+/// RFC 2119 level of a rule: its `level` attribute or the strongest keyword
+/// in its text. Keywords only count in uppercase and as whole words, so
+/// "DISMAY" or "not required" don't set a level.
+pub fn rule_level(rule: &ApiRule) -> Option<&'static str> {
+    if let Some(level) = &rule.level {
+        return LEVELS.into_iter().find(|l| level.eq_ignore_ascii_case(l));
+    }
+    let words: Vec<&str> = rule
+        .raw
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    words
+        .iter()
+        .enumerate()
+        .filter_map(|(i, word)| match *word {
+            "MUST" | "SHALL" => Some(0),
+            // "NOT REQUIRED" isn't a requirement at all
+            "REQUIRED" if i == 0 || words[i - 1] != "NOT" => Some(0),
+            "SHOULD" | "RECOMMENDED" => Some(1),
+            "MAY" | "OPTIONAL" => Some(2),
+            _ => None,
+        })
+        .min()
+        .map(|i| LEVELS[i])
+}
+
+/// Status of a rule. Rules without a `status` attribute are stable.
+pub fn rule_status(rule: &ApiRule) -> &str {
+    rule.status.as_deref().unwrap_or("stable")
+}
+
+/// Rules grouped by section title, with sections in the order they appear
+/// in the spec. Rules outside any section are under "Uncategorized".
+pub fn sections_in_spec_order(rules: &[ApiRule]) -> Vec<(String, Vec<&ApiRule>)> {
+    let mut sections: Vec<(String, Vec<&ApiRule>)> = Vec::new();
+    for rule in rules {
+        let title = rule
+            .section_title
+            .clone()
+            .unwrap_or_else(|| "Uncategorized".to_string());
+        match sections.iter_mut().find(|(t, _)| *t == title) {
+            Some((_, rules)) => rules.push(rule),
+            None => sections.push((title, vec![rule])),
+        }
+    }
+    sections
+}
+
+impl CoverageStats {
+    /// Coverage per RFC 2119 level, strongest first. Levels without rules are
+    /// omitted.
+    pub fn by_level(rules: &[&ApiRule]) -> Vec<(&'static str, CoverageStats)> {
+        LEVELS
+            .into_iter()
+            .filter_map(|level| {
+                let stats = Self::from_rule_refs(
+                    rules
+                        .iter()
+                        .copied()
+                        .filter(|r| rule_level(r) == Some(level)),
+                );
+                (stats.total_rules > 0).then_some((level, stats))
+            })
+            .collect()
+    }
+
+    /// Coverage per owner, sorted by owner, with unowned rules last under
+    /// `None`. A rule with several owners counts for each of them. Empty when
+    /// no rule has an owner.
+    pub fn by_owner(rules: &[&ApiRule]) -> Vec<(Option<String>, CoverageStats)> {
+        let mut groups: Vec<(Option<String>, CoverageStats)> =
+            Self::by_label(rules, |r| r.owners.as_slice())
+                .into_iter()
+                .map(|(owner, stats)| (Some(owner), stats))
+                .collect();
+        if groups.is_empty() {
+            return groups;
+        }
+        let unowned = Self::from_rule_refs(rules.iter().copied().filter(|r| r.owners.is_empty()));
+        if unowned.total_rules > 0 {
+            groups.push((None, unowned));
+        }
+        groups
+    }
+
+    /// Coverage per tag, sorted by tag. A rule with several tags counts for
+    /// each of them; untagged rules are left out.
+    pub fn by_tag(rules: &[&ApiRule]) -> Vec<(String, CoverageStats)> {
+        Self::by_label(rules, |r| r.tags.as_slice())
+    }
+
+    /// Coverage per distinct label of the rules, e.g. owner or tag, sorted.
+    fn by_label(
+        rules: &[&ApiRule],
+        labels: impl Fn(&ApiRule) -> &[String],
+    ) -> Vec<(String, CoverageStats)> {
+        let mut all: Vec<&str> = rules
+            .iter()
+            .flat_map(|r| labels(r).iter().map(String::as_str))
+            .collect();
+        all.sort_unstable();
+        all.dedup();
+        all.into_iter()
+            .map(|label| {
+                let stats = Self::from_rule_refs(
+                    rules
+                        .iter()
+                        .copied()
+                        .filter(|r| labels(r).iter().any(|l| l == label)),
+                );
+                (label.to_string(), stats)
+            })
+            .collect()
+    }
+}
+
+/// Narrows queries to the rules of one RFC 2119 level, status, owner and/or
+/// tag.
+#[derive(Debug, Clone, Default)]
+pub struct RuleFilter {
+    /// `must`, `should` or `may`
+    pub level: Option<String>,
+    /// e.g. `stable` or `draft`
+    pub status: Option<String>,
+    /// e.g. `@payments`
+    pub owner: Option<String>,
+    /// e.g. `security`
+    pub tag: Option<String>,
+}
+
+impl RuleFilter {
+    // r[impl query.filter]
+    // r[impl query.filter.owner]
+    // r[impl query.filter.tag]
+    pub fn matches(&self, rule: &ApiRule) -> bool {
+        self.level
+            .as_deref()
+            .is_none_or(|level| rule_level(rule).is_some_and(|l| l.eq_ignore_ascii_case(level)))
+            && self
+                .status
+                .as_deref()
+                .is_none_or(|status| rule_status(rule).eq_ignore_ascii_case(status))
+            && self
+                .owner
+                .as_deref()
+                .is_none_or(|owner| rule.owners.iter().any(|o| o.eq_ignore_ascii_case(owner)))
+            && self
+                .tag
+                .as_deref()
+                .is_none_or(|tag| rule.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+    }
+}
+
+/// Reverse coverage (code units referencing rules) for a spec/impl pair
+#[derive(Debug, Clone, Default)]
+pub struct ReverseStats {
+    pub total_units: usize,
+    pub covered_units: usize,
+}
+
+impl ReverseStats {
+    pub fn percent(&self) -> f64 {
+        if self.total_units > 0 {
+            (self.covered_units as f64 / self.total_units as f64) * 100.0
+        } else {
+            0.0
         }
     }
 }
@@ -86,15 +301,32 @@ pub struct ImplDelta {
     pub newly_covered: Vec<CoverageChange>,
     /// Rules that lost coverage (had refs, now have none)
     pub newly_uncovered: Vec<RuleId>,
+    /// Rules that became stale (references point to an older version)
+    pub newly_stale: Vec<RuleId>,
+    /// Rules whose base ID did not exist before
+    pub added_rules: Vec<RuleId>,
+    /// Rules whose base ID no longer exists
+    pub removed_rules: Vec<RuleId>,
+    /// Rules whose version was bumped: (old, new)
+    pub bumped_rules: Vec<(RuleId, RuleId)>,
     /// Previous stats
     pub prev_stats: CoverageStats,
     /// Current stats
     pub curr_stats: CoverageStats,
+    /// Previous reverse coverage
+    pub prev_reverse: ReverseStats,
+    /// Current reverse coverage
+    pub curr_reverse: ReverseStats,
 }
 
 impl ImplDelta {
     pub fn is_empty(&self) -> bool {
-        self.newly_covered.is_empty() && self.newly_uncovered.is_empty()
+        self.newly_covered.is_empty()
+            && self.newly_uncovered.is_empty()
+            && self.newly_stale.is_empty()
+            && self.added_rules.is_empty()
+            && self.removed_rules.is_empty()
+            && self.bumped_rules.is_empty()
     }
 
     pub fn coverage_change(&self) -> f64 {
@@ -115,22 +347,44 @@ impl Delta {
     }
 
     /// Compute delta between old and new data
+    ///
+    /// Rules are matched by base ID, so a version bump shows up in
+    /// `bumped_rules` rather than as a removed and an added rule.
     pub fn compute(old: &DashboardData, new: &DashboardData) -> Self {
         let mut by_impl = BTreeMap::new();
 
-        for (key, new_forward) in &new.forward_by_impl {
+        let keys: std::collections::BTreeSet<&ImplKey> = old
+            .forward_by_impl
+            .keys()
+            .chain(new.forward_by_impl.keys())
+            .collect();
+
+        for key in keys {
             let impl_key = format!("{}/{}", key.0, key.1);
 
             let old_forward = old.forward_by_impl.get(key);
-            let old_rules: BTreeMap<&RuleId, &ApiRule> = old_forward
-                .map(|f| f.rules.iter().map(|r| (&r.id, r)).collect())
+            let new_forward = new.forward_by_impl.get(key);
+            let old_rules: BTreeMap<&str, &ApiRule> = old_forward
+                .map(|f| f.rules.iter().map(|r| (r.id.base.as_str(), r)).collect())
                 .unwrap_or_default();
+            let new_rules: &[ApiRule] = new_forward.map(|f| f.rules.as_slice()).unwrap_or(&[]);
 
             let mut newly_covered = Vec::new();
             let mut newly_uncovered = Vec::new();
+            let mut newly_stale = Vec::new();
+            let mut added_rules = Vec::new();
+            let mut bumped_rules = Vec::new();
+
+            for new_rule in new_rules {
+                let old_rule = old_rules.get(new_rule.id.base.as_str());
 
-            for new_rule in &new_forward.rules {
-                let old_rule = old_rules.get(&new_rule.id);
+                match old_rule {
+                    None => added_rules.push(new_rule.id.clone()),
+                    Some(r) if r.id.version != new_rule.id.version => {
+                        bumped_rules.push((r.id.clone(), new_rule.id.clone()));
+                    }
+                    Some(_) => {}
+                }
 
                 let was_impl_covered = old_rule.is_some_and(|r| !r.impl_refs.is_empty());
                 let is_impl_covered = !new_rule.impl_refs.is_empty();
@@ -168,20 +422,49 @@ impl Delta {
                 if was_impl_covered && !is_impl_covered {
                     newly_uncovered.push(new_rule.id.clone());
                 }
+
+                // Check for newly stale
+                if new_rule.is_stale && !old_rule.is_some_and(|r| r.is_stale) {
+                    newly_stale.push(new_rule.id.clone());
+                }
             }
 
+            let new_bases: std::collections::HashSet<&str> =
+                new_rules.iter().map(|r| r.id.base.as_str()).collect();
+            let removed_rules = old_rules
+                .iter()
+                .filter(|(base, _)| !new_bases.contains(*base))
+                .map(|(_, r)| r.id.clone())
+                .collect();
+
             let prev_stats = old_forward
                 .map(|f| CoverageStats::from_rules(&f.rules))
                 .unwrap_or_default();
-            let curr_stats = CoverageStats::from_rules(&new_forward.rules);
+            let curr_stats = CoverageStats::from_rules(new_rules);
+
+            let reverse_stats = |data: &DashboardData| {
+                data.reverse_by_impl
+                    .get(key)
+                    .map(|r| ReverseStats {
+                        total_units: r.total_units,
+                        covered_units: r.covered_units,
+                    })
+                    .unwrap_or_default()
+            };
 
             by_impl.insert(
                 impl_key,
                 ImplDelta {
                     newly_covered,
                     newly_uncovered,
+                    newly_stale,
+                    added_rules,
+                    removed_rules,
+                    bumped_rules,
                     prev_stats,
                     curr_stats,
+                    prev_reverse: reverse_stats(old),
+                    curr_reverse: reverse_stats(new),
                 },
             );
         }
@@ -219,11 +502,30 @@ impl Delta {
 /// Provides query methods over DashboardData
 pub struct QueryEngine<'a> {
     data: &'a DashboardData,
+    filter: RuleFilter,
 }
 
 impl<'a> QueryEngine<'a> {
     pub fn new(data: &'a DashboardData) -> Self {
-        Self { data }
+        Self {
+            data,
+            filter: RuleFilter::default(),
+        }
+    }
+
+    /// Only consider rules matching `filter`, in stats as well as in lists.
+    pub fn with_filter(mut self, filter: RuleFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Rules of a spec/impl that match the filter.
+    fn rules(&self, forward: &'a ApiSpecForward) -> Vec<&'a ApiRule> {
+        forward
+            .rules
+            .iter()
+            .filter(|r| self.filter.matches(r))
+            .collect()
     }
 
     /// Get coverage stats for all spec/impl pairs
@@ -232,12 +534,50 @@ impl<'a> QueryEngine<'a> {
             .forward_by_impl
             .iter()
             .map(|(key, forward)| {
-                let stats = CoverageStats::from_rules(&forward.rules);
+                let stats = CoverageStats::from_rule_refs(self.rules(forward));
                 (key.0.clone(), key.1.clone(), stats)
             })
             .collect()
     }
 
+    /// Coverage stats per RFC 2119 level for a spec/impl
+    // r[impl query.status.levels]
+    pub fn status_by_level(
+        &self,
+        spec: &str,
+        impl_name: &str,
+    ) -> Vec<(&'static str, CoverageStats)> {
+        let key: ImplKey = (spec.to_string(), impl_name.to_string());
+        let Some(forward) = self.data.forward_by_impl.get(&key) else {
+            return Vec::new();
+        };
+        CoverageStats::by_level(&self.rules(forward))
+    }
+
+    /// Coverage stats per owner for a spec/impl
+    // r[impl query.status.owners]
+    pub fn status_by_owner(
+        &self,
+        spec: &str,
+        impl_name: &str,
+    ) -> Vec<(Option<String>, CoverageStats)> {
+        let key: ImplKey = (spec.to_string(), impl_name.to_string());
+        let Some(forward) = self.data.forward_by_impl.get(&key) else {
+            return Vec::new();
+        };
+        CoverageStats::by_owner(&self.rules(forward))
+    }
+
+    /// Coverage stats per tag for a spec/impl
+    // r[impl query.status.tags]
+    pub fn status_by_tag(&self, spec: &str, impl_name: &str) -> Vec<(String, CoverageStats)> {
+        let key: ImplKey = (spec.to_string(), impl_name.to_string());
+        let Some(forward) = self.data.forward_by_impl.get(&key) else {
+            return Vec::new();
+        };
+        CoverageStats::by_tag(&self.rules(forward))
+    }
+
     /// Get uncovered rules (no impl refs) for a spec/impl
     // r[impl mcp.discovery.pagination] - Prefix filtering provides pagination
     // r[impl validation.orphaned]
@@ -250,13 +590,14 @@ impl<'a> QueryEngine<'a> {
         let key: ImplKey = (spec.to_string(), impl_name.to_string());
         let forward = self.data.forward_by_impl.get(&key)?;
 
-        let stats = CoverageStats::from_rules(&forward.rules);
+        let rules = self.rules(forward);
+        let stats = CoverageStats::from_rule_refs(rules.iter().copied());
 
-        // Filter uncovered rules, optionally by ID prefix (case-insensitive)
-        let uncovered_rules: Vec<&ApiRule> = forward
-            .rules
-            .iter()
-            .filter(|r| r.impl_refs.is_empty())
+        // Filter uncovered rules, optionally by ID prefix (case-insensitive).
+        // Excluded rules do not need coverage.
+        let uncovered_rules: Vec<&ApiRule> = rules
+            .into_iter()
+            .filter(|r| !r.excluded && r.impl_refs.is_empty())
             .filter(|r| {
                 prefix_filter
                     .map(|p| r.id.base.to_lowercase().starts_with(&p.to_lowercase()))
@@ -277,7 +618,9 @@ impl<'a> QueryEngine<'a> {
         })
     }
 
-    /// Get untested rules (have impl but no verify refs) for a spec/impl
+    /// Get untested rules (have impl but no verify refs) for a spec/impl.
+    /// With test results, rules whose verifying tests failed or didn't run
+    /// are untested too.
     // r[impl mcp.discovery.pagination] - Prefix filtering provides pagination
     pub fn untested(
         &self,
@@ -288,13 +631,17 @@ impl<'a> QueryEngine<'a> {
         let key: ImplKey = (spec.to_string(), impl_name.to_string());
         let forward = self.data.forward_by_impl.get(&key)?;
 
-        let stats = CoverageStats::from_rules(&forward.rules);
+        let rules = self.rules(forward);
+        let stats = CoverageStats::from_rule_refs(rules.iter().copied());
 
         // Filter untested rules, optionally by ID prefix (case-insensitive)
-        let untested_rules: Vec<&ApiRule> = forward
-            .rules
-            .iter()
-            .filter(|r| !r.impl_refs.is_empty() && r.verify_refs.is_empty())
+        let untested_rules: Vec<&ApiRule> = rules
+            .into_iter()
+            .filter(|r| !r.excluded && !r.impl_refs.is_empty())
+            // r[impl query.results]
+            .filter(|r| {
+                r.verify_refs.is_empty() || r.verification.is_some_and(|o| o != TestOutcome::Passed)
+            })
             .filter(|r| {
                 prefix_filter
                     .map(|p| r.id.base.to_lowercase().starts_with(&p.to_lowercase()))
@@ -324,11 +671,20 @@ impl<'a> QueryEngine<'a> {
         let key: ImplKey = (spec.to_string(), impl_name.to_string());
         let forward = self.data.forward_by_impl.get(&key)?;
 
-        let stats = CoverageStats::from_rules(&forward.rules);
+        let rules = self.rules(forward);
+        let stats = CoverageStats::from_rule_refs(rules.iter().copied());
 
         let mut entries: Vec<StaleEntryResult> = Vec::new();
+        let mut needs_review: Vec<ApiRule> = Vec::new();
 
-        for rule in &forward.rules {
+        for rule in rules {
+            // r[impl query.stale.needs-review]
+            if !rule.needs_review.is_empty()
+                && prefix_filter
+                    .is_none_or(|p| rule.id.base.to_lowercase().starts_with(&p.to_lowercase()))
+            {
+                needs_review.push(rule.clone());
+            }
             if rule.stale_refs.is_empty() {
                 continue;
             }
@@ -355,6 +711,7 @@ impl<'a> QueryEngine<'a> {
             impl_name: impl_name.to_string(),
             stats,
             entries,
+            needs_review,
         })
     }
 
@@ -483,6 +840,8 @@ impl<'a> QueryEngine<'a> {
                         source_line: rule.source_line,
                         status: rule.status.clone(),
                         level: rule.level.clone(),
+                        owners: rule.owners.clone(),
+                        tags: rule.tags.clone(),
                         is_stale: rule.is_stale,
                         coverage: Vec::new(), // Will be set at the end
                     });
@@ -494,6 +853,7 @@ impl<'a> QueryEngine<'a> {
                     impl_name: key.1.clone(),
                     impl_refs: rule.impl_refs.clone(),
                     verify_refs: rule.verify_refs.clone(),
+                    executed: rule.executed,
                 });
             }
         }
@@ -535,6 +895,7 @@ pub struct UntestedResult {
 pub struct RuleRef {
     pub id: RuleId,
     pub impl_refs: Vec<ApiCodeRef>,
+    pub verification: Option<TestOutcome>,
 }
 
 #[derive(Debug, Clone)]
@@ -543,6 +904,8 @@ pub struct StaleResult {
     pub impl_name: String,
     pub stats: CoverageStats,
     pub entries: Vec<StaleEntryResult>,
+    /// Rules needing review, in spec order
+    pub needs_review: Vec<ApiRule>,
 }
 
 #[derive(Debug, Clone)]
@@ -605,6 +968,7 @@ pub struct ImplCoverage {
     pub impl_name: String,
     pub impl_refs: Vec<ApiCodeRef>,
     pub verify_refs: Vec<ApiCodeRef>,
+    pub executed: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
@@ -617,6 +981,8 @@ pub struct RuleInfo {
     pub source_line: Option<usize>,
     pub status: Option<String>,
     pub level: Option<String>,
+    pub owners: Vec<String>,
+    pub tags: Vec<String>,
     /// True if any reference to this rule is stale
     pub is_stale: bool,
     /// Coverage across all implementations
@@ -657,6 +1023,7 @@ fn group_rules_by_section(rules: &[&ApiRule]) -> BTreeMap<String, Vec<RuleRef>>
         result.entry(section).or_default().push(RuleRef {
             id: rule.id.clone(),
             impl_refs: rule.impl_refs.clone(),
+            verification: rule.verification,
         });
     }
 
@@ -751,7 +1118,24 @@ pub fn format_status_header(data: &DashboardData, delta: &Delta) -> String {
                 String::new()
             };
 
-            format!("{}: {:.0}%{}", impl_key, stats.impl_percent, change_str)
+            // r[impl mcp.response.header.levels]
+            let rules: Vec<&ApiRule> = forward.rules.iter().collect();
+            let levels: Vec<String> = CoverageStats::by_level(&rules)
+                .into_iter()
+                .map(|(level, stats)| {
+                    format!("{} {:.0}%", level.to_uppercase(), stats.impl_percent)
+                })
+                .collect();
+            let levels_str = if levels.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", levels.join(", "))
+            };
+
+            format!(
+                "{}: {:.0}%{}{}",
+                impl_key, stats.impl_percent, change_str, levels_str
+            )
         })
         .collect();
 
@@ -1019,6 +1403,12 @@ impl RuleInfo {
         if let Some(ref level) = self.level {
             out.push_str(&format!("Level: {}\n", level));
         }
+        if !self.owners.is_empty() {
+            out.push_str(&format!("Owners: {}\n", self.owners.join(", ")));
+        }
+        if !self.tags.is_empty() {
+            out.push_str(&format!("Tags: {}\n", self.tags.join(", ")));
+        }
 
         // Show coverage per implementation
         out.push_str("\n## Coverage by Implementation\n\n");
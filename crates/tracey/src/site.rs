@@ -0,0 +1,194 @@
+//! `tracey export-site` implementation.
+//!
+//! Writes the dashboard as a static site: the embedded SPA assets plus every
+//! API response the SPA needs, pre-rendered to JSON files. The exported
+//! `index.html` sets `window.__TRACEY_STATIC__`, which makes the frontend
+//! loader read those files instead of calling the HTTP bridge, and disables
+//! live updates, search and editing.
+//!
+//! Layout of the output directory (`<spec>` and `<impl>` are slugs, see
+//! [`slug`]):
+//!
+//! - `index.html`, `404.html`, `assets/index.js`, `assets/index.css`
+//! - `api/config.json`
+//! - `api/{forward,reverse,spec}/<spec>/<impl>.json`
+//! - `api/file/<spec>/<impl>/index.json`: map from file path to file number
+//! - `api/file/<spec>/<impl>/<n>.json`
+
+use eyre::{Result, WrapErr};
+use facet::Facet;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tracey_api::ApiForwardData;
+use tracey_proto::FileRequest;
+
+use crate::config::Config;
+use crate::data::{ConfigFile, DashboardData, ImplKey, build_dashboard_data_from_file};
+
+/// What `export_site` wrote.
+#[derive(Debug, Clone, Default)]
+pub struct SiteSummary {
+    pub impls: usize,
+    pub files: usize,
+}
+
+/// Directory name for a spec or impl name. Must match `staticSlug` in the
+/// dashboard's `hooks.ts`.
+pub fn slug(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn write(dir: &Path, relative: &str, content: impl AsRef<[u8]>) -> Result<()> {
+    let path = dir.join(relative);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .wrap_err_with(|| format!("failed to create {}", parent.display()))?;
+    }
+    std::fs::write(&path, content).wrap_err_with(|| format!("failed to write {}", path.display()))
+}
+
+fn write_json<'a, T: Facet<'a>>(dir: &Path, relative: &str, value: &T) -> Result<()> {
+    let json = facet_json::to_string(value).expect("JSON serialization failed");
+    write(dir, relative, json)
+}
+
+/// The SPA shell with static mode switched on. Its assets and API files are
+/// referenced relative to the page, unless `base` pins them to a directory.
+fn static_index_html(base: Option<&str>) -> String {
+    let html = crate::bridge::http::INDEX_HTML.replacen(
+        "</head>",
+        "<script>window.__TRACEY_STATIC__ = true;</script>\n</head>",
+        1,
+    );
+    match base {
+        Some(base) => html.replacen("<head>", &format!("<head>\n  <base href=\"{base}\">"), 1),
+        None => html,
+    }
+}
+
+/// Write the API responses for one spec/impl pair, returning the number of
+/// files exported.
+async fn export_impl(
+    project_root: &Path,
+    out_dir: &Path,
+    data: &DashboardData,
+    key: &ImplKey,
+    highlighter: &mut arborium::Highlighter,
+) -> Result<usize> {
+    let (spec, impl_name) = key;
+    let pair = format!("{}/{}", slug(spec), slug(impl_name));
+    let endpoint = |name: &str| format!("api/{name}/{pair}.json");
+
+    if let Some(forward) = data.forward_by_impl.get(key) {
+        write_json(
+            out_dir,
+            &endpoint("forward"),
+            &ApiForwardData {
+                specs: vec![forward.clone()],
+            },
+        )?;
+
+        let spec_data = match data.specs_content_by_impl.get(key) {
+            Some(cached) => Some(cached.clone()),
+            None => match data.spec_files_by_name.get(spec) {
+                Some(files) => Some(
+                    crate::data::render_spec_content_for_impl(
+                        project_root,
+                        files,
+                        spec,
+                        impl_name,
+                        forward,
+                    )
+                    .await?,
+                ),
+                None => None,
+            },
+        };
+        if let Some(spec_data) = spec_data {
+            write_json(out_dir, &endpoint("spec"), &spec_data)?;
+        }
+    }
+
+    // Files can be opened from the sources tree (reverse data) and from
+    // reference badges in the spec (forward data).
+    let mut paths: Vec<String> = Vec::new();
+    if let Some(reverse) = data.reverse_by_impl.get(key) {
+        write_json(out_dir, &endpoint("reverse"), reverse)?;
+        paths.extend(reverse.files.iter().map(|f| f.path.clone()));
+    }
+    if let Some(forward) = data.forward_by_impl.get(key) {
+        for rule in &forward.rules {
+            for r in rule.impl_refs.iter().chain(&rule.verify_refs) {
+                paths.push(r.file.clone());
+            }
+        }
+    }
+    paths.sort();
+    paths.dedup();
+
+    let file_dir = format!("api/file/{pair}");
+    let mut index: BTreeMap<String, usize> = BTreeMap::new();
+    for path in paths {
+        let req = FileRequest {
+            spec: spec.clone(),
+            impl_name: impl_name.clone(),
+            path: path.clone(),
+        };
+        let Some(file) = crate::daemon::service::render_file(data, project_root, highlighter, req)
+        else {
+            continue;
+        };
+        let n = index.len();
+        write_json(out_dir, &format!("{file_dir}/{n}.json"), &file)?;
+        index.insert(path, n);
+    }
+    let files = index.len();
+    write_json(out_dir, &format!("{file_dir}/index.json"), &index)?;
+
+    Ok(files)
+}
+
+/// Build dashboard data in-process and write it as a static site to `out_dir`,
+/// to be served from `base_url`.
+pub async fn export_site(
+    project_root: &Path,
+    config: &Config,
+    config_file: &ConfigFile,
+    out_dir: &Path,
+    base_url: &str,
+) -> Result<SiteSummary> {
+    let data = build_dashboard_data_from_file(project_root, config, config_file, 1, true).await?;
+    let mut highlighter = arborium::Highlighter::new();
+    let mut summary = SiteSummary::default();
+
+    write(out_dir, "index.html", static_index_html(None))?;
+    // Static hosts serve 404.html for unknown paths, which lets deep links
+    // into the SPA's routes load the app. It can't tell where the site starts
+    // from its own URL, so the base pins its files and routes there.
+    let base = if base_url.ends_with('/') {
+        base_url.to_string()
+    } else {
+        format!("{base_url}/")
+    };
+    write(out_dir, "404.html", static_index_html(Some(&base)))?;
+    write(out_dir, "assets/index.js", crate::bridge::http::INDEX_JS)?;
+    write(out_dir, "assets/index.css", crate::bridge::http::INDEX_CSS)?;
+
+    write_json(out_dir, "api/config.json", &data.config)?;
+
+    for key in data.forward_by_impl.keys() {
+        summary.files += export_impl(project_root, out_dir, &data, key, &mut highlighter).await?;
+        summary.impls += 1;
+    }
+
+    Ok(summary)
+}
@@ -0,0 +1,384 @@
+//! Specs read from a pinned revision of another git repository.
+//!
+//! A spec with a `git` source reads its markdown straight from the object
+//! database of a local repository, so the other repository's worktree never
+//! matters. The commit that `rev` resolved to is pinned in
+//! `.config/tracey/spec.lock`, next to the config; the spec only moves when
+//! `tracey spec update` advances the pin.
+
+use eyre::{Result, WrapErr, bail};
+use facet::Facet;
+use owo_colors::OwoColorize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::ExtractedRule;
+use crate::bump::git_capture;
+use crate::config::{Config, GitSource, SpecConfig};
+
+/// Lock file pinning git spec sources, relative to the project root.
+pub const LOCK_PATH: &str = ".config/tracey/spec.lock";
+
+/// Contents of `.config/tracey/spec.lock`.
+#[derive(Debug, Clone, Default, Facet)]
+pub struct SpecLock {
+    #[facet(default)]
+    pub specs: Vec<LockedSpec>,
+}
+
+/// The commit a git spec source is pinned to.
+#[derive(Debug, Clone, Facet)]
+pub struct LockedSpec {
+    pub name: String,
+    pub repo: String,
+    pub rev: String,
+    pub commit: String,
+}
+
+impl SpecLock {
+    /// The pin of a spec, if it was made for the same repository and revision.
+    pub fn get(&self, name: &str, git: &GitSource) -> Option<&LockedSpec> {
+        self.specs
+            .iter()
+            .find(|l| l.name == name && l.repo == git.repo && l.rev == git.rev)
+    }
+
+    fn set(&mut self, name: &str, git: &GitSource, commit: &str) {
+        self.specs.retain(|l| l.name != name);
+        self.specs.push(LockedSpec {
+            name: name.to_string(),
+            repo: git.repo.clone(),
+            rev: git.rev.clone(),
+            commit: commit.to_string(),
+        });
+        self.specs.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+}
+
+/// Load the lock file. A missing lock file is empty.
+pub fn load_lock(project_root: &Path) -> Result<SpecLock> {
+    let path = project_root.join(LOCK_PATH);
+    if !path.exists() {
+        return Ok(SpecLock::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+    facet_styx::from_str(&content).wrap_err_with(|| format!("failed to parse {LOCK_PATH}"))
+}
+
+pub fn save_lock(project_root: &Path, lock: &SpecLock) -> Result<()> {
+    let content = facet_styx::to_string(lock)
+        .map_err(|e| eyre::eyre!("failed to serialize {LOCK_PATH}: {e}"))?;
+    let path = project_root.join(LOCK_PATH);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .wrap_err_with(|| format!("failed to create {}", parent.display()))?;
+    }
+    std::fs::write(&path, content).wrap_err_with(|| format!("failed to write {}", path.display()))
+}
+
+/// Path of a spec source repository.
+pub fn repo_path(project_root: &Path, git: &GitSource) -> PathBuf {
+    project_root.join(&git.repo)
+}
+
+/// Resolve the revision of a git spec source to a commit.
+pub fn resolve(project_root: &Path, git: &GitSource) -> Result<String> {
+    let repo = repo_path(project_root, git);
+    let output = git_capture(
+        &repo,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", git.rev)],
+    )
+    .wrap_err_with(|| format!("unknown revision '{}' in {}", git.rev, git.repo))?;
+    Ok(output.trim().to_string())
+}
+
+/// r[impl spec.lock+2]
+/// The pinned commit of every git spec source, by spec name.
+///
+/// Builds never write the lock file: a spec without a matching pin (new, or
+/// whose `repo` or `rev` changed) is an error until `tracey spec update`
+/// pins it.
+pub fn locked_commits(project_root: &Path, config: &Config) -> Result<HashMap<String, String>> {
+    let lock = load_lock(project_root)?;
+    let mut commits = HashMap::new();
+    for spec in &config.specs {
+        let Some(git) = &spec.git else {
+            continue;
+        };
+        let Some(locked) = lock.get(&spec.name, git) else {
+            bail!(
+                "spec '{}' has no pin for {} at '{}' in {LOCK_PATH}; run `tracey spec update {}` to pin it",
+                spec.name,
+                git.repo,
+                git.rev,
+                spec.name
+            );
+        };
+        commits.insert(spec.name.clone(), locked.commit.clone());
+    }
+    Ok(commits)
+}
+
+/// Prepare a snapshot of the project at `revision` (see
+/// [`crate::diff::build_at_revision`]) for its git spec sources: repositories
+/// are made absolute, and the snapshot gets the lock file of that revision if
+/// it was committed, or the current one otherwise. Specs that lock file
+/// doesn't pin are pinned to what their `rev` resolves to now, in the
+/// snapshot only.
+pub(crate) fn relocate_into_snapshot(
+    project_root: &Path,
+    revision: &str,
+    snapshot: &Path,
+    config: &mut Config,
+) -> Result<()> {
+    if config.specs.iter().all(|s| s.git.is_none()) {
+        return Ok(());
+    }
+    let mut lock =
+        match crate::bump::git_cat_file(project_root, revision, &format!("./{LOCK_PATH}"))? {
+            Some(content) => facet_styx::from_str(&content)
+                .wrap_err_with(|| format!("failed to parse {LOCK_PATH} at {revision}"))?,
+            None => load_lock(project_root)?,
+        };
+    let absolute = |repo: &str| project_root.join(repo).to_string_lossy().into_owned();
+    for locked in &mut lock.specs {
+        locked.repo = absolute(&locked.repo);
+    }
+    for spec in &mut config.specs {
+        let Some(git) = spec.git.as_mut() else {
+            continue;
+        };
+        git.repo = absolute(&git.repo);
+        if lock.get(&spec.name, git).is_none() {
+            let commit = resolve(project_root, git)?;
+            lock.set(&spec.name, git, &commit);
+        }
+    }
+    save_lock(snapshot, &lock)
+}
+
+fn include_matcher(include: &[String]) -> Result<globset::GlobSet> {
+    let mut builder = globset::GlobSetBuilder::new();
+    if include.is_empty() {
+        builder.add(globset::Glob::new("**/*.md").expect("valid glob"));
+    }
+    for pattern in include {
+        builder.add(
+            globset::Glob::new(pattern)
+                .wrap_err_with(|| format!("invalid glob pattern: {pattern}"))?,
+        );
+    }
+    builder.build().wrap_err("failed to build glob set")
+}
+
+/// The markdown files of a spec at a commit of its source repository, as
+/// `(<repo>/<path>, content)`, so they point at the other repository's
+/// checkout.
+pub fn read_files(
+    project_root: &Path,
+    include: &[String],
+    git: &GitSource,
+    commit: &str,
+) -> Result<Vec<(String, String)>> {
+    let repo = repo_path(project_root, git);
+    let listing = git_capture(&repo, &["ls-tree", "-r", "-z", "--name-only", commit])?;
+    let matcher = include_matcher(include)?;
+    let paths: Vec<String> = listing
+        .split('\0')
+        .filter(|p| p.ends_with(".md") && matcher.is_match(p))
+        .map(String::from)
+        .collect();
+
+    crate::diff::git_cat_files(&repo, commit, &paths)?
+        .into_iter()
+        .map(|(path, content)| {
+            let display_path = format!("{}/{path}", git.repo.trim_end_matches('/'));
+            let content = String::from_utf8(content)
+                .map_err(|_| eyre::eyre!("{display_path} is not valid UTF-8"))?;
+            Ok((display_path, content))
+        })
+        .collect()
+}
+
+/// r[impl config.spec.git]
+/// Extract the rules of a spec from a commit of its source repository.
+pub async fn load_rules(
+    project_root: &Path,
+    spec: &SpecConfig,
+    git: &GitSource,
+    commit: &str,
+    quiet: bool,
+) -> Result<Vec<ExtractedRule>> {
+    let mut rules = Vec::new();
+    let mut seen_ids: HashSet<String> = HashSet::new();
+    for (display_path, content) in read_files(project_root, &spec.include, git, commit)? {
+        let extracted = crate::extract_rules_from_markdown(&content, &display_path)
+            .await
+            .wrap_err_with(|| format!("Failed to process {display_path}"))?;
+        if !quiet && !extracted.is_empty() {
+            eprintln!(
+                "   {} {} requirements from {} at {}",
+                "Found".green(),
+                extracted.len(),
+                display_path,
+                short(commit)
+            );
+        }
+        for rule in extracted {
+            if !seen_ids.insert(rule.def.id.to_string()) {
+                bail!(
+                    "Duplicate requirement '{}' found in {}",
+                    rule.def.id.red(),
+                    display_path
+                );
+            }
+            rules.push(rule);
+        }
+    }
+    Ok(rules)
+}
+
+/// What `tracey spec update` changed for one spec.
+#[derive(Debug, Clone)]
+pub struct SpecUpdate {
+    pub name: String,
+    /// Previously pinned commit, if the spec was pinned
+    pub old_commit: Option<String>,
+    pub new_commit: String,
+    /// Number of rules at the new commit
+    pub rules: usize,
+    /// Rules that only exist at the new commit
+    pub added: Vec<String>,
+    /// Rules that no longer exist at the new commit
+    pub removed: Vec<String>,
+    /// Rules whose text or version changed
+    pub changed: Vec<String>,
+}
+
+fn rules_by_base(rules: &[ExtractedRule]) -> BTreeMap<String, (String, String)> {
+    rules
+        .iter()
+        .filter_map(|r| {
+            let id = r.def.id.to_string();
+            let base = tracey_core::parse_rule_id(&id)?.base;
+            Some((base, (id, r.def.raw.trim().to_string())))
+        })
+        .collect()
+}
+
+/// r[impl spec.lock.update]
+/// Advance the pins of git spec sources (all, or the one named `name`) to
+/// what their revisions resolve to now, and report which rules changed.
+pub async fn update(
+    project_root: &Path,
+    config: &Config,
+    name: Option<&str>,
+) -> Result<Vec<SpecUpdate>> {
+    let specs: Vec<(&SpecConfig, &GitSource)> = config
+        .specs
+        .iter()
+        .filter(|s| name.is_none_or(|n| s.name == n))
+        .filter_map(|s| s.git.as_ref().map(|git| (s, git)))
+        .collect();
+    if specs.is_empty() {
+        match name {
+            Some(name) => bail!("no spec named '{name}' is read from git"),
+            None => bail!("no spec is read from git"),
+        }
+    }
+
+    let mut lock = load_lock(project_root)?;
+    let mut updates = Vec::new();
+    for (spec, git) in specs {
+        let old_commit = lock.get(&spec.name, git).map(|l| l.commit.clone());
+        let new_commit = resolve(project_root, git)?;
+        let new_rules = load_rules(project_root, spec, git, &new_commit, true).await?;
+
+        let mut update = SpecUpdate {
+            name: spec.name.clone(),
+            old_commit: old_commit.clone(),
+            new_commit: new_commit.clone(),
+            rules: new_rules.len(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        };
+        if let Some(old_commit) = old_commit.filter(|c| *c != new_commit) {
+            let old_rules = load_rules(project_root, spec, git, &old_commit, true)
+                .await
+                .wrap_err_with(|| {
+                    format!(
+                        "failed to read spec '{}' at its pinned commit {old_commit}",
+                        spec.name
+                    )
+                })?;
+            let old = rules_by_base(&old_rules);
+            let new = rules_by_base(&new_rules);
+            for (base, (id, text)) in &new {
+                match old.get(base) {
+                    None => update.added.push(id.clone()),
+                    Some((old_id, old_text)) if old_id != id || old_text != text => {
+                        update.changed.push(id.clone())
+                    }
+                    Some(_) => {}
+                }
+            }
+            for (base, (id, _)) in &old {
+                if !new.contains_key(base) {
+                    update.removed.push(id.clone());
+                }
+            }
+        }
+
+        lock.set(&spec.name, git, &new_commit);
+        updates.push(update);
+    }
+    save_lock(project_root, &lock)?;
+    Ok(updates)
+}
+
+fn short(commit: &str) -> &str {
+    &commit[..commit.len().min(10)]
+}
+
+/// Render the result of `tracey spec update`.
+pub fn format_updates(updates: &[SpecUpdate]) -> String {
+    let mut out = String::new();
+    for update in updates {
+        match &update.old_commit {
+            None => out.push_str(&format!(
+                "{}: pinned at {} ({} rules)\n",
+                update.name,
+                short(&update.new_commit),
+                update.rules
+            )),
+            Some(old) if *old == update.new_commit => out.push_str(&format!(
+                "{}: already at {}\n",
+                update.name,
+                short(&update.new_commit)
+            )),
+            Some(old) => {
+                out.push_str(&format!(
+                    "{}: {} -> {} ({} added, {} removed, {} changed)\n",
+                    update.name,
+                    short(old),
+                    short(&update.new_commit),
+                    update.added.len(),
+                    update.removed.len(),
+                    update.changed.len()
+                ));
+                for id in &update.added {
+                    out.push_str(&format!("  + {id}\n"));
+                }
+                for id in &update.removed {
+                    out.push_str(&format!("  - {id}\n"));
+                }
+                for id in &update.changed {
+                    out.push_str(&format!("  ~ {id}\n"));
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,302 @@
+//! Test results: does a `verify` reference point at a passing test?
+//!
+//! Result files are JUnit XML (cargo-nextest, pytest, most CI tooling),
+//! libtest JSON (`cargo test -- --format json`, nextest's libtest output) or
+//! `go test -json` output. Each `verify` reference is matched to results by
+//! the name of the code unit enclosing it and, when the format records it,
+//! the test's file.
+
+use eyre::{Result, WrapErr, bail};
+use facet::Facet;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use tracey_core::code_units::CodeUnit;
+
+use crate::data::{ApiCodeRef, ApiSpecForward, ApiTestResult, DashboardData, TestOutcome};
+
+/// r[impl results.formats]
+/// Parse a test result file, detecting its format from the content.
+pub fn parse(content: &str) -> Result<Vec<ApiTestResult>> {
+    let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+    if trimmed.starts_with('<') {
+        return Ok(parse_junit(trimmed));
+    }
+    let results = parse_json_lines(content);
+    if results.is_empty() && !trimmed.is_empty() {
+        bail!("no test results found (expected JUnit XML, libtest JSON or `go test -json` output)");
+    }
+    Ok(results)
+}
+
+/// Read test results from a file, or from every `.xml` and `.json` file of a
+/// directory.
+pub fn load(path: &Path) -> Result<Vec<ApiTestResult>> {
+    let files = if path.is_dir() {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .wrap_err_with(|| format!("failed to read {}", path.display()))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|ext| ext == "xml" || ext == "json")
+            })
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut results = Vec::new();
+    for file in files {
+        let content = std::fs::read_to_string(&file)
+            .wrap_err_with(|| format!("failed to read {}", file.display()))?;
+        results.extend(
+            parse(&content).wrap_err_with(|| format!("failed to parse {}", file.display()))?,
+        );
+    }
+    Ok(results)
+}
+
+/// Load the `results` of an implementation's config, relative to the project
+/// root. Files that don't exist yet, e.g. because the tests haven't run, are
+/// skipped, as are files that can't be parsed.
+pub fn load_configured(project_root: &Path, paths: &[String]) -> Vec<ApiTestResult> {
+    let mut results = Vec::new();
+    for path in paths {
+        let path = project_root.join(path);
+        if !path.exists() {
+            continue;
+        }
+        match load(&path) {
+            Ok(loaded) => results.extend(loaded),
+            Err(e) => tracing::warn!("skipping test results {}: {e:?}", path.display()),
+        }
+    }
+    results
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Value of an attribute of an XML start tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(pos) = rest.find(name) {
+        let preceded_by_space = rest[..pos].ends_with(char::is_whitespace);
+        let after = rest[pos + name.len()..].trim_start();
+        rest = &rest[pos + name.len()..];
+        let Some(after) = after.strip_prefix('=') else {
+            continue;
+        };
+        let after = after.trim_start();
+        let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            continue;
+        };
+        if !preceded_by_space {
+            continue;
+        }
+        let value = &after[1..];
+        let end = value.find(quote)?;
+        return Some(unescape(&value[..end]));
+    }
+    None
+}
+
+/// `<testcase>` elements of a JUnit report. A test case with a `<failure>`
+/// or `<error>` failed, one with `<skipped>` did not run.
+fn parse_junit(xml: &str) -> Vec<ApiTestResult> {
+    let mut results = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find("<testcase") {
+        rest = &rest[start + "<testcase".len()..];
+        if !rest.starts_with(char::is_whitespace) && !rest.starts_with(['>', '/']) {
+            continue;
+        }
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..tag_end];
+        let body = if tag.ends_with('/') {
+            ""
+        } else {
+            let body = &rest[tag_end + 1..];
+            &body[..body.find("</testcase>").unwrap_or(body.len())]
+        };
+        let Some(name) = attribute(tag, "name") else {
+            continue;
+        };
+        let outcome = if body.contains("<failure") || body.contains("<error") {
+            TestOutcome::Failed
+        } else if body.contains("<skipped") {
+            TestOutcome::NotRun
+        } else {
+            TestOutcome::Passed
+        };
+        results.push(ApiTestResult {
+            name,
+            file: attribute(tag, "file"),
+            outcome,
+        });
+        rest = &rest[tag_end..];
+    }
+    results
+}
+
+/// One line of libtest JSON or `go test -json` output.
+#[derive(Debug, Facet)]
+struct JsonEvent {
+    /// libtest: `suite` or `test`
+    #[facet(rename = "type", default)]
+    kind: Option<String>,
+    /// libtest: `started`, `ok`, `failed`, `ignored` or `timeout`
+    #[facet(default)]
+    event: Option<String>,
+    /// libtest test name
+    #[facet(default)]
+    name: Option<String>,
+    /// go: `run`, `pass`, `fail`, `skip`, `output`, ...
+    #[facet(rename = "Action", default)]
+    action: Option<String>,
+    /// go test name; unset for package events
+    #[facet(rename = "Test", default)]
+    test: Option<String>,
+}
+
+fn parse_json_lines(content: &str) -> Vec<ApiTestResult> {
+    let mut results = Vec::new();
+    for line in content.lines().map(str::trim) {
+        // `go test -json` interleaves build output, and `cargo test` its own
+        // progress lines.
+        if !line.starts_with('{') {
+            continue;
+        }
+        let Ok(event) = facet_json::from_str::<JsonEvent>(line) else {
+            continue;
+        };
+        let (name, status) = match (&event.kind, &event.name, &event.test, &event.action) {
+            (Some(kind), Some(name), _, _) if kind == "test" => (name, event.event.as_deref()),
+            (_, _, Some(test), Some(_)) => (test, event.action.as_deref()),
+            _ => continue,
+        };
+        let outcome = match status {
+            Some("ok" | "pass") => TestOutcome::Passed,
+            Some("failed" | "fail" | "timeout") => TestOutcome::Failed,
+            Some("ignored" | "skip") => TestOutcome::NotRun,
+            _ => continue,
+        };
+        results.push(ApiTestResult {
+            name: name.clone(),
+            file: None,
+            outcome,
+        });
+    }
+    results
+}
+
+/// The function name of a reported test: without nextest's binary prefix
+/// (`crate::bin$`), module or class path, go subtest or pytest parameters.
+fn function_name(name: &str) -> &str {
+    let name = name.rsplit('$').next().unwrap_or(name);
+    let name = name.split(['[', '/']).next().unwrap_or(name);
+    name.rsplit([':', '.']).next().unwrap_or(name)
+}
+
+/// Whether a file named by a report is the file of a reference, allowing
+/// either to be relative to a different root.
+pub(crate) fn same_file(result_file: &str, ref_file: &str) -> bool {
+    let result_file = result_file.trim_start_matches("./");
+    let ref_file = ref_file.trim_start_matches("./");
+    Path::new(ref_file).ends_with(result_file) || Path::new(result_file).ends_with(ref_file)
+}
+
+/// The code unit a reference to `base` belongs to: the named code unit the
+/// reference annotates or, failing that, the innermost one containing it.
+pub(crate) fn enclosing_unit<'u>(
+    units: &'u [CodeUnit],
+    line: usize,
+    base: &str,
+) -> Option<&'u CodeUnit> {
+    let annotated = units
+        .iter()
+        .filter(|u| u.name.is_some() && u.end_line >= line)
+        .filter(|u| u.req_refs.iter().any(|id| id.base == base))
+        .min_by_key(|u| (u.start_line.abs_diff(line), u.end_line - u.start_line));
+    let containing = || {
+        units
+            .iter()
+            .filter(|u| u.name.is_some() && u.start_line <= line && line <= u.end_line)
+            .min_by_key(|u| u.end_line - u.start_line)
+    };
+    annotated.or_else(containing)
+}
+
+/// r[impl results.verification]
+/// Combine outcomes: any failure fails, otherwise any pass passes.
+fn combine(outcomes: impl IntoIterator<Item = TestOutcome>) -> Option<TestOutcome> {
+    outcomes.into_iter().fold(None, |combined, outcome| {
+        Some(match (combined, outcome) {
+            (Some(TestOutcome::Failed), _) | (_, TestOutcome::Failed) => TestOutcome::Failed,
+            (Some(TestOutcome::Passed), _) | (_, TestOutcome::Passed) => TestOutcome::Passed,
+            _ => TestOutcome::NotRun,
+        })
+    })
+}
+
+fn outcome_of(
+    code_ref: &ApiCodeRef,
+    base: &str,
+    code_units: &BTreeMap<PathBuf, Vec<CodeUnit>>,
+    results: &[ApiTestResult],
+) -> TestOutcome {
+    let name = code_units
+        .iter()
+        .find(|(path, _)| path.ends_with(&code_ref.file))
+        .and_then(|(_, units)| enclosing_unit(units, code_ref.line, base))
+        .and_then(|unit| unit.name.as_deref());
+    let Some(name) = name else {
+        return TestOutcome::NotRun;
+    };
+    let matching = results.iter().filter(|result| {
+        function_name(&result.name) == name
+            && result
+                .file
+                .as_deref()
+                .is_none_or(|file| same_file(file, &code_ref.file))
+    });
+    combine(matching.map(|result| result.outcome)).unwrap_or(TestOutcome::NotRun)
+}
+
+/// r[impl results.matching]
+/// Set the outcome of every `verify` reference of a spec/impl pair, and the
+/// combined verification of every rule that has one.
+pub fn apply(
+    forward: &mut ApiSpecForward,
+    code_units: &BTreeMap<PathBuf, Vec<CodeUnit>>,
+    results: &[ApiTestResult],
+) {
+    for rule in &mut forward.rules {
+        for code_ref in &mut rule.verify_refs {
+            code_ref.outcome = Some(outcome_of(code_ref, &rule.id.base, code_units, results));
+        }
+        rule.verification = combine(rule.verify_refs.iter().filter_map(|r| r.outcome));
+    }
+}
+
+/// A copy of `data` with `results` applied to every spec/impl pair, for
+/// queries given their own result files.
+pub fn with_results(data: &DashboardData, results: &[ApiTestResult]) -> DashboardData {
+    let mut data = data.clone();
+    for (key, forward) in &mut data.forward_by_impl {
+        if let Some(code_units) = data.code_units_by_impl.get(key) {
+            apply(forward, code_units, results);
+        }
+    }
+    data
+}
@@ -0,0 +1,456 @@
+//! Coverage thresholds from the `thresholds` blocks in the config.
+//!
+//! Thresholds can be set on a spec, on an impl (overriding the spec field by
+//! field), and on rule ID prefixes within a spec. They are evaluated after
+//! every build and reported as `CoverageThreshold` validation errors, so they
+//! show up wherever validation does: `tracey query validate`, `query status`,
+//! the MCP `tracey_status` tool and LSP diagnostics on the config file.
+
+use std::collections::BTreeMap;
+
+use tracey_api::{ApiReverseData, ApiRule, ApiSpecForward};
+
+use crate::config::{Config, SpecConfig, Thresholds};
+use crate::data::ImplKey;
+use crate::server::CoverageStats;
+
+/// A threshold that was not met by a spec/impl pair.
+#[derive(Debug, Clone)]
+pub struct ThresholdViolation {
+    pub spec: String,
+    pub impl_name: String,
+    /// Where the violated threshold is configured.
+    pub source: ThresholdSource,
+    pub message: String,
+}
+
+/// Which `thresholds` block a threshold came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThresholdSource {
+    Spec,
+    Impl,
+    Prefix(String),
+}
+
+/// Thresholds for an impl, with the impl block overriding the spec block.
+fn effective(spec: &SpecConfig, impl_name: &str) -> (Thresholds, Thresholds) {
+    let spec_thresholds = spec.thresholds.clone().unwrap_or_default();
+    let impl_thresholds = spec
+        .impls
+        .iter()
+        .find(|i| i.name == impl_name)
+        .and_then(|i| i.thresholds.clone())
+        .unwrap_or_default();
+    (spec_thresholds, impl_thresholds)
+}
+
+/// Check one threshold field, preferring the impl value over the spec value.
+fn check_min(
+    label: &str,
+    actual: f64,
+    spec_min: Option<f64>,
+    impl_min: Option<f64>,
+    mut violate: impl FnMut(ThresholdSource, String),
+) {
+    let (min, source) = match (impl_min, spec_min) {
+        (Some(min), _) => (min, ThresholdSource::Impl),
+        (None, Some(min)) => (min, ThresholdSource::Spec),
+        (None, None) => return,
+    };
+    if actual < min {
+        violate(
+            source,
+            format!("{label} {actual:.1}% is below the required {min:.1}%"),
+        );
+    }
+}
+
+fn check_max_stale(
+    stale: usize,
+    max: Option<usize>,
+    source: ThresholdSource,
+    mut violate: impl FnMut(ThresholdSource, String),
+) {
+    if let Some(max) = max
+        && stale > max
+    {
+        violate(
+            source,
+            format!("{stale} stale rule(s) exceed the allowed maximum of {max}"),
+        );
+    }
+}
+
+/// Whether a rule ID is the prefix or under it, segment by segment: `auth`
+/// covers `auth` and `auth.login` but not `authz.grant`. A prefix ending
+/// with `.` only covers the rules under it.
+pub(crate) fn is_under(base: &str, prefix: &str) -> bool {
+    if prefix.ends_with('.') {
+        return base.starts_with(prefix);
+    }
+    base == prefix
+        || base
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// Evaluate every configured threshold against built forward/reverse data.
+///
+/// Pairs without rules are skipped: there is nothing to cover yet.
+pub fn evaluate(
+    config: &Config,
+    forward_by_impl: &BTreeMap<ImplKey, ApiSpecForward>,
+    reverse_by_impl: &BTreeMap<ImplKey, ApiReverseData>,
+) -> Vec<ThresholdViolation> {
+    let mut out = Vec::new();
+
+    for ((spec_name, impl_name), forward) in forward_by_impl {
+        let Some(spec) = config.specs.iter().find(|s| &s.name == spec_name) else {
+            continue;
+        };
+        if forward.rules.is_empty() {
+            continue;
+        }
+
+        let mut violate = |source: ThresholdSource, message: String| {
+            out.push(ThresholdViolation {
+                spec: spec_name.clone(),
+                impl_name: impl_name.clone(),
+                source,
+                message,
+            });
+        };
+
+        let (spec_t, impl_t) = effective(spec, impl_name);
+        let stats = CoverageStats::from_rules(&forward.rules);
+        check_min(
+            "impl coverage",
+            stats.impl_percent,
+            spec_t.min_impl,
+            impl_t.min_impl,
+            &mut violate,
+        );
+        check_min(
+            "verify coverage",
+            stats.verify_percent,
+            spec_t.min_verify,
+            impl_t.min_verify,
+            &mut violate,
+        );
+        match (impl_t.max_stale, spec_t.max_stale) {
+            (Some(max), _) => check_max_stale(
+                stats.stale_covered,
+                Some(max),
+                ThresholdSource::Impl,
+                &mut violate,
+            ),
+            (None, max) => check_max_stale(
+                stats.stale_covered,
+                max,
+                ThresholdSource::Spec,
+                &mut violate,
+            ),
+        }
+        if let Some(reverse) = reverse_by_impl.get(&(spec_name.clone(), impl_name.clone()))
+            && reverse.total_units > 0
+        {
+            let percent = reverse.covered_units as f64 / reverse.total_units as f64 * 100.0;
+            check_min(
+                "reverse coverage",
+                percent,
+                spec_t.min_reverse,
+                impl_t.min_reverse,
+                &mut violate,
+            );
+        }
+
+        for prefix in &spec.prefix_thresholds {
+            let rules: Vec<ApiRule> = forward
+                .rules
+                .iter()
+                .filter(|r| is_under(&r.id.base, &prefix.prefix))
+                .cloned()
+                .collect();
+            if rules.is_empty() {
+                continue;
+            }
+            let stats = CoverageStats::from_rules(&rules);
+            let source = ThresholdSource::Prefix(prefix.prefix.clone());
+            let t = &prefix.thresholds;
+            let label = |what: &str| format!("{what} of rules under '{}'", prefix.prefix);
+            check_min(
+                &label("impl coverage"),
+                stats.impl_percent,
+                t.min_impl,
+                None,
+                |_, m| violate(source.clone(), m),
+            );
+            check_min(
+                &label("verify coverage"),
+                stats.verify_percent,
+                t.min_verify,
+                None,
+                |_, m| violate(source.clone(), m),
+            );
+            check_max_stale(stats.stale_covered, t.max_stale, source.clone(), |_, m| {
+                violate(source.clone(), format!("{m} under '{}'", prefix.prefix))
+            });
+        }
+    }
+
+    out
+}
+
+/// Find the 0-based line in the config text where a violated threshold is
+/// configured, so diagnostics can point at it.
+///
+/// The text is read as a styx document and the keys are matched by structure:
+/// the `specs` entry whose `name` is the spec, then its `impls` entry whose
+/// `name` is the impl, then the `thresholds` key (or the `prefix_thresholds`
+/// entry with the prefix). Key order within a block does not matter. Impls
+/// discovered with `impls_from` point at that key; otherwise a missing key
+/// falls back to the spec's `name`.
+pub fn locate(config_text: &str, violation: &ThresholdViolation) -> usize {
+    let offset = locate_offset(config_text, violation).unwrap_or(0);
+    config_text[..offset].matches('\n').count()
+}
+
+fn locate_offset(config_text: &str, violation: &ThresholdViolation) -> Option<usize> {
+    let document = Reader::document(config_text);
+    let spec = document.get("specs")?.find("name", &violation.spec)?;
+
+    let found = match &violation.source {
+        ThresholdSource::Spec => spec.key("thresholds"),
+        ThresholdSource::Impl => {
+            match spec
+                .get("impls")
+                .and_then(|impls| impls.find("name", &violation.impl_name))
+            {
+                Some(impl_) => impl_.key("thresholds").or(impl_.key("name")),
+                None => spec.key("impls_from"),
+            }
+        }
+        ThresholdSource::Prefix(prefix) => spec
+            .get("prefix_thresholds")
+            .and_then(|entries| entries.find("prefix", prefix))
+            .and_then(|entry| entry.key("prefix"))
+            .or(spec.key("prefix_thresholds")),
+    };
+    found.or(spec.key("name"))
+}
+
+/// A value of the config document, as far as locating keys needs: objects
+/// keep the byte offset of each key.
+enum Node {
+    Scalar(String),
+    Object(Vec<Entry>),
+    Sequence(Vec<Node>),
+    Unit,
+}
+
+struct Entry {
+    key: String,
+    offset: usize,
+    value: Node,
+}
+
+impl Node {
+    fn entry(&self, key: &str) -> Option<&Entry> {
+        match self {
+            Node::Object(entries) => entries.iter().find(|e| e.key == key),
+            _ => None,
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&Node> {
+        self.entry(key).map(|e| &e.value)
+    }
+
+    /// Byte offset of `key` in this object.
+    fn key(&self, key: &str) -> Option<usize> {
+        self.entry(key).map(|e| e.offset)
+    }
+
+    fn items(&self) -> &[Node] {
+        match self {
+            Node::Sequence(items) => items,
+            _ => &[],
+        }
+    }
+
+    /// The item of a sequence whose `key` is the scalar `value`.
+    fn find(&self, key: &str, value: &str) -> Option<&Node> {
+        self.items()
+            .iter()
+            .find(|item| matches!(item.get(key), Some(Node::Scalar(s)) if s == value))
+    }
+}
+
+/// Reads the structure of a styx document: objects, sequences, scalars,
+/// tags, comments, quoted, raw and heredoc strings. It is lenient, since
+/// the document has already been parsed into the config.
+struct Reader<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn document(text: &'a str) -> Node {
+        let mut reader = Reader { text, pos: 0 };
+        Node::Object(reader.entries(None))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// Skip blanks and comments, and newlines and commas when `separators`.
+    fn skip(&mut self, separators: bool) {
+        while let Some(c) = self.peek() {
+            match c {
+                b' ' | b'\t' | b'\r' => self.pos += 1,
+                b'\n' | b',' if separators => self.pos += 1,
+                b'/' if self.rest().starts_with("//") => {
+                    self.pos += self.rest().find('\n').unwrap_or(self.rest().len());
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn entries(&mut self, close: Option<u8>) -> Vec<Entry> {
+        let mut entries = Vec::new();
+        loop {
+            self.skip(true);
+            match self.peek() {
+                None => break,
+                Some(b'}' | b')') => {
+                    if close.is_some() {
+                        self.pos += 1;
+                        break;
+                    }
+                    self.pos += 1;
+                    continue;
+                }
+                Some(b'{' | b'(') => {
+                    self.value();
+                    continue;
+                }
+                _ => {}
+            }
+            let offset = self.pos;
+            let key = self.scalar();
+            self.skip(false);
+            let value = match self.peek() {
+                None | Some(b'\n' | b',' | b'}' | b')') => Node::Unit,
+                _ => self.value(),
+            };
+            entries.push(Entry { key, offset, value });
+        }
+        entries
+    }
+
+    fn value(&mut self) -> Node {
+        match self.peek() {
+            Some(b'{') => {
+                self.pos += 1;
+                Node::Object(self.entries(Some(b'}')))
+            }
+            Some(b'(') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                loop {
+                    self.skip(true);
+                    match self.peek() {
+                        None => break,
+                        Some(b')' | b'}') => {
+                            self.pos += 1;
+                            break;
+                        }
+                        _ => items.push(self.value()),
+                    }
+                }
+                Node::Sequence(items)
+            }
+            Some(b'@') => {
+                self.scalar();
+                // A tag's payload follows it directly.
+                match self.peek() {
+                    Some(b'{' | b'(' | b'"') => self.value(),
+                    _ => Node::Unit,
+                }
+            }
+            _ => Node::Scalar(self.scalar()),
+        }
+    }
+
+    /// A bare, quoted, raw or heredoc scalar, or a tag name.
+    fn scalar(&mut self) -> String {
+        let rest = self.rest();
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let mut value = String::new();
+            let mut chars = quoted.char_indices();
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        self.pos += 1 + i + 1;
+                        return value;
+                    }
+                    '\\' => {
+                        if let Some((_, escaped)) = chars.next() {
+                            value.push(match escaped {
+                                'n' => '\n',
+                                't' => '\t',
+                                other => other,
+                            });
+                        }
+                    }
+                    c => value.push(c),
+                }
+            }
+            self.pos = self.text.len();
+            return value;
+        }
+        let hashes = rest
+            .strip_prefix('r')
+            .map(|raw| raw.len() - raw.trim_start_matches('#').len());
+        if let Some(hashes) = hashes
+            && rest[1 + hashes..].starts_with('"')
+        {
+            let body = &rest[1 + hashes + 1..];
+            let end = format!("\"{}", "#".repeat(hashes));
+            let len = body.find(&end).unwrap_or(body.len());
+            self.pos += 1 + hashes + 1 + (len + end.len()).min(body.len());
+            return body[..len].to_string();
+        }
+        if let Some(heredoc) = rest.strip_prefix("<<") {
+            let delimiter = heredoc.split_whitespace().next().unwrap_or_default();
+            let mut value = String::new();
+            let mut consumed = rest.find('\n').map_or(rest.len(), |i| i + 1);
+            for line in rest[consumed..].split_inclusive('\n') {
+                consumed += line.len();
+                if line.trim() == delimiter {
+                    break;
+                }
+                value.push_str(line);
+            }
+            self.pos += consumed;
+            return value;
+        }
+        let len = rest
+            .find(|c: char| c.is_whitespace() || "{}(),\"".contains(c))
+            .unwrap_or(rest.len());
+        // Always make progress, even on a stray character.
+        let len = if len == 0 {
+            rest.chars().next().map_or(0, char::len_utf8)
+        } else {
+            len
+        };
+        self.pos += len;
+        rest[..len].to_string()
+    }
+}
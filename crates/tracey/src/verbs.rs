@@ -0,0 +1,104 @@
+//! Reference verbs declared in the config, besides the built-in `impl`,
+//! `verify`, `depends`, `related` and `define`.
+//!
+//! A declared verb says which coverage its references count toward, if any,
+//! and whether they may appear in test files. Words that are neither built
+//! in nor declared don't make references at all, see [`names`].
+
+use eyre::{Result, bail};
+use tracey_core::RefVerb;
+
+use crate::config::Config;
+use crate::data::{ApiVerb, VerbCounts};
+
+/// What a reference counts as, by its verb.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerbRole {
+    /// Implementation coverage, like `impl`
+    Impl,
+    /// Verification coverage, like `verify`
+    Verify,
+    /// Neither, like `depends`
+    Other,
+}
+
+/// r[impl config.verbs]
+/// The verbs declared in the config, validated.
+pub fn declared(config: &Config) -> Result<Vec<ApiVerb>> {
+    let mut verbs: Vec<ApiVerb> = Vec::new();
+    for verb in &config.verbs {
+        let name = verb.name.as_str();
+        if RefVerb::parse(name).is_some() {
+            bail!("verb `{name}` is built in and can't be declared");
+        }
+        if !is_verb_name(name) {
+            bail!(
+                "invalid verb `{name}`: verbs are lowercase letters, digits and dashes, \
+                 starting with a letter"
+            );
+        }
+        if verbs.iter().any(|v| v.name == name) {
+            bail!("verb `{name}` is declared twice");
+        }
+        let counts = match verb.counts.as_deref() {
+            None => None,
+            Some("impl") => Some(VerbCounts::Impl),
+            Some("verify") => Some(VerbCounts::Verify),
+            Some(other) => {
+                bail!("invalid `counts` for verb `{name}`: `{other}` (expected `impl` or `verify`)")
+            }
+        };
+        verbs.push(ApiVerb {
+            name: name.to_string(),
+            counts,
+            in_tests: verb.in_tests.unwrap_or(counts != Some(VerbCounts::Impl)),
+            description: verb.description.clone(),
+        });
+    }
+    Ok(verbs)
+}
+
+fn is_verb_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// The names of the declared verbs, for extracting references with
+/// [`tracey_core::Reqs::extract_from_content`].
+pub fn names(declared: &[ApiVerb]) -> Vec<String> {
+    declared.iter().map(|v| v.name.clone()).collect()
+}
+
+fn find<'a>(verb: &RefVerb, declared: &'a [ApiVerb]) -> Option<&'a ApiVerb> {
+    match verb {
+        RefVerb::Custom(name) => declared.iter().find(|v| &v.name == name),
+        _ => None,
+    }
+}
+
+/// r[impl ref.verb.custom+2]
+/// What a reference counts as, or `None` if its verb isn't declared.
+pub fn role(verb: &RefVerb, declared: &[ApiVerb]) -> Option<VerbRole> {
+    match verb {
+        RefVerb::Impl | RefVerb::Define => Some(VerbRole::Impl),
+        RefVerb::Verify => Some(VerbRole::Verify),
+        RefVerb::Depends | RefVerb::Related => Some(VerbRole::Other),
+        RefVerb::Custom(_) => find(verb, declared).map(|v| match v.counts {
+            Some(VerbCounts::Impl) => VerbRole::Impl,
+            Some(VerbCounts::Verify) => VerbRole::Verify,
+            None => VerbRole::Other,
+        }),
+    }
+}
+
+/// Whether a reference with this verb may appear in a test file.
+/// Undeclared verbs are ignored, so they are allowed anywhere.
+pub fn allowed_in_tests(verb: &RefVerb, declared: &[ApiVerb]) -> bool {
+    match verb {
+        RefVerb::Impl => false,
+        RefVerb::Custom(_) => find(verb, declared).is_none_or(|v| v.in_tests),
+        _ => true,
+    }
+}
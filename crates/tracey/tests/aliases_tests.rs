@@ -0,0 +1,55 @@
+//! Tests for rule ID aliases declared with `replaces`.
+
+mod common;
+
+use std::fs;
+
+use common::{AUTH_SPEC, write_auth_project};
+use tracey::check::{CheckOptions, check};
+use tracey::data::{ConfigFile, ValidationErrorCode};
+
+#[tokio::test]
+async fn replaced_ids_resolve_to_the_new_rule() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(
+        tmp.path(),
+        "// r[impl auth.login]\nfn login() {}\n\n// r[impl auth.token]\nfn session() {}\n",
+    );
+    fs::write(
+        tmp.path().join("spec.md"),
+        AUTH_SPEC.replace("r[auth.session]", "r[auth.session replaces=auth.token]"),
+    )
+    .unwrap();
+
+    let report = check(
+        tmp.path(),
+        &common::simple_config(),
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(report.impls[0].stats.impl_covered, 2);
+    assert_eq!(report.impls[0].validation_errors, 0);
+
+    let data = tracey::data::build_dashboard_data(tmp.path(), &common::simple_config(), 1, true)
+        .await
+        .unwrap();
+    let forward = data.forward_by_impl.values().next().unwrap();
+    let session = forward
+        .rules
+        .iter()
+        .find(|r| r.id.base == "auth.session")
+        .unwrap();
+    assert_eq!(session.renamed_refs.len(), 1);
+    assert_eq!(session.renamed_refs[0].reference_id.base, "auth.token");
+
+    let validation = data.validation_by_impl.values().next().unwrap();
+    assert_eq!(validation.warning_count, 1);
+    let renamed = &validation.errors[0];
+    assert_eq!(renamed.code, ValidationErrorCode::RenamedRequirement);
+    assert_eq!(
+        renamed.message,
+        "Rule 'auth.token' was renamed to 'auth.session'; update the reference"
+    );
+}
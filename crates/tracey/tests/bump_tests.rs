@@ -64,7 +64,16 @@ fn simple_config() -> Config {
             source_url: None,
             include: vec!["spec.md".to_string()],
             impls: vec![],
+            impls_from: None,
+            thresholds: None,
+            prefix_thresholds: vec![],
+            exclude_statuses: vec![],
+            removed: vec![],
+            git: None,
+            propagate_stale: false,
+            owners: vec![],
         }],
+        verbs: vec![],
     }
 }
 
@@ -344,7 +353,10 @@ async fn test_no_config_is_noop() {
     fs::write(root.join("spec.md"), &modified).unwrap();
     git_add(root, "spec.md");
 
-    let empty_config = Config { specs: vec![] };
+    let empty_config = Config {
+        specs: vec![],
+        verbs: vec![],
+    };
     let changes = detect_changed_rules(root, &empty_config).await.unwrap();
     assert!(changes.is_empty(), "empty config should produce no changes");
 }
@@ -428,7 +440,16 @@ async fn test_renamed_spec_file_not_flagged() {
             source_url: None,
             include: vec!["**/*.md".to_string()],
             impls: vec![],
+            impls_from: None,
+            thresholds: None,
+            prefix_thresholds: vec![],
+            exclude_statuses: vec![],
+            removed: vec![],
+            git: None,
+            propagate_stale: false,
+            owners: vec![],
         }],
+        verbs: vec![],
     };
     let changes = detect_changed_rules(root, &wildcard_config).await.unwrap();
     assert!(
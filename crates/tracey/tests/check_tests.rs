@@ -0,0 +1,118 @@
+//! Integration tests for `tracey check`.
+//!
+//! Each test writes a small project to a temp directory and runs the check
+//! logic in-process via the library API, without a daemon.
+
+mod common;
+
+use common::write_auth_project;
+use tracey::check::{CheckOptions, check};
+use tracey::data::ConfigFile;
+
+#[tokio::test]
+async fn passes_without_thresholds() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+
+    let report = check(
+        tmp.path(),
+        &common::simple_config(),
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(report.impls.len(), 1);
+    assert!(report.passed());
+}
+
+#[tokio::test]
+async fn fails_below_min_impl() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+
+    let options = CheckOptions {
+        min_impl: Some(80.0),
+        ..Default::default()
+    };
+    let report = check(
+        tmp.path(),
+        &common::simple_config(),
+        &ConfigFile::default(),
+        &options,
+    )
+    .await
+    .unwrap();
+
+    assert!(!report.passed());
+    assert_eq!(report.impls[0].failures.len(), 1);
+    assert!(report.impls[0].failures[0].contains("impl coverage"));
+
+    let options = CheckOptions {
+        min_impl: Some(50.0),
+        ..Default::default()
+    };
+    let report = check(
+        tmp.path(),
+        &common::simple_config(),
+        &ConfigFile::default(),
+        &options,
+    )
+    .await
+    .unwrap();
+    assert!(report.passed());
+}
+
+#[tokio::test]
+async fn fails_below_min_verify() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+
+    let options = CheckOptions {
+        min_verify: Some(1.0),
+        ..Default::default()
+    };
+    let report = check(
+        tmp.path(),
+        &common::simple_config(),
+        &ConfigFile::default(),
+        &options,
+    )
+    .await
+    .unwrap();
+
+    assert!(!report.passed());
+    assert!(report.impls[0].failures[0].contains("verify coverage"));
+}
+
+#[tokio::test]
+async fn fail_on_validation_errors() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.nonexistent]\nfn nope() {}\n");
+
+    let report = check(
+        tmp.path(),
+        &common::simple_config(),
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert!(report.passed());
+    assert!(report.impls[0].validation_errors > 0);
+
+    let options = CheckOptions {
+        fail_on_validation_errors: true,
+        ..Default::default()
+    };
+    let report = check(
+        tmp.path(),
+        &common::simple_config(),
+        &ConfigFile::default(),
+        &options,
+    )
+    .await
+    .unwrap();
+    assert!(!report.passed());
+}
@@ -2,13 +2,15 @@
 
 #![allow(dead_code)]
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use tokio::task::JoinHandle;
 
 use roam_memory::memory_transport_pair;
 use roam_stream::{
     ConnectionHandle, HandshakeConfig, NoDispatcher, accept_framed, initiate_framed,
 };
+use tracey::config::{Config, Impl, SpecConfig};
 use tracey_proto::{TraceyDaemonClient, TraceyDaemonDispatcher};
 
 pub struct RpcTestService {
@@ -91,3 +93,88 @@ pub fn create_temp_project() -> tempfile::TempDir {
 
     temp
 }
+
+/// Write `content` to `path` under `root`, creating parent directories.
+pub fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).expect("Failed to create dir");
+    std::fs::write(&path, content).expect("Failed to write file");
+}
+
+/// Write a project's files, given as `(path, content)` pairs.
+pub fn write_project(root: &Path, files: &[(&str, &str)]) {
+    for (path, content) in files {
+        write(root, path, content);
+    }
+}
+
+/// A spec with two `auth` rules, for tests that only need a little coverage.
+pub const AUTH_SPEC: &str = "\
+# Spec
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+";
+
+/// Write [`AUTH_SPEC`] as `spec.md` and `source` as `src/lib.rs`.
+pub fn write_auth_project(root: &Path, source: &str) {
+    write_project(root, &[("spec.md", AUTH_SPEC), ("src/lib.rs", source)]);
+}
+
+/// Run git in `dir`, failing the test if it fails.
+pub fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .expect("git not found");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// Create a git repository in `dir` with a committer configured.
+pub fn git_init(dir: &Path) {
+    git(dir, &["init", "--initial-branch=main"]);
+    git(dir, &["config", "user.email", "test@example.com"]);
+    git(dir, &["config", "user.name", "Test"]);
+}
+
+/// Stage and commit everything in the repository.
+pub fn commit_all(dir: &Path, message: &str) {
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "-m", message]);
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+/// The `rust` implementation, scanning `include` with tests in `test_include`.
+pub fn rust_impl(include: &[&str], test_include: &[&str]) -> Impl {
+    Impl {
+        name: "rust".to_string(),
+        include: strings(include),
+        test_include: strings(test_include),
+        ..Default::default()
+    }
+}
+
+/// The `test` spec, read from `spec.md`.
+pub fn test_spec(impls: Vec<Impl>) -> SpecConfig {
+    SpecConfig {
+        name: "test".to_string(),
+        include: strings(&["spec.md"]),
+        impls,
+        ..Default::default()
+    }
+}
+
+/// A config with the `test` spec implemented by `rust` sources in `src/`.
+pub fn simple_config() -> Config {
+    Config {
+        specs: vec![test_spec(vec![rust_impl(&["src/**/*.rs"], &[])])],
+        ..Default::default()
+    }
+}
@@ -0,0 +1,94 @@
+//! Tests for `depends` edges between rules, within and across specs.
+
+mod common;
+
+use std::fs;
+
+use common::{AUTH_SPEC, write_auth_project};
+use tracey::data::ValidationErrorCode;
+
+#[tokio::test]
+async fn cross_spec_dependencies() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+    fs::write(
+        tmp.path().join("spec.md"),
+        AUTH_SPEC.replace(
+            "r[auth.session]",
+            "r[auth.session depends=auth.login,t[transport.framing]]",
+        ),
+    )
+    .unwrap();
+    fs::write(
+        tmp.path().join("transport.md"),
+        "# Transport\n\nt[transport.framing depends=r[auth.session]]\n\
+         Frames MUST carry a length.\n\n\
+         t[transport.close depends=t[transport.missing]]\nConnections MAY close.\n",
+    )
+    .unwrap();
+    fs::write(
+        tmp.path().join("src/transport.rs"),
+        "// r[depends t[transport.framing]]\nfn frame() {}\n",
+    )
+    .unwrap();
+
+    let mut config = common::simple_config();
+    let mut transport = config.specs[0].clone();
+    transport.name = "transport".to_string();
+    transport.include = vec!["transport.md".to_string()];
+    config.specs.push(transport);
+
+    let data = tracey::data::build_dashboard_data(tmp.path(), &config, 1, true)
+        .await
+        .unwrap();
+    let key = |spec: &str| (spec.to_string(), "rust".to_string());
+    let session = data.forward_by_impl[&key("test")]
+        .rules
+        .iter()
+        .find(|r| r.id.base == "auth.session")
+        .unwrap();
+    let upstream: Vec<_> = session
+        .depends_on
+        .iter()
+        .map(|l| (l.spec.as_deref(), l.id.base.as_str()))
+        .collect();
+    assert_eq!(
+        upstream,
+        [
+            (Some("test"), "auth.login"),
+            (Some("transport"), "transport.framing")
+        ]
+    );
+    let framing = data.forward_by_impl[&key("transport")]
+        .rules
+        .iter()
+        .find(|r| r.id.base == "transport.framing")
+        .unwrap();
+    assert_eq!(framing.dependents.len(), 1);
+    assert_eq!(framing.dependents[0].spec.as_deref(), Some("test"));
+    // The qualified code reference counts for the transport spec.
+    assert_eq!(framing.depends_refs.len(), 1);
+
+    for spec in ["test", "transport"] {
+        let validation = &data.validation_by_impl[&key(spec)];
+        let cycle = validation
+            .errors
+            .iter()
+            .find(|e| e.code == ValidationErrorCode::CircularDependency)
+            .unwrap();
+        assert!(
+            cycle
+                .message
+                .contains("r[auth.session] -> t[transport.framing]")
+        );
+    }
+    let unknown = data.validation_by_impl[&key("transport")]
+        .errors
+        .iter()
+        .find(|e| e.code == ValidationErrorCode::UnknownRequirement)
+        .unwrap();
+    assert_eq!(
+        unknown.message,
+        "Rule 'transport.close' depends on unknown rule 't[transport.missing]'"
+    );
+}
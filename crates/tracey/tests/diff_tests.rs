@@ -0,0 +1,115 @@
+//! Integration tests for `tracey diff`.
+//!
+//! Each test creates a real git repository in a temp directory with two
+//! commits, then diffs them via the library API. The worktree is modified
+//! after committing to check that only committed content is analyzed.
+
+mod common;
+
+use std::fs;
+
+use common::{commit_all, git_init};
+use tracey::diff::{diff, resolve_range};
+
+const BASE_SPEC: &str = "\
+# Spec
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+
+r[auth.legacy]
+Legacy tokens MUST be accepted.
+";
+
+const HEAD_SPEC: &str = "\
+# Spec
+
+r[auth.login+2]
+Users MUST provide valid credentials and a second factor to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+
+r[auth.logout]
+Users MUST be able to log out.
+";
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn reports_rule_and_coverage_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    git_init(root);
+
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("spec.md"), BASE_SPEC).unwrap();
+    fs::write(
+        root.join("src/lib.rs"),
+        "// r[impl auth.login]\nfn login() {}\n\n// r[impl auth.legacy]\nfn legacy() {}\n",
+    )
+    .unwrap();
+    commit_all(root, "base");
+
+    fs::write(root.join("spec.md"), HEAD_SPEC).unwrap();
+    fs::write(
+        root.join("src/lib.rs"),
+        "// r[impl auth.login]\nfn login() {}\n\n// r[impl auth.session]\nfn session() {}\n",
+    )
+    .unwrap();
+    commit_all(root, "head");
+
+    // Uncommitted changes must not affect the result.
+    fs::write(root.join("src/lib.rs"), "fn nothing() {}\n").unwrap();
+
+    let (base, head) = resolve_range(root, "HEAD~1..HEAD").unwrap();
+    let result = diff(
+        root,
+        &root.join(".config/tracey/config.styx"),
+        &common::simple_config(),
+        &base,
+        &head,
+    )
+    .await
+    .unwrap();
+
+    let delta = &result.delta.by_impl["test/rust"];
+    let ids = |ids: &[tracey_core::RuleId]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(ids(&delta.added_rules), ["auth.logout"]);
+    assert_eq!(ids(&delta.removed_rules), ["auth.legacy"]);
+    assert_eq!(delta.bumped_rules.len(), 1);
+    assert_eq!(delta.bumped_rules[0].1.to_string(), "auth.login+2");
+    assert_eq!(ids(&delta.newly_stale), ["auth.login+2"]);
+    assert!(
+        delta
+            .newly_covered
+            .iter()
+            .any(|c| c.rule_id.to_string() == "auth.session" && c.ref_type == "impl")
+    );
+
+    let markdown = result.format_markdown();
+    assert!(markdown.contains("### test/rust"));
+    assert!(markdown.contains("**Newly stale** (1)"));
+}
+
+#[tokio::test]
+async fn single_revision_compares_against_head() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    git_init(root);
+
+    fs::write(root.join("spec.md"), BASE_SPEC).unwrap();
+    commit_all(root, "base");
+
+    let (base, head) = resolve_range(root, "main").unwrap();
+    assert_eq!(base, "main");
+    assert_eq!(head, "HEAD");
+
+    assert!(resolve_range(root, "does-not-exist..HEAD").is_err());
+}
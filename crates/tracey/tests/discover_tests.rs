@@ -0,0 +1,290 @@
+//! Integration tests for implementations discovered from workspace manifests.
+//!
+//! Each test writes a small workspace to a temp directory and expands or
+//! builds its config via the library API.
+
+mod common;
+
+use std::path::Path;
+use std::sync::Arc;
+
+use common::write;
+use tracey::config::{Config, Impl, SpecConfig};
+use tracey::data::build_dashboard_data;
+use tracey::discover::{expand, manifests};
+use tracey_proto::ConfigPatternRequest;
+
+const SPEC: &str = "\
+# Spec
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+";
+
+fn config(impls_from: &str, impls: Vec<Impl>) -> Config {
+    Config {
+        specs: vec![SpecConfig {
+            impls_from: Some(impls_from.to_string()),
+            ..common::test_spec(impls)
+        }],
+        ..Default::default()
+    }
+}
+
+fn impls_of(config: &Config) -> Vec<(&str, Vec<&str>, Vec<&str>)> {
+    config.specs[0]
+        .impls
+        .iter()
+        .map(|i| {
+            (
+                i.name.as_str(),
+                i.include.iter().map(String::as_str).collect(),
+                i.test_include.iter().map(String::as_str).collect(),
+            )
+        })
+        .collect()
+}
+
+fn write_cargo_workspace(root: &Path) {
+    write(root, "spec.md", SPEC);
+    write(
+        root,
+        "Cargo.toml",
+        "[workspace]\n\
+         members = [\n    \"crates/*\", # every crate\n]\n\
+         exclude = [\"crates/old\"]\n",
+    );
+    write(
+        root,
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"app-core\"\n\n[dependencies]\n",
+    );
+    write(
+        root,
+        "crates/core/src/lib.rs",
+        "// r[impl auth.login]\nfn login() {}\n",
+    );
+    write(
+        root,
+        "crates/core/tests/login.rs",
+        "// r[verify auth.login]\nfn test_login() {}\n",
+    );
+    write(
+        root,
+        "crates/cli/Cargo.toml",
+        "[package]\nname = \"app-cli\"\n",
+    );
+    write(
+        root,
+        "crates/cli/src/main.rs",
+        "// r[impl auth.session]\nfn main() {}\n",
+    );
+    write(
+        root,
+        "crates/old/Cargo.toml",
+        "[package]\nname = \"app-old\"\n",
+    );
+}
+
+#[tokio::test]
+async fn cargo_members_become_impls() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_cargo_workspace(tmp.path());
+
+    // A configured impl takes precedence over the discovered one.
+    let cli = Impl {
+        name: "app-cli".to_string(),
+        include: vec!["crates/cli/src/main.rs".to_string()],
+        ..Default::default()
+    };
+    let config = config("cargo", vec![cli]);
+
+    let expanded = expand(&config, tmp.path()).unwrap();
+    assert_eq!(
+        impls_of(&expanded),
+        [
+            ("app-cli", vec!["crates/cli/src/main.rs"], vec![]),
+            (
+                "app-core",
+                vec![
+                    "crates/core/src/**",
+                    "crates/core/tests/**",
+                    "crates/core/benches/**"
+                ],
+                vec!["crates/core/tests/**", "crates/core/benches/**"]
+            ),
+        ]
+    );
+    assert_eq!(
+        impls_of(&expand(&expanded, tmp.path()).unwrap()),
+        impls_of(&expanded)
+    );
+
+    let watched: Vec<_> = manifests(&config, tmp.path())
+        .into_iter()
+        .map(|p| p.strip_prefix(tmp.path()).unwrap().to_path_buf())
+        .collect();
+    assert_eq!(
+        watched,
+        [
+            Path::new("Cargo.toml"),
+            Path::new("crates/cli/Cargo.toml"),
+            Path::new("crates/core/Cargo.toml"),
+        ]
+    );
+
+    // Builds take the expanded config as is.
+    let data = build_dashboard_data(tmp.path(), &expanded, 1, true)
+        .await
+        .unwrap();
+    assert_eq!(
+        data.config.specs[0].implementations,
+        ["app-cli", "app-core"]
+    );
+    let core = &data.forward_by_impl[&("test".to_string(), "app-core".to_string())];
+    let login = core
+        .rules
+        .iter()
+        .find(|r| r.id.base == "auth.login")
+        .unwrap();
+    assert_eq!(login.impl_refs.len(), 1);
+    assert_eq!(login.verify_refs.len(), 1);
+}
+
+#[test]
+fn npm_and_go_workspaces_become_impls() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    write(
+        root,
+        "package.json",
+        r#"{"name": "monorepo", "workspaces": ["packages/*", "!packages/legacy"]}"#,
+    );
+    write(
+        root,
+        "packages/web/package.json",
+        r#"{"name": "@shop/web"}"#,
+    );
+    write(
+        root,
+        "packages/legacy/package.json",
+        r#"{"name": "legacy"}"#,
+    );
+    write(
+        root,
+        "go.work",
+        "go 1.22\n\nuse (\n\t./services/cart\n\t./tools // linters\n)\n",
+    );
+    write(
+        root,
+        "services/cart/go.mod",
+        "module example.com/shop/cart/v2\n\ngo 1.22\n",
+    );
+    write(root, "tools/go.mod", "module example.com/shop/tools\n");
+
+    let npm = expand(&config("npm", vec![]), root).unwrap();
+    assert_eq!(
+        impls_of(&npm),
+        [(
+            "@shop/web",
+            vec![
+                "packages/web/src/**",
+                "packages/web/test/**",
+                "packages/web/tests/**"
+            ],
+            vec![
+                "packages/web/test/**",
+                "packages/web/tests/**",
+                "packages/web/src/**/*.test.*",
+                "packages/web/src/**/*.spec.*"
+            ]
+        )]
+    );
+
+    let go = expand(&config("go", vec![]), root).unwrap();
+    assert_eq!(
+        impls_of(&go),
+        [
+            (
+                "cart",
+                vec!["services/cart/**"],
+                vec!["services/cart/**/*_test.go"]
+            ),
+            ("tools", vec!["tools/**"], vec!["tools/**/*_test.go"]),
+        ]
+    );
+}
+
+#[test]
+fn invalid_discovery_is_rejected() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+
+    // No manifest to discover from
+    assert!(expand(&config("cargo", vec![]), root).is_err());
+
+    write(
+        root,
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"a\", \"b\"]\n",
+    );
+    write(root, "a/Cargo.toml", "[package]\nname = \"same\"\n");
+    write(root, "b/Cargo.toml", "[package]\nname = \"same\"\n");
+    assert!(expand(&config("cargo", vec![]), root).is_err());
+    assert!(expand(&config("maven", vec![]), root).is_err());
+}
+
+#[tokio::test]
+async fn config_edits_override_discovered_impls() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    write_cargo_workspace(root);
+    write(
+        root,
+        ".config/tracey/config.styx",
+        "specs (\n  {\n    name test\n    include (spec.md)\n    impls_from cargo\n  }\n)\n",
+    );
+
+    let config_path = root.join(".config/tracey/config.styx");
+    let engine = Arc::new(
+        tracey::daemon::Engine::new(root.to_path_buf(), config_path.clone())
+            .await
+            .unwrap(),
+    );
+    let rpc = common::create_test_rpc_service(tracey::daemon::TraceyService::new(engine)).await;
+
+    rpc.client
+        .config_add_exclude(ConfigPatternRequest {
+            spec: Some("test".to_string()),
+            impl_name: Some("app-core".to_string()),
+            pattern: "crates/core/src/gen/**".to_string(),
+        })
+        .await
+        .unwrap();
+    let err = rpc
+        .client
+        .config_add_exclude(ConfigPatternRequest {
+            spec: Some("test".to_string()),
+            impl_name: Some("app-old".to_string()),
+            pattern: "**".to_string(),
+        })
+        .await
+        .unwrap_err();
+    assert!(format!("{err:?}").contains("app-old"), "{err:?}");
+
+    // The discovered impl is written out as an entry of its own
+    let saved = tracey::load_config(&config_path).unwrap();
+    let core = &saved.specs[0].impls[0];
+    assert_eq!(core.name, "app-core");
+    assert_eq!(core.exclude, ["crates/core/src/gen/**"]);
+    assert_eq!(
+        impls_of(&expand(&saved, root).unwrap())
+            .iter()
+            .map(|i| i.0)
+            .collect::<Vec<_>>(),
+        ["app-core", "app-cli"]
+    );
+}
@@ -0,0 +1,220 @@
+//! Integration tests for `tracey export`.
+//!
+//! Each test writes a small project to a temp directory, builds dashboard data
+//! in-process and renders it via the library API.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use tracey::badge::{BadgeFormat, BadgeMetric, badge_for};
+use tracey::config::Config;
+use tracey::data::ConfigFile;
+use tracey::data::{DashboardData, build_dashboard_data};
+use tracey::junit::{JunitOptions, render_junit};
+use tracey::matrix::{MatrixFormat, MatrixOptions, render_matrix};
+use tracey::site::export_site;
+
+const SPEC: &str = "\
+# Spec
+
+## Auth
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+
+## Storage
+
+r[storage.encrypt]
+Data MUST be encrypted at rest.
+";
+
+const SOURCE: &str = "\
+// r[impl auth.login]
+fn login() {}
+
+// r[impl auth.session]
+fn session() {}
+";
+
+const TESTS: &str = "\
+// r[verify auth.login]
+fn test_login() {}
+";
+
+fn config() -> Config {
+    Config {
+        specs: vec![common::test_spec(vec![common::rust_impl(
+            &["src/**/*.rs"],
+            &["src/tests.rs"],
+        )])],
+        ..Default::default()
+    }
+}
+
+async fn build(root: &Path) -> DashboardData {
+    common::write_project(
+        root,
+        &[
+            ("spec.md", SPEC),
+            ("src/lib.rs", SOURCE),
+            ("src/tests.rs", TESTS),
+        ],
+    );
+    build_dashboard_data(root, &config(), 1, true)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn junit_groups_by_section_and_classifies_rules() {
+    let tmp = tempfile::tempdir().unwrap();
+    let data = build(tmp.path()).await;
+
+    let xml = render_junit(&data, &JunitOptions::default());
+
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains("tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"1\""));
+    assert!(xml.contains("<testsuite name=\"test/rust: Auth\""));
+    assert!(xml.contains("<testsuite name=\"test/rust: Storage\""));
+    assert!(xml.contains("<failure type=\"uncovered\""));
+    assert!(xml.contains("<skipped message=\"No verification references\"/>"));
+}
+
+#[tokio::test]
+async fn junit_fail_untested() {
+    let tmp = tempfile::tempdir().unwrap();
+    let data = build(tmp.path()).await;
+
+    let xml = render_junit(
+        &data,
+        &JunitOptions {
+            fail_untested: true,
+        },
+    );
+
+    assert!(xml.contains("tests=\"3\" failures=\"2\" errors=\"0\" skipped=\"0\""));
+    assert!(xml.contains("<failure type=\"untested\""));
+}
+
+#[tokio::test]
+async fn junit_skips_excluded_rules() {
+    let tmp = tempfile::tempdir().unwrap();
+    build(tmp.path()).await;
+    fs::write(
+        tmp.path().join("spec.md"),
+        SPEC.replace("r[storage.encrypt]", "r[storage.encrypt status=deprecated]"),
+    )
+    .unwrap();
+    let data = build_dashboard_data(tmp.path(), &config(), 1, true)
+        .await
+        .unwrap();
+
+    let xml = render_junit(&data, &JunitOptions::default());
+
+    assert!(xml.contains("tests=\"3\" failures=\"0\" errors=\"0\" skipped=\"2\""));
+    assert!(!xml.contains("<failure"));
+    assert!(xml.contains("<skipped message=\"Excluded from coverage (status: deprecated)\"/>"));
+}
+
+#[tokio::test]
+async fn matrix_lists_references_per_impl() {
+    let tmp = tempfile::tempdir().unwrap();
+    let data = build(tmp.path()).await;
+
+    let csv = render_matrix(&data, &MatrixOptions::default());
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "spec,id,version,section,level,status,rust impl,rust verify"
+    );
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1].starts_with("test,auth.login,1,Auth,"));
+    assert!(lines[1].ends_with(",src/lib.rs:1,src/tests.rs:1"));
+    assert!(lines[3].starts_with("test,storage.encrypt,1,Storage,"));
+    assert!(lines[3].ends_with(",,"));
+}
+
+#[tokio::test]
+async fn matrix_tsv_with_text() {
+    let tmp = tempfile::tempdir().unwrap();
+    let data = build(tmp.path()).await;
+
+    let tsv = render_matrix(
+        &data,
+        &MatrixOptions {
+            format: MatrixFormat::Tsv,
+            include_text: true,
+            include_stale: true,
+            include_depends: false,
+        },
+    );
+    let lines: Vec<&str> = tsv.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "spec\tid\tversion\tsection\tlevel\tstatus\ttext\trust impl\trust verify\trust stale"
+    );
+    assert!(lines[2].contains("\tSessions MUST expire after 24 hours of inactivity.\t"));
+}
+
+#[tokio::test]
+async fn badges_use_report_color_thresholds() {
+    let tmp = tempfile::tempdir().unwrap();
+    let data = build(tmp.path()).await;
+
+    // 2 of 3 rules implemented, 1 of 3 verified.
+    let impl_badge = badge_for(&data, None, None, BadgeMetric::Impl).unwrap();
+    assert_eq!(impl_badge.message(), "66.7%");
+    let svg = impl_badge.render(BadgeFormat::Svg);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("impl coverage: 66.7%"));
+    assert!(svg.contains("#dfb317"));
+
+    let verify_badge = badge_for(&data, Some("test"), Some("rust"), BadgeMetric::Verify).unwrap();
+    let json = verify_badge.render(BadgeFormat::Json);
+    assert!(json.contains("\"schemaVersion\":1"));
+    assert!(json.contains("\"message\":\"33.3%\""));
+    assert!(json.contains("\"color\":\"red\""));
+
+    assert!(badge_for(&data, Some("missing"), None, BadgeMetric::Impl).is_err());
+}
+
+#[tokio::test]
+async fn site_writes_api_responses() {
+    let tmp = tempfile::tempdir().unwrap();
+    build(tmp.path()).await;
+    let out = tmp.path().join("site");
+
+    let summary = export_site(
+        tmp.path(),
+        &config(),
+        &ConfigFile::default(),
+        &out,
+        "/docs/coverage",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(summary.impls, 1);
+    assert_eq!(summary.files, 2);
+    let index = fs::read_to_string(out.join("index.html")).unwrap();
+    assert!(index.contains("window.__TRACEY_STATIC__ = true"));
+    // The landing page resolves its files relative to itself; deep links
+    // served through 404.html resolve them from the base URL.
+    assert!(!index.contains("<base"));
+    let not_found = fs::read_to_string(out.join("404.html")).unwrap();
+    assert!(not_found.contains(r#"<base href="/docs/coverage/">"#));
+    assert!(out.join("api/config.json").exists());
+    for endpoint in ["forward", "reverse", "spec"] {
+        assert!(out.join(format!("api/{endpoint}/test/rust.json")).exists());
+    }
+    let files = fs::read_to_string(out.join("api/file/test/rust/index.json")).unwrap();
+    assert!(files.contains("src/lib.rs"));
+    assert!(out.join("api/file/test/rust/0.json").exists());
+}
@@ -0,0 +1,75 @@
+//! Integration tests for the coverage history.
+//!
+//! Each test creates a real git repository in a temp directory, records
+//! commits via the library API and reads the history back.
+
+mod common;
+
+use std::fs;
+
+use common::{commit_all, git_init};
+use tracey::history::{format_trend, is_recorded, load, record, resolve_commits};
+
+const SPEC: &str = "\
+# Spec
+
+## Auth
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+";
+
+#[tokio::test]
+async fn records_commits_and_reports_trend() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    git_init(root);
+
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("spec.md"), SPEC).unwrap();
+    fs::write(root.join("src/lib.rs"), "fn nothing() {}\n").unwrap();
+    commit_all(root, "first");
+    fs::write(
+        root.join("src/lib.rs"),
+        "// r[impl auth.login]\nfn login() {}\n",
+    )
+    .unwrap();
+    commit_all(root, "second");
+
+    let config_path = root.join(".config/tracey/config.styx");
+    let commits = resolve_commits(root, "HEAD~1..HEAD").unwrap();
+    assert_eq!(commits.len(), 1);
+    let mut commits = resolve_commits(root, "HEAD~1").unwrap();
+    commits.extend(resolve_commits(root, "HEAD~1..HEAD").unwrap());
+    for commit in &commits {
+        record(root, &config_path, &common::simple_config(), commit)
+            .await
+            .unwrap();
+        assert!(is_recorded(root, commit));
+    }
+
+    let history = load(root, None, None, None).unwrap();
+    assert_eq!(history.entries.len(), 2);
+    assert_eq!(history.entries[0].subject, "first");
+    let last = &history.entries[1].impls[0];
+    assert_eq!(last.stats.total_rules, 2);
+    assert_eq!(last.stats.impl_covered, 1);
+    assert_eq!(last.sections[0].title, "Auth");
+
+    let trend = format_trend(&history);
+    assert!(trend.contains("## test/rust"));
+    assert!(trend.contains("Over 2 commits: +0 rules, +1 implemented"));
+
+    let limited = load(root, Some("test"), Some("rust"), Some(1)).unwrap();
+    assert_eq!(limited.entries.len(), 1);
+    assert_eq!(limited.entries[0].subject, "second");
+    assert!(
+        load(root, Some("other"), None, None)
+            .unwrap()
+            .entries
+            .is_empty()
+    );
+}
@@ -0,0 +1,172 @@
+//! Integration tests for `tracey impact`.
+//!
+//! Each test creates a real git repository in a temp directory, changes a
+//! file and maps the changed lines onto requirements via the library API.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::{commit_all, git, git_init};
+use tracey::config::Config;
+use tracey::data::build_dashboard_data;
+use tracey::diff::build_at_revision;
+use tracey::impact::{ChangeSource, analyze, changed_lines, changed_revision, parse_diff};
+
+fn config() -> Config {
+    Config {
+        specs: vec![common::test_spec(vec![common::rust_impl(
+            &["src/**/*.rs", "tests/**/*.rs"],
+            &[],
+        )])],
+        ..Default::default()
+    }
+}
+
+const SPEC: &str = "\
+# Spec
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+";
+
+const LIB: &str = "\
+// r[impl auth.login]
+fn login() {
+    check();
+}
+
+// r[impl auth.session]
+fn session() {}
+
+fn helper() {}
+";
+
+#[test]
+fn parses_new_side_of_hunks() {
+    let diff = "\
+diff --git src/a.rs src/a.rs
+--- src/a.rs
++++ src/a.rs
+@@ -3 +3,2 @@ fn a() {
+-old
+++++ added line that looks like a header
++new
+@@ -10,2 +11,0 @@
+diff --git src/gone.rs src/gone.rs
+--- src/gone.rs
++++ /dev/null
+@@ -1,3 +0,0 @@
+";
+    let files = parse_diff(diff);
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, "src/a.rs");
+    let ranges: Vec<(usize, usize)> = files[0].ranges.iter().map(|r| (r.start, r.end)).collect();
+    assert_eq!(ranges, [(3, 4), (11, 11)]);
+}
+
+#[tokio::test]
+async fn maps_changed_lines_to_rules_and_tests() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    git_init(root);
+
+    common::write_project(
+        root,
+        &[
+            ("spec.md", SPEC),
+            ("src/lib.rs", LIB),
+            (
+                "tests/login.rs",
+                "// r[verify auth.login]\nfn test_login() {}\n",
+            ),
+        ],
+    );
+    commit_all(root, "initial");
+
+    fs::write(
+        root.join("src/lib.rs"),
+        LIB.replace("    check();", "    check_harder();")
+            .replace("fn helper() {}", "fn helper() { todo!() }"),
+    )
+    .unwrap();
+
+    let files = changed_lines(root, &ChangeSource::WorkingTree).unwrap();
+    assert_eq!(files.len(), 1);
+    let data = build_dashboard_data(root, &config(), 1, true)
+        .await
+        .unwrap();
+    let impact = analyze(&data, &files);
+
+    let ids: Vec<String> = impact.rules.iter().map(|r| r.id.to_string()).collect();
+    assert_eq!(ids, ["auth.login"]);
+    let login = &impact.rules[0];
+    assert_eq!(login.units[0].name.as_deref(), Some("login"));
+    assert_eq!(login.verify_refs.len(), 1);
+    assert_eq!(login.verify_refs[0].file, "tests/login.rs");
+
+    let unmapped: Vec<Option<&str>> = impact
+        .unmapped_units
+        .iter()
+        .map(|u| u.name.as_deref())
+        .collect();
+    assert_eq!(unmapped, [Some("helper")]);
+
+    assert!(
+        changed_lines(root, &ChangeSource::Staged)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+/// Rules touched by `source`, analyzed at the revision its lines refer to.
+async fn impacted_at_revision(root: &Path, source: ChangeSource) -> Vec<String> {
+    let files = changed_lines(root, &source).unwrap();
+    let revision = changed_revision(root, &source).unwrap().unwrap();
+    let config_path = root.join(".config/tracey/config.styx");
+    let data = build_at_revision(root, &config_path, &config(), &revision)
+        .await
+        .unwrap();
+    let impact = analyze(&data, &files);
+    impact.rules.iter().map(|r| r.id.to_string()).collect()
+}
+
+#[tokio::test]
+async fn maps_staged_and_committed_lines_onto_their_revision() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    git_init(root);
+
+    common::write_project(root, &[("spec.md", SPEC), ("src/lib.rs", LIB)]);
+    commit_all(root, "initial");
+
+    let staged = LIB.replace("fn session() {}", "fn session() { expire(); }");
+    fs::write(root.join("src/lib.rs"), &staged).unwrap();
+    git(root, &["add", "."]);
+    // Unstaged lines shift the staged change onto `login` in the worktree.
+    fs::write(
+        root.join("src/lib.rs"),
+        format!("// a\n// b\n// c\n{staged}"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        impacted_at_revision(root, ChangeSource::Staged).await,
+        ["auth.session"]
+    );
+
+    git(root, &["commit", "-m", "expire sessions"]);
+    assert_eq!(
+        impacted_at_revision(root, ChangeSource::Range("HEAD~1..HEAD".to_string())).await,
+        ["auth.session"]
+    );
+    assert!(
+        changed_revision(root, &ChangeSource::WorkingTree)
+            .unwrap()
+            .is_none()
+    );
+}
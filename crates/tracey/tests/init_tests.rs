@@ -0,0 +1,87 @@
+//! Integration tests for `tracey init`.
+//!
+//! Each test lays out a small project in a temp directory and checks the
+//! proposed config via the library API.
+
+use std::fs;
+use std::path::Path;
+
+use tracey::init::{propose, validate};
+
+fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[tokio::test]
+async fn proposes_specs_impls_and_test_globs() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    write(
+        root,
+        "docs/spec/auth.md",
+        "# Auth\n\nr[auth.login]\nUsers MUST log in.\n",
+    );
+    write(
+        root,
+        "docs/api.md",
+        "# API\n\napi[api.list]\nThe API MUST list items.\n",
+    );
+    write(root, "README.md", "# Project\n");
+    write(root, "src/lib.rs", "// r[impl auth.login]\nfn login() {}\n");
+    write(
+        root,
+        "tests/login.rs",
+        "// r[verify auth.login]\nfn test_login() {}\n",
+    );
+    write(
+        root,
+        "web/app.ts",
+        "// api[impl api.list]\nexport function list() {}\n",
+    );
+    write(
+        root,
+        "web/app.spec.ts",
+        "// api[verify api.list]\ntest(\"list\", () => {});\n",
+    );
+    // Fixtures hold `impl` references, so `tests/` must not be proposed for Go.
+    write(
+        root,
+        "go/tests/fixture.go",
+        "// r[impl auth.login]\npackage fixture\n",
+    );
+    write(
+        root,
+        "go/login_test.go",
+        "// r[verify auth.login]\npackage login\n",
+    );
+
+    let proposal = propose(root).await.unwrap();
+
+    let names: Vec<&str> = proposal.specs.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&"api"));
+
+    let r = proposal.specs.iter().find(|s| s.prefix == "r").unwrap();
+    assert_eq!(r.include, ["docs/spec/**/*.md"]);
+    let api = proposal.specs.iter().find(|s| s.prefix == "api").unwrap();
+    assert_eq!(api.include, ["docs/api.md"]);
+
+    let impl_names: Vec<&str> = r.impls.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(impl_names, ["go", "rust", "typescript"]);
+
+    let go = &r.impls[0];
+    assert_eq!(go.test_include, ["**/*_test.go"]);
+    let rust = &r.impls[1];
+    assert_eq!(rust.include, ["src/**/*.rs", "tests/**/*.rs"]);
+    assert_eq!(rust.test_include, ["**/tests/**/*.rs"]);
+    let typescript = &r.impls[2];
+    assert_eq!(typescript.include, ["web/**/*.ts"]);
+    assert_eq!(typescript.test_include, ["**/*.spec.ts"]);
+
+    let text = proposal.render();
+    let config = validate(&text).unwrap();
+    assert_eq!(config.specs.len(), 2);
+    assert_eq!(config.specs[0].impls.len(), 3);
+}
@@ -69,7 +69,7 @@ fn rid(id: &str) -> tracey_core::RuleId {
 #[tokio::test]
 async fn test_status_returns_coverage() {
     let service = create_test_service().await;
-    let status = rpc(service.client.status().await);
+    let status = rpc(service.client.status(StatusRequest::default()).await);
 
     // We should have at least one impl
     assert!(!status.impls.is_empty(), "Expected at least one impl");
@@ -88,7 +88,7 @@ async fn test_status_returns_coverage() {
 #[tokio::test]
 async fn test_status_coverage_percentages() {
     let service = create_test_service().await;
-    let status = rpc(service.client.status().await);
+    let status = rpc(service.client.status(StatusRequest::default()).await);
 
     for impl_status in &status.impls {
         // Covered rules should not exceed total
@@ -120,6 +120,10 @@ async fn test_uncovered_returns_rules() {
         spec: Some("test".to_string()),
         impl_name: Some("rust".to_string()),
         prefix: None,
+        level: None,
+        status: None,
+        owner: None,
+        tag: None,
     };
 
     let response = rpc(service.client.uncovered(req).await);
@@ -140,6 +144,10 @@ async fn test_uncovered_with_prefix_filter() {
         spec: Some("test".to_string()),
         impl_name: Some("rust".to_string()),
         prefix: Some("auth".to_string()),
+        level: None,
+        status: None,
+        owner: None,
+        tag: None,
     };
 
     let response = rpc(service.client.uncovered(req).await);
@@ -163,6 +171,11 @@ async fn test_untested_returns_rules() {
         spec: Some("test".to_string()),
         impl_name: Some("rust".to_string()),
         prefix: None,
+        level: None,
+        status: None,
+        owner: None,
+        tag: None,
+        results: vec![],
     };
 
     let response = rpc(service.client.untested(req).await);
@@ -262,13 +275,13 @@ specs (
     );
 
     // Service remains responsive even when config is invalid.
-    let _status = rpc(rpc_service.client.status().await);
+    let _status = rpc(rpc_service.client.status(StatusRequest::default()).await);
 }
 
 #[tokio::test]
 async fn test_reload_with_semantically_invalid_config_keeps_previous_data() {
     let (temp, service) = create_isolated_test_service().await;
-    let before = rpc(service.client.status().await);
+    let before = rpc(service.client.status(StatusRequest::default()).await);
     assert!(
         !before.impls.is_empty(),
         "Expected fixture project to have initial coverage data"
@@ -304,7 +317,7 @@ specs (
     );
 
     // Last known good data remains available after failed semantic rebuild.
-    let after = rpc(service.client.status().await);
+    let after = rpc(service.client.status(StatusRequest::default()).await);
     assert!(
         !after.impls.is_empty(),
         "Expected previous data to remain available after failed rebuild"
@@ -0,0 +1,103 @@
+//! Tests for requirement levels and the statuses left out of coverage.
+
+mod common;
+
+use std::fs;
+
+use common::{AUTH_SPEC, write_auth_project};
+use tracey::check::{CheckOptions, check};
+use tracey::data::ConfigFile;
+use tracey::server::{CoverageStats, RuleFilter};
+
+#[tokio::test]
+async fn excluded_statuses_leave_coverage() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+    fs::write(
+        tmp.path().join("spec.md"),
+        format!(
+            "{AUTH_SPEC}\nr[auth.sso status=draft]\nUsers SHOULD be able to log in with SSO.\n"
+        ),
+    )
+    .unwrap();
+
+    let mut config = common::simple_config();
+    config.specs[0].exclude_statuses = vec!["Draft".to_string()];
+    let options = CheckOptions {
+        min_impl: Some(50.0),
+        ..Default::default()
+    };
+    let report = check(tmp.path(), &config, &ConfigFile::default(), &options)
+        .await
+        .unwrap();
+    assert!(report.passed());
+    assert_eq!(report.impls[0].stats.total_rules, 2);
+    assert_eq!(report.impls[0].stats.excluded, 1);
+
+    let data = tracey::data::build_dashboard_data(tmp.path(), &config, 1, true)
+        .await
+        .unwrap();
+    let forward = data.forward_by_impl.values().next().unwrap();
+    let rules: Vec<&tracey_api::ApiRule> = forward.rules.iter().collect();
+    let levels: Vec<&str> = CoverageStats::by_level(&rules)
+        .into_iter()
+        .map(|(level, _)| level)
+        .collect();
+    assert_eq!(levels, ["must"]);
+
+    let filter = RuleFilter {
+        status: Some("draft".to_string()),
+        ..Default::default()
+    };
+    let drafts: Vec<&str> = forward
+        .rules
+        .iter()
+        .filter(|r| filter.matches(r))
+        .map(|r| r.id.base.as_str())
+        .collect();
+    assert_eq!(drafts, ["auth.sso"]);
+}
+
+#[tokio::test]
+async fn levels_come_from_whole_uppercase_keywords() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "fn main() {}\n");
+    fs::write(
+        tmp.path().join("spec.md"),
+        "\
+# Spec
+
+r[text.none]
+To everyone's DISMAY, MARSHALLING must be done by hand.
+
+r[text.may]
+Retries are not required but MAY happen.
+
+r[text.should]
+A token is NOT REQUIRED here; clients SHOULD send one.
+
+r[text.must]
+Clients **MUST** send a token.
+",
+    )
+    .unwrap();
+
+    let data = tracey::data::build_dashboard_data(tmp.path(), &common::simple_config(), 1, true)
+        .await
+        .unwrap();
+    let forward = data.forward_by_impl.values().next().unwrap();
+    let levels: Vec<(&str, Option<&str>)> = forward
+        .rules
+        .iter()
+        .map(|r| (r.id.base.as_str(), tracey::server::rule_level(r)))
+        .collect();
+    assert_eq!(
+        levels,
+        [
+            ("text.may", Some("may")),
+            ("text.must", Some("must")),
+            ("text.none", None),
+            ("text.should", Some("should")),
+        ]
+    );
+}
@@ -0,0 +1,73 @@
+//! Tests for deprecated and removed requirements.
+
+mod common;
+
+use std::fs;
+
+use common::{AUTH_SPEC, write_auth_project};
+use tracey::check::{CheckOptions, check};
+use tracey::config::RemovedRule;
+use tracey::data::{ConfigFile, ValidationErrorCode};
+
+#[tokio::test]
+async fn deprecated_and_removed_rules() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(
+        tmp.path(),
+        "// r[impl auth.login]\nfn login() {}\n\n\
+         // r[impl auth.legacy]\nfn legacy() {}\n\n\
+         // r[impl auth.token]\nfn token() {}\n",
+    );
+    fs::write(
+        tmp.path().join("spec.md"),
+        format!("{AUTH_SPEC}\nr[auth.legacy status=deprecated]\nClients MAY use legacy tokens.\n"),
+    )
+    .unwrap();
+
+    let mut config = common::simple_config();
+    config.specs[0].removed = vec![RemovedRule {
+        id: "auth.token".to_string(),
+        replaced_by: Some("auth.session".to_string()),
+        reason: None,
+    }];
+
+    let report = check(
+        tmp.path(),
+        &config,
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    // The deprecated rule is left out of coverage; only its reference warns.
+    assert_eq!(report.impls[0].stats.total_rules, 2);
+    assert_eq!(report.impls[0].stats.excluded, 1);
+    assert_eq!(report.impls[0].validation_errors, 1);
+
+    let data = tracey::data::build_dashboard_data(tmp.path(), &config, 1, true)
+        .await
+        .unwrap();
+    let validation = data.validation_by_impl.values().next().unwrap();
+    assert_eq!(validation.warning_count, 1);
+    let deprecated = validation
+        .errors
+        .iter()
+        .find(|e| e.code == ValidationErrorCode::DeprecatedRequirement)
+        .unwrap();
+    assert_eq!(deprecated.line, Some(4));
+    let removed = validation
+        .errors
+        .iter()
+        .find(|e| e.code == ValidationErrorCode::RemovedRequirement)
+        .unwrap();
+    assert_eq!(
+        removed.message,
+        "Rule 'auth.token' was removed in favor of 'auth.session'"
+    );
+    assert!(
+        !validation
+            .errors
+            .iter()
+            .any(|e| e.code == ValidationErrorCode::UnknownRequirement)
+    );
+}
@@ -52,7 +52,7 @@ fn rid(id: &str) -> tracey_core::RuleId {
 #[tokio::test]
 async fn test_mcp_status_tool() {
     let service = create_test_service().await;
-    let status = rpc(service.client.status().await);
+    let status = rpc(service.client.status(StatusRequest::default()).await);
 
     // Verify we get coverage information
     assert!(!status.impls.is_empty(), "Expected at least one impl");
@@ -81,6 +81,10 @@ async fn test_mcp_uncovered_tool_no_filter() {
         spec: Some("test".to_string()),
         impl_name: Some("rust".to_string()),
         prefix: None,
+        level: None,
+        status: None,
+        owner: None,
+        tag: None,
     };
 
     let response = rpc(service.client.uncovered(req).await);
@@ -99,6 +103,10 @@ async fn test_mcp_uncovered_tool_with_prefix() {
         spec: Some("test".to_string()),
         impl_name: Some("rust".to_string()),
         prefix: Some("data".to_string()),
+        level: None,
+        status: None,
+        owner: None,
+        tag: None,
     };
 
     let response = rpc(service.client.uncovered(req).await);
@@ -124,6 +132,10 @@ async fn test_mcp_uncovered_tool_auto_select() {
         spec: None,
         impl_name: None,
         prefix: None,
+        level: None,
+        status: None,
+        owner: None,
+        tag: None,
     };
 
     let response = rpc(service.client.uncovered(req).await);
@@ -144,6 +156,11 @@ async fn test_mcp_untested_tool() {
         spec: Some("test".to_string()),
         impl_name: Some("rust".to_string()),
         prefix: None,
+        level: None,
+        status: None,
+        owner: None,
+        tag: None,
+        results: vec![],
     };
 
     let response = rpc(service.client.untested(req).await);
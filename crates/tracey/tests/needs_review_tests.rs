@@ -0,0 +1,76 @@
+//! Tests for propagating rule bumps through `depends` edges as needs-review.
+
+mod common;
+
+use std::fs;
+
+use common::{AUTH_SPEC, write_auth_project};
+use tracey::config::Config;
+
+#[tokio::test]
+async fn bumped_dependencies_propagate_needs_review() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.session]\nfn session() {}\n");
+    let spec = |session_depends: &str| {
+        AUTH_SPEC
+            .replace("r[auth.login]", "r[auth.login+2]")
+            .replace(
+                "r[auth.session]",
+                &format!("r[auth.session depends={session_depends}]"),
+            )
+            + "\nr[auth.audit depends=auth.session]\nSessions MUST be audited.\n"
+    };
+    fs::write(tmp.path().join("spec.md"), spec("auth.login")).unwrap();
+
+    let mut config = common::simple_config();
+    let build = |config: Config| {
+        let root = tmp.path().to_path_buf();
+        async move {
+            tracey::data::build_dashboard_data(&root, &config, 1, true)
+                .await
+                .unwrap()
+        }
+    };
+    let needs_review = |data: &tracey::data::DashboardData| -> Vec<(String, Vec<String>)> {
+        data.forward_by_impl[&("test".to_string(), "rust".to_string())]
+            .rules
+            .iter()
+            .filter(|r| !r.needs_review.is_empty())
+            .map(|r| {
+                let causes = r.needs_review.iter().map(|l| l.id.to_string()).collect();
+                (r.id.base.clone(), causes)
+            })
+            .collect()
+    };
+
+    // Off by default.
+    assert!(needs_review(&build(config.clone()).await).is_empty());
+
+    config.specs[0].propagate_stale = true;
+    let data = build(config.clone()).await;
+    assert_eq!(
+        needs_review(&data),
+        [
+            ("auth.session".to_string(), vec!["auth.login+2".to_string()]),
+            ("auth.audit".to_string(), vec!["auth.login+2".to_string()]),
+        ]
+    );
+    let stale = tracey::server::QueryEngine::new(&data)
+        .stale("test", "rust", None)
+        .unwrap();
+    assert_eq!(stale.needs_review.len(), 2);
+    assert_eq!(stale.needs_review[0].impl_refs.len(), 1);
+    let diagnostics: Vec<&str> = data
+        .workspace_diagnostics
+        .iter()
+        .flat_map(|f| &f.diagnostics)
+        .map(|d| d.code.as_str())
+        .collect();
+    assert_eq!(diagnostics, ["needs-review"]);
+
+    // Naming the current version acknowledges the change downstream too.
+    fs::write(tmp.path().join("spec.md"), spec("auth.login+2")).unwrap();
+    let data = build(config).await;
+    assert!(needs_review(&data).is_empty());
+    assert!(data.workspace_diagnostics.is_empty());
+}
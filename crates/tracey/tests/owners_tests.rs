@@ -0,0 +1,140 @@
+//! Integration tests for rule owners.
+//!
+//! Each test writes a small project to a temp directory and builds its
+//! coverage via the library API.
+
+mod common;
+
+use std::path::Path;
+
+use tracey::config::{Config, OwnersConfig, SpecConfig};
+use tracey::data::{DashboardData, build_dashboard_data};
+use tracey::server::{QueryEngine, RuleFilter};
+
+const SPEC: &str = "\
+# Spec
+
+r[payments.charge]
+Charges MUST be idempotent.
+
+r[payments.refund owner=@billing]
+Refunds MUST go to the original payment method.
+
+r[payments.refund.partial]
+Partial refunds MAY be issued.
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+";
+
+const SOURCE: &str = "\
+// r[impl payments.charge]
+fn charge() {}
+
+// r[impl auth.login]
+fn login() {}
+";
+
+fn write_project(root: &Path) {
+    common::write_project(root, &[("spec.md", SPEC), ("src/lib.rs", SOURCE)]);
+}
+
+fn owners(prefix: &str, owners: &[&str]) -> OwnersConfig {
+    OwnersConfig {
+        prefix: prefix.to_string(),
+        owners: owners.iter().map(|o| o.to_string()).collect(),
+    }
+}
+
+fn config() -> Config {
+    Config {
+        specs: vec![SpecConfig {
+            owners: vec![
+                owners("payments.", &["@payments"]),
+                owners("payments.refund", &["@payments", "@billing"]),
+            ],
+            ..common::test_spec(vec![common::rust_impl(&["src/**/*.rs"], &[])])
+        }],
+        ..Default::default()
+    }
+}
+
+async fn build(root: &Path) -> DashboardData {
+    build_dashboard_data(root, &config(), 1, true)
+        .await
+        .unwrap()
+}
+
+fn owners_of<'a>(data: &'a DashboardData, id: &str) -> &'a [String] {
+    &data.forward_by_impl[&("test".to_string(), "rust".to_string())]
+        .rules
+        .iter()
+        .find(|r| r.id.base == id)
+        .unwrap()
+        .owners
+}
+
+#[tokio::test]
+async fn owners_come_from_markers_then_config() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+    let data = build(tmp.path()).await;
+
+    assert_eq!(owners_of(&data, "payments.charge"), ["@payments"]);
+    // The marker's `owner=` takes precedence over the config.
+    assert_eq!(owners_of(&data, "payments.refund"), ["@billing"]);
+    // The last matching entry wins.
+    assert_eq!(
+        owners_of(&data, "payments.refund.partial"),
+        ["@payments", "@billing"]
+    );
+    assert!(owners_of(&data, "auth.login").is_empty());
+}
+
+#[tokio::test]
+async fn owner_prefixes_match_whole_segments() {
+    let tmp = tempfile::tempdir().unwrap();
+    common::write_project(
+        tmp.path(),
+        &[
+            (
+                "spec.md",
+                "# Spec\n\nr[payments.refundable]\nCharges MAY be refundable.\n",
+            ),
+            ("src/lib.rs", SOURCE),
+        ],
+    );
+    let data = build(tmp.path()).await;
+
+    // `payments.refund` doesn't cover `payments.refundable`.
+    assert_eq!(owners_of(&data, "payments.refundable"), ["@payments"]);
+}
+
+#[tokio::test]
+async fn queries_filter_and_group_by_owner() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+    let data = build(tmp.path()).await;
+
+    let query = QueryEngine::new(&data).with_filter(RuleFilter {
+        owner: Some("@Billing".to_string()),
+        ..Default::default()
+    });
+    let uncovered = query.uncovered("test", "rust", None).unwrap();
+    assert_eq!(uncovered.stats.total_rules, 2);
+    assert_eq!(uncovered.total_uncovered, 2);
+
+    let stats = QueryEngine::new(&data).status_by_owner("test", "rust");
+    let by_owner: Vec<(Option<&str>, usize, usize)> = stats
+        .iter()
+        .map(|(owner, s)| (owner.as_deref(), s.total_rules, s.impl_covered))
+        .collect();
+    assert_eq!(
+        by_owner,
+        [
+            (Some("@billing"), 2, 0),
+            (Some("@payments"), 2, 1),
+            (None, 1, 1),
+        ]
+    );
+}
@@ -0,0 +1,122 @@
+//! Tests for `tracey query --format json`.
+//!
+//! Each test writes a small project to a temp directory, runs its daemon
+//! in-process and queries it through the same client as the CLI.
+
+mod common;
+
+use std::path::Path;
+use std::time::Duration;
+
+use facet::Facet;
+use tracey::bridge::query::{Caller, QueryClient};
+use tracey::output::OutputFormat;
+use tracey_api::ValidationResult;
+use tracey_proto::{RuleInfo, StatusResponse};
+
+const CONFIG: &str = "\
+specs (
+  {
+    name test
+    include (spec.md)
+    impls (
+      {
+        name rust
+        include (src/**/*.rs)
+      }
+    )
+  }
+)
+";
+
+#[derive(Facet)]
+struct RuleLookup {
+    rules: Vec<RuleInfo>,
+    errors: Vec<String>,
+}
+
+#[derive(Facet)]
+struct JsonError {
+    error: String,
+}
+
+#[derive(Facet)]
+struct PatternAdded {
+    kind: String,
+    pattern: String,
+}
+
+/// Write a project implementing `auth.login` and run `queries` against its
+/// daemon with a JSON query client.
+async fn query<T>(root: &Path, queries: impl AsyncFnOnce(QueryClient) -> T) -> T {
+    common::write_auth_project(root, "// r[impl auth.login]\nfn login() {}\n");
+    common::write(root, tracey::DEFAULT_CONFIG_PATH, CONFIG);
+
+    let daemon = tracey::daemon::run(root.to_path_buf(), root.join(tracey::DEFAULT_CONFIG_PATH));
+    let queries = async {
+        while !tracey::daemon::is_running(root).await {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        let client =
+            QueryClient::new(root.to_path_buf(), Caller::Cli).with_format(OutputFormat::Json);
+        queries(client).await
+    };
+    tokio::select! {
+        result = daemon => panic!("daemon exited: {result:?}"),
+        output = queries => output,
+    }
+}
+
+#[tokio::test]
+async fn status_is_the_daemon_response() {
+    let tmp = tempfile::tempdir().unwrap();
+    let json = query(tmp.path(), async |client| client.status().await).await;
+
+    let status: StatusResponse = facet_json::from_str(&json).unwrap();
+    assert_eq!(status.impls.len(), 1);
+    assert_eq!(status.impls[0].total_rules, 2);
+    assert_eq!(status.impls[0].covered_rules, 1);
+}
+
+#[tokio::test]
+async fn rules_separate_found_rules_from_errors() {
+    let tmp = tempfile::tempdir().unwrap();
+    let ids = ["auth.login".to_string(), "auth.missing".to_string()];
+    let json = query(tmp.path(), async |client| client.rules(&ids).await).await;
+
+    let lookup: RuleLookup = facet_json::from_str(&json).unwrap();
+    assert_eq!(lookup.rules.len(), 1);
+    assert_eq!(lookup.rules[0].id.base, "auth.login");
+    assert_eq!(lookup.errors, ["Rule not found: auth.missing"]);
+}
+
+#[tokio::test]
+async fn validate_is_an_array_of_results() {
+    let tmp = tempfile::tempdir().unwrap();
+    let json = query(tmp.path(), async |client| client.validate(None).await).await;
+
+    let results: Vec<ValidationResult> = facet_json::from_str(&json).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].spec, "test");
+    assert_eq!(results[0].error_count, 0);
+}
+
+#[tokio::test]
+async fn config_patterns_and_errors_are_objects() {
+    let tmp = tempfile::tempdir().unwrap();
+    let (added, failed) = query(tmp.path(), async |client| {
+        let added = client
+            .config_exclude(Some("test/rust"), "src/generated/**")
+            .await;
+        let failed = client.config_include(Some("nope/rust"), "lib/**").await;
+        (added, failed)
+    })
+    .await;
+
+    let added: PatternAdded = facet_json::from_str(&added).unwrap();
+    assert_eq!(added.kind, "exclude");
+    assert_eq!(added.pattern, "src/generated/**");
+
+    let failed: JsonError = facet_json::from_str(&failed).unwrap();
+    assert!(failed.error.contains("nope"), "{}", failed.error);
+}
@@ -0,0 +1,220 @@
+//! Integration tests for test result ingestion.
+//!
+//! Each test writes a small project with test result files to a temp
+//! directory and builds its coverage via the library API.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use tracey::config::{Config, Impl};
+use tracey::data::{DashboardData, TestOutcome, build_dashboard_data};
+use tracey::server::{CoverageStats, QueryEngine};
+use tracey::test_results::{parse, with_results};
+
+const SPEC: &str = "\
+# Spec
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+";
+
+const SOURCE: &str = "\
+// r[impl auth.login]
+fn login() {}
+
+// r[impl auth.session]
+fn session() {}
+";
+
+const TESTS: &str = "\
+// r[verify auth.login]
+#[test]
+fn login_works() {}
+
+#[test]
+fn session_expires() {
+    // r[verify auth.session]
+    assert!(true);
+}
+";
+
+fn write_project(root: &Path) {
+    common::write_project(
+        root,
+        &[
+            ("spec.md", SPEC),
+            ("src/lib.rs", SOURCE),
+            ("tests/auth.rs", TESTS),
+        ],
+    );
+}
+
+fn config(results: Vec<String>) -> Config {
+    let rust = Impl {
+        results,
+        ..common::rust_impl(&["src/**/*.rs", "tests/**/*.rs"], &["tests/**/*.rs"])
+    };
+    Config {
+        specs: vec![common::test_spec(vec![rust])],
+        ..Default::default()
+    }
+}
+
+fn verification(data: &DashboardData) -> Vec<(String, Option<TestOutcome>)> {
+    data.forward_by_impl[&("test".to_string(), "rust".to_string())]
+        .rules
+        .iter()
+        .map(|r| (r.id.base.clone(), r.verification))
+        .collect()
+}
+
+fn untested(data: &DashboardData) -> Vec<String> {
+    QueryEngine::new(data)
+        .untested("test", "rust", None)
+        .unwrap()
+        .by_section
+        .values()
+        .flatten()
+        .map(|r| r.id.base.clone())
+        .collect()
+}
+
+#[test]
+fn parses_result_formats() {
+    let junit = parse(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="auth">
+    <testcase name="login_works" classname="auth" file="tests/auth.rs"/>
+    <testcase name="session_expires" classname="auth">
+      <failure message="assertion failed">left &amp; right</failure>
+    </testcase>
+    <testcase name="slow"><skipped/></testcase>
+  </testsuite>
+</testsuites>"#,
+    )
+    .unwrap();
+    let outcomes: Vec<_> = junit
+        .iter()
+        .map(|r| (r.name.as_str(), r.file.as_deref(), r.outcome))
+        .collect();
+    assert_eq!(
+        outcomes,
+        [
+            ("login_works", Some("tests/auth.rs"), TestOutcome::Passed),
+            ("session_expires", None, TestOutcome::Failed),
+            ("slow", None, TestOutcome::NotRun),
+        ]
+    );
+
+    let libtest = parse(
+        r#"{ "type": "suite", "event": "started", "test_count": 2 }
+{ "type": "test", "event": "started", "name": "auth::login_works" }
+{ "type": "test", "name": "auth::login_works", "event": "ok" }
+{ "type": "test", "name": "auth$session_expires", "event": "failed", "stdout": "panicked" }
+{ "type": "suite", "event": "failed", "passed": 1, "failed": 1 }"#,
+    )
+    .unwrap();
+    let outcomes: Vec<_> = libtest
+        .iter()
+        .map(|r| (r.name.as_str(), r.outcome))
+        .collect();
+    assert_eq!(
+        outcomes,
+        [
+            ("auth::login_works", TestOutcome::Passed),
+            ("auth$session_expires", TestOutcome::Failed),
+        ]
+    );
+
+    let go = parse(
+        "go: downloading example.com/auth v1.0.0\n\
+         {\"Action\":\"run\",\"Package\":\"auth\",\"Test\":\"TestLogin\"}\n\
+         {\"Action\":\"skip\",\"Package\":\"auth\",\"Test\":\"TestLogin/legacy\"}\n\
+         {\"Action\":\"pass\",\"Package\":\"auth\",\"Test\":\"TestLogin\"}\n\
+         {\"Action\":\"pass\",\"Package\":\"auth\"}\n",
+    )
+    .unwrap();
+    let outcomes: Vec<_> = go.iter().map(|r| (r.name.as_str(), r.outcome)).collect();
+    assert_eq!(
+        outcomes,
+        [
+            ("TestLogin/legacy", TestOutcome::NotRun),
+            ("TestLogin", TestOutcome::Passed),
+        ]
+    );
+
+    assert!(parse("running 2 tests\ntest result: ok\n").is_err());
+}
+
+#[tokio::test]
+async fn configured_results_decide_verification() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+
+    // Results that don't exist yet are skipped.
+    let results = vec!["target/junit.xml".to_string()];
+    let data = build_dashboard_data(tmp.path(), &config(results.clone()), 1, true)
+        .await
+        .unwrap();
+    assert_eq!(
+        verification(&data),
+        [
+            ("auth.login".to_string(), Some(TestOutcome::NotRun)),
+            ("auth.session".to_string(), Some(TestOutcome::NotRun)),
+        ]
+    );
+
+    fs::create_dir_all(tmp.path().join("target")).unwrap();
+    fs::write(
+        tmp.path().join("target/junit.xml"),
+        r#"<testsuite name="auth">
+  <testcase name="login_works" classname="auth::bin"/>
+  <testcase name="session_expires" classname="auth::bin"><failure/></testcase>
+</testsuite>"#,
+    )
+    .unwrap();
+    let data = build_dashboard_data(tmp.path(), &config(results), 1, true)
+        .await
+        .unwrap();
+    assert_eq!(
+        verification(&data),
+        [
+            ("auth.login".to_string(), Some(TestOutcome::Passed)),
+            ("auth.session".to_string(), Some(TestOutcome::Failed)),
+        ]
+    );
+    let forward = &data.forward_by_impl[&("test".to_string(), "rust".to_string())];
+    let stats = CoverageStats::from_rule_refs(&forward.rules);
+    assert!(stats.has_results);
+    assert_eq!((stats.verify_passing, stats.verify_failing), (1, 1));
+    assert_eq!(untested(&data), ["auth.session"]);
+
+    // Results given to a query replace the configured ones.
+    let passing = parse(
+        r#"{ "type": "test", "name": "auth::login_works", "event": "ok" }
+{ "type": "test", "name": "auth::session_expires", "event": "ok" }"#,
+    )
+    .unwrap();
+    let data = with_results(&data, &passing);
+    assert!(untested(&data).is_empty());
+}
+
+#[tokio::test]
+async fn no_results_leave_verification_unset() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+
+    let data = build_dashboard_data(tmp.path(), &config(vec![]), 1, true)
+        .await
+        .unwrap();
+    assert!(verification(&data).iter().all(|(_, v)| v.is_none()));
+    let forward = &data.forward_by_impl[&("test".to_string(), "rust".to_string())];
+    assert!(!CoverageStats::from_rule_refs(&forward.rules).has_results);
+    assert!(untested(&data).is_empty());
+}
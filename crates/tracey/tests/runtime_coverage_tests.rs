@@ -0,0 +1,148 @@
+//! Integration tests for runtime coverage import.
+//!
+//! Each test writes a small project with coverage data to a temp directory
+//! and builds its coverage via the library API.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use tracey::config::{Config, Impl};
+use tracey::data::{DashboardData, build_dashboard_data};
+use tracey::runtime_coverage::parse;
+use tracey::server::CoverageStats;
+
+const SPEC: &str = "\
+# Spec
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+";
+
+const SOURCE: &str = "\
+// r[impl auth.login]
+fn login() {
+    check();
+}
+
+// r[impl auth.session]
+fn session() {
+    expire();
+}
+";
+
+fn write_project(root: &Path) {
+    common::write_project(root, &[("spec.md", SPEC), ("src/lib.rs", SOURCE)]);
+}
+
+fn config(runtime_coverage: Vec<String>) -> Config {
+    let rust = Impl {
+        runtime_coverage,
+        ..common::rust_impl(&["src/**/*.rs"], &[])
+    };
+    Config {
+        specs: vec![common::test_spec(vec![rust])],
+        ..Default::default()
+    }
+}
+
+fn key() -> (String, String) {
+    ("test".to_string(), "rust".to_string())
+}
+
+fn executed(data: &DashboardData) -> Vec<(String, Option<bool>, Option<u64>)> {
+    data.forward_by_impl[&key()]
+        .rules
+        .iter()
+        .map(|r| (r.id.base.clone(), r.executed, r.impl_refs[0].hits))
+        .collect()
+}
+
+#[test]
+fn parses_coverage_formats() {
+    let lcov = parse(
+        "TN:\nSF:/ci/src/lib.rs\nFN:2,login\nFNDA:3,login\nDA:2,3\nDA:3,3\nDA:7,0\n\
+         LF:3\nLH:2\nend_of_record\nSF:src/other.rs\nDA:1,1.0\nend_of_record\n",
+    )
+    .unwrap();
+    assert_eq!(
+        lcov["/ci/src/lib.rs"].iter().collect::<Vec<_>>(),
+        [(&2, &3), (&3, &3), (&7, &0)]
+    );
+    assert_eq!(lcov["src/other.rs"][&1], 1);
+
+    let llvm = parse(
+        r#"{"data":[{"files":[{"filename":"/ci/src/lib.rs","segments":[
+            [2,14,5,true,true,false],[2,20,2,true,false,false],
+            [4,2,0,false,false,false],[7,16,0,true,true,false]
+        ]}],"functions":[]}],"type":"llvm.coverage.json.export","version":"2.0.1"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        llvm["/ci/src/lib.rs"].iter().collect::<Vec<_>>(),
+        [(&2, &5), (&7, &0)]
+    );
+
+    assert!(parse("not coverage data\n").is_err());
+    assert!(parse(r#"{"results": []}"#).is_err());
+}
+
+#[tokio::test]
+async fn marks_rules_whose_implementation_never_ran() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+
+    // Coverage that doesn't exist yet is skipped.
+    let coverage = vec!["target/coverage".to_string()];
+    let data = build_dashboard_data(tmp.path(), &config(coverage.clone()), 1, true)
+        .await
+        .unwrap();
+    assert!(
+        executed(&data)
+            .iter()
+            .all(|(_, e, h)| e.is_none() && h.is_none())
+    );
+
+    // Reports from another checkout match by path suffix, and hits add up
+    // across reports.
+    fs::create_dir_all(tmp.path().join("target/coverage")).unwrap();
+    fs::write(
+        tmp.path().join("target/coverage/unit.info"),
+        "SF:/ci/checkout/src/lib.rs\nDA:2,1\nDA:3,1\nDA:7,0\nDA:8,0\nend_of_record\n",
+    )
+    .unwrap();
+    fs::write(
+        tmp.path().join("target/coverage/integration.json"),
+        r#"{"data":[{"files":[{"filename":"/ci/checkout/src/lib.rs",
+            "segments":[[2,14,2,true,true,false],[7,16,0,true,true,false]]}]}]}"#,
+    )
+    .unwrap();
+    let data = build_dashboard_data(tmp.path(), &config(coverage), 1, true)
+        .await
+        .unwrap();
+    assert_eq!(
+        executed(&data),
+        [
+            ("auth.login".to_string(), Some(true), Some(3)),
+            ("auth.session".to_string(), Some(false), Some(0)),
+        ]
+    );
+
+    let units: Vec<(Option<&str>, Option<u64>)> = data.code_units_by_impl[&key()]
+        .values()
+        .flatten()
+        .map(|u| (u.name.as_deref(), u.hits))
+        .collect();
+    assert_eq!(
+        units,
+        [(Some("login"), Some(3)), (Some("session"), Some(0))]
+    );
+
+    let stats = CoverageStats::from_rule_refs(&data.forward_by_impl[&key()].rules);
+    assert!(stats.has_runtime_coverage);
+    assert_eq!(stats.impl_executed, 1);
+}
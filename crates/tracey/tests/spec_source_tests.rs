@@ -0,0 +1,131 @@
+//! Integration tests for specs read from another git repository.
+//!
+//! Each test creates a spec repository and a project next to it in a temp
+//! directory, and builds the project's coverage via the library API.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::{commit_all, git_init};
+use tracey::config::{Config, GitSource, SpecConfig};
+use tracey::data::{build_dashboard_data, render_spec_content_for_impl};
+use tracey::spec_source::{format_updates, load_lock, update};
+
+fn config() -> Config {
+    Config {
+        specs: vec![SpecConfig {
+            name: "protocol".to_string(),
+            include: vec!["docs/**/*.md".to_string()],
+            impls: vec![common::rust_impl(&["src/**/*.rs"], &[])],
+            git: Some(GitSource {
+                repo: "../spec-repo".to_string(),
+                rev: "main".to_string(),
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+async fn rule_ids(root: &Path) -> Vec<String> {
+    let data = build_dashboard_data(root, &config(), 1, true)
+        .await
+        .unwrap();
+    let forward = &data.forward_by_impl[&("protocol".to_string(), "rust".to_string())];
+    forward.rules.iter().map(|r| r.id.to_string()).collect()
+}
+
+/// The spec's rendered content, as shown by the dashboard.
+async fn spec_html(root: &Path) -> String {
+    let data = build_dashboard_data(root, &config(), 1, true)
+        .await
+        .unwrap();
+    let forward = &data.forward_by_impl[&("protocol".to_string(), "rust".to_string())];
+    let content = render_spec_content_for_impl(
+        root,
+        &data.spec_files_by_name["protocol"],
+        "protocol",
+        "rust",
+        forward,
+    )
+    .await
+    .unwrap();
+    content.sections.iter().map(|s| s.html.as_str()).collect()
+}
+
+#[tokio::test]
+async fn reads_the_pinned_commit_until_updated() {
+    let dir = tempfile::tempdir().unwrap();
+    let spec_repo = dir.path().join("spec-repo");
+    let root = dir.path().join("project");
+    fs::create_dir_all(spec_repo.join("docs")).unwrap();
+    fs::create_dir_all(root.join("src")).unwrap();
+
+    git_init(&spec_repo);
+    fs::write(
+        spec_repo.join("docs/spec.md"),
+        "# Protocol\n\nr[msg.header]\nMessages MUST start with a header.\n\n\
+         r[msg.length]\nThe header MUST carry the body length.\n",
+    )
+    .unwrap();
+    commit_all(&spec_repo, "first");
+    fs::write(
+        root.join("src/lib.rs"),
+        "// r[impl msg.header]\nfn header() {}\n",
+    )
+    .unwrap();
+
+    // Builds don't pin specs on their own.
+    let err = build_dashboard_data(&root, &config(), 1, true)
+        .await
+        .unwrap_err();
+    assert!(format!("{err:#}").contains("tracey spec update"));
+    assert!(!root.join(".config/tracey/spec.lock").exists());
+
+    let updates = update(&root, &config(), None).await.unwrap();
+    assert_eq!(updates[0].old_commit, None);
+    assert!(format_updates(&updates).contains("pinned at"));
+    assert!(root.join(".config/tracey/spec.lock").exists());
+
+    assert_eq!(rule_ids(&root).await, ["msg.header", "msg.length"]);
+    let lock = load_lock(&root).unwrap();
+    assert_eq!(lock.specs.len(), 1);
+    let pinned = lock.specs[0].commit.clone();
+
+    fs::write(
+        spec_repo.join("docs/spec.md"),
+        "# Protocol\n\nr[msg.header]\nMessages MUST start with a versioned header.\n\n\
+         r[msg.checksum]\nThe header MUST carry a checksum.\n",
+    )
+    .unwrap();
+    commit_all(&spec_repo, "second");
+    // Uncommitted changes in the spec repository are never read.
+    fs::write(spec_repo.join("docs/spec.md"), "# Protocol\n").unwrap();
+
+    // The pin holds even though `main` moved.
+    assert_eq!(rule_ids(&root).await, ["msg.header", "msg.length"]);
+    let content = spec_html(&root).await;
+    assert!(content.contains("The header MUST carry the body length."));
+    assert!(!content.contains("checksum"));
+
+    let updates = update(&root, &config(), None).await.unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].old_commit.as_deref(), Some(pinned.as_str()));
+    assert_ne!(updates[0].new_commit, pinned);
+    assert_eq!(updates[0].added, ["msg.checksum"]);
+    assert_eq!(updates[0].removed, ["msg.length"]);
+    assert_eq!(updates[0].changed, ["msg.header"]);
+    let report = format_updates(&updates);
+    assert!(report.contains("1 added, 1 removed, 1 changed"));
+    assert!(report.contains("  ~ msg.header"));
+
+    assert_eq!(
+        load_lock(&root).unwrap().specs[0].commit,
+        updates[0].new_commit
+    );
+    assert_eq!(rule_ids(&root).await, ["msg.header", "msg.checksum"]);
+
+    assert!(update(&root, &config(), Some("other")).await.is_err());
+}
@@ -0,0 +1,131 @@
+//! Integration tests for rule tags.
+//!
+//! Each test writes a small project to a temp directory and builds its
+//! coverage via the library API.
+
+mod common;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tracey::data::{DashboardData, build_dashboard_data};
+use tracey::search::{RuleEntry, build_index};
+use tracey::server::{QueryEngine, RuleFilter};
+use tracey_core::parse_rule_id;
+
+const SPEC: &str = "\
+# Spec
+
+r[auth.login tags=security,compliance-gdpr,security]
+Users MUST provide valid credentials to log in.
+
+r[auth.session tags=security]
+Sessions MUST expire after 24 hours of inactivity.
+
+r[cache.ttl tags=perf]
+Cached responses SHOULD expire after 5 minutes.
+
+r[cache.size]
+The cache MAY hold up to 1000 entries.
+";
+
+const SOURCE: &str = "\
+// r[impl auth.login]
+fn login() {}
+
+// r[impl cache.ttl]
+fn ttl() {}
+";
+
+fn write_project(root: &Path) {
+    common::write_project(root, &[("spec.md", SPEC), ("src/lib.rs", SOURCE)]);
+}
+
+async fn build(root: &Path) -> DashboardData {
+    build_dashboard_data(root, &common::simple_config(), 1, true)
+        .await
+        .unwrap()
+}
+
+fn tags_of<'a>(data: &'a DashboardData, id: &str) -> &'a [String] {
+    &data.forward_by_impl[&("test".to_string(), "rust".to_string())]
+        .rules
+        .iter()
+        .find(|r| r.id.base == id)
+        .unwrap()
+        .tags
+}
+
+#[tokio::test]
+async fn tags_come_from_markers() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+    let data = build(tmp.path()).await;
+
+    assert_eq!(
+        tags_of(&data, "auth.login"),
+        ["security", "compliance-gdpr"]
+    );
+    assert_eq!(tags_of(&data, "cache.ttl"), ["perf"]);
+    assert!(tags_of(&data, "cache.size").is_empty());
+
+    let info = QueryEngine::new(&data)
+        .rule(&parse_rule_id("auth.session").unwrap())
+        .unwrap();
+    assert_eq!(info.tags, ["security"]);
+}
+
+#[tokio::test]
+async fn queries_filter_and_group_by_tag() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+    let data = build(tmp.path()).await;
+
+    let query = QueryEngine::new(&data).with_filter(RuleFilter {
+        tag: Some("Security".to_string()),
+        ..Default::default()
+    });
+    let uncovered = query.uncovered("test", "rust", None).unwrap();
+    assert_eq!(uncovered.stats.total_rules, 2);
+    assert_eq!(uncovered.total_uncovered, 1);
+    let untested = query.untested("test", "rust", None).unwrap();
+    assert_eq!(untested.total_untested, 1);
+
+    let stats = QueryEngine::new(&data).status_by_tag("test", "rust");
+    let by_tag: Vec<(&str, usize, usize)> = stats
+        .iter()
+        .map(|(tag, s)| (tag.as_str(), s.total_rules, s.impl_covered))
+        .collect();
+    assert_eq!(
+        by_tag,
+        [
+            ("compliance-gdpr", 1, 1),
+            ("perf", 1, 1),
+            ("security", 2, 1)
+        ]
+    );
+}
+
+#[test]
+fn search_finds_rules_by_tag() {
+    let rules = vec![
+        RuleEntry {
+            id: "auth.login".to_string(),
+            raw: "Users MUST provide valid credentials to log in.".to_string(),
+            tags: vec!["security".to_string()],
+        },
+        RuleEntry {
+            id: "cache.ttl".to_string(),
+            raw: "Cached responses SHOULD expire, for security.".to_string(),
+            tags: vec!["perf".to_string()],
+        },
+    ];
+    let index = build_index(Path::new("/"), &BTreeMap::new(), &rules);
+
+    let ids: Vec<String> = index
+        .search("tag:security", 10)
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    assert_eq!(ids, ["auth.login"]);
+}
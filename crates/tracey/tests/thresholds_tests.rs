@@ -0,0 +1,212 @@
+//! Tests for coverage thresholds from the config's `thresholds` blocks.
+
+mod common;
+
+use common::write_auth_project;
+use tracey::check::{CheckOptions, check};
+use tracey::config::{PrefixThresholds, Thresholds};
+use tracey::data::{ConfigFile, ValidationErrorCode, build_dashboard_data_from_file};
+use tracey::thresholds::{ThresholdSource, ThresholdViolation, locate};
+
+#[tokio::test]
+async fn enforces_config_thresholds() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+
+    let mut config = common::simple_config();
+    config.specs[0].thresholds = Some(Thresholds {
+        min_impl: Some(40.0),
+        ..Default::default()
+    });
+    let report = check(
+        tmp.path(),
+        &config,
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert!(report.passed());
+
+    // The impl block overrides the spec block.
+    config.specs[0].impls[0].thresholds = Some(Thresholds {
+        min_impl: Some(80.0),
+        ..Default::default()
+    });
+    let report = check(
+        tmp.path(),
+        &config,
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert!(!report.passed());
+    assert!(report.impls[0].failures[0].contains("impl coverage 50.0%"));
+}
+
+#[tokio::test]
+async fn enforces_prefix_thresholds() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+
+    let mut config = common::simple_config();
+    config.specs[0].prefix_thresholds = vec![PrefixThresholds {
+        prefix: "auth.login".to_string(),
+        thresholds: Thresholds {
+            min_impl: Some(100.0),
+            ..Default::default()
+        },
+    }];
+    let report = check(
+        tmp.path(),
+        &config,
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert!(report.passed());
+
+    config.specs[0].prefix_thresholds[0].prefix = "auth.".to_string();
+    let report = check(
+        tmp.path(),
+        &config,
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert!(!report.passed());
+    assert!(report.impls[0].failures[0].contains("under 'auth.'"));
+}
+
+#[tokio::test]
+async fn prefix_thresholds_match_whole_segments() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+
+    let mut config = common::simple_config();
+    config.specs[0].prefix_thresholds = vec![PrefixThresholds {
+        prefix: "auth.sess".to_string(),
+        thresholds: Thresholds {
+            min_impl: Some(100.0),
+            ..Default::default()
+        },
+    }];
+    // `auth.sess` doesn't cover `auth.session`, so there's nothing to check.
+    let report = check(
+        tmp.path(),
+        &config,
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert!(report.passed());
+
+    config.specs[0].prefix_thresholds[0].prefix = "auth".to_string();
+    let report = check(
+        tmp.path(),
+        &config,
+        &ConfigFile::default(),
+        &CheckOptions::default(),
+    )
+    .await
+    .unwrap();
+    assert!(!report.passed());
+    assert!(report.impls[0].failures[0].contains("under 'auth'"));
+}
+
+#[tokio::test]
+async fn threshold_violations_point_into_the_loaded_config() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_auth_project(tmp.path(), "// r[impl auth.login]\nfn login() {}\n");
+
+    let mut config = common::simple_config();
+    config.specs[0].thresholds = Some(Thresholds {
+        min_impl: Some(80.0),
+        ..Default::default()
+    });
+    let text = "specs (\n  {\n    name test\n    include (spec.md)\n    thresholds {min_impl 80}\n  }\n)\n";
+    let config_file = ConfigFile::new(
+        tmp.path(),
+        &tmp.path().join("ci/tracey.styx"),
+        text.to_string(),
+    );
+
+    let data = build_dashboard_data_from_file(tmp.path(), &config, &config_file, 1, true)
+        .await
+        .unwrap();
+    let errors: Vec<_> = data
+        .validation_by_impl
+        .values()
+        .flat_map(|v| &v.errors)
+        .filter(|e| e.code == ValidationErrorCode::CoverageThreshold)
+        .map(|e| (e.file.as_deref(), e.line))
+        .collect();
+    assert_eq!(errors, [(Some("ci/tracey.styx"), Some(5))]);
+
+    // The breach fails the check once, not also as a validation error.
+    let options = CheckOptions {
+        fail_on_validation_errors: true,
+        ..Default::default()
+    };
+    let report = check(tmp.path(), &config, &config_file, &options)
+        .await
+        .unwrap();
+    assert_eq!(report.impls[0].validation_errors, 0);
+    assert_eq!(report.impls[0].failures.len(), 1);
+}
+
+#[test]
+fn locate_follows_the_config_structure() {
+    // The impl shares the spec's name, `name` comes after `impls`, the impl's
+    // thresholds come before the spec's, and comments mention names too.
+    let text = r##"@schema {id crate:tracey-config@1, cli tracey}
+// name app
+specs (
+  {
+    impls (
+      {
+        name app
+        thresholds {min_impl 90} // name app
+        include ("src/**/*.rs")
+      }
+    )
+    thresholds {min_verify 50}
+    name app
+    prefix_thresholds (
+      {prefix auth, thresholds {min_impl 100}}
+      {
+        prefix "api"
+        thresholds {min_impl 100}
+      }
+    )
+  }
+  {
+    name other
+    impls_from r#"Cargo.toml"#
+  }
+)
+"##;
+    let line = |spec: &str, impl_name: &str, source: ThresholdSource| {
+        let violation = ThresholdViolation {
+            spec: spec.to_string(),
+            impl_name: impl_name.to_string(),
+            source,
+            message: String::new(),
+        };
+        locate(text, &violation)
+    };
+
+    assert_eq!(line("app", "app", ThresholdSource::Spec), 11);
+    assert_eq!(line("app", "app", ThresholdSource::Impl), 7);
+    let prefix = |prefix: &str| ThresholdSource::Prefix(prefix.to_string());
+    assert_eq!(line("app", "app", prefix("auth")), 14);
+    assert_eq!(line("app", "app", prefix("api")), 16);
+    // Discovered impls point at where they were discovered from.
+    assert_eq!(line("other", "core", ThresholdSource::Impl), 23);
+    assert_eq!(line("other", "core", ThresholdSource::Spec), 22);
+    assert_eq!(line("missing", "core", ThresholdSource::Spec), 0);
+}
@@ -0,0 +1,187 @@
+//! Integration tests for verbs declared in the config.
+//!
+//! Each test writes a small project to a temp directory and builds its
+//! coverage via the library API.
+
+mod common;
+
+use std::path::Path;
+
+use tracey::config::{Config, VerbConfig};
+use tracey::data::{
+    ApiRule, BuildCache, ConfigFile, DashboardData, FileOverlay, ValidationErrorCode,
+    build_dashboard_data, build_dashboard_data_with_overlay_and_cache,
+};
+
+const SPEC: &str = "\
+# Spec
+
+r[auth.login]
+Users MUST provide valid credentials to log in.
+
+r[auth.session]
+Sessions MUST expire after 24 hours of inactivity.
+";
+
+const SOURCE: &str = "\
+// r[impl auth.login]
+fn login() {}
+
+// r[fuzz auth.session]
+fn fuzz_session() {}
+
+// r[sketch auth.session]
+fn sketch() {}
+";
+
+const BENCHES: &str = "\
+// r[bench auth.login]
+fn bench_login() {}
+
+// r[stub auth.session]
+fn stub_session() {}
+";
+
+fn write_project(root: &Path) {
+    common::write_project(
+        root,
+        &[
+            ("spec.md", SPEC),
+            ("src/lib.rs", SOURCE),
+            ("tests/benches.rs", BENCHES),
+        ],
+    );
+}
+
+fn verb(name: &str, counts: Option<&str>) -> VerbConfig {
+    VerbConfig {
+        name: name.to_string(),
+        counts: counts.map(str::to_string),
+        in_tests: None,
+        description: None,
+    }
+}
+
+fn config(verbs: Vec<VerbConfig>) -> Config {
+    Config {
+        specs: vec![common::test_spec(vec![common::rust_impl(
+            &["src/**/*.rs", "tests/**/*.rs"],
+            &["tests/**/*.rs"],
+        )])],
+        verbs,
+    }
+}
+
+fn rule<'a>(data: &'a DashboardData, id: &str) -> &'a ApiRule {
+    data.forward_by_impl[&("test".to_string(), "rust".to_string())]
+        .rules
+        .iter()
+        .find(|r| r.id.base == id)
+        .unwrap()
+}
+
+fn verbs_of(refs: &[tracey::data::ApiCodeRef]) -> Vec<Option<&str>> {
+    refs.iter().map(|r| r.verb.as_deref()).collect()
+}
+
+async fn build_cached(
+    root: &Path,
+    verbs: Vec<VerbConfig>,
+    cache: &mut BuildCache,
+) -> DashboardData {
+    build_dashboard_data_with_overlay_and_cache(
+        root,
+        &config(verbs),
+        &ConfigFile::default(),
+        1,
+        true,
+        &FileOverlay::new(),
+        cache,
+        &[],
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn declared_verbs_count_as_configured() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+
+    let verbs = vec![
+        verb("bench", Some("verify")),
+        verb("fuzz", None),
+        verb("stub", Some("impl")),
+    ];
+    let data = build_dashboard_data(tmp.path(), &config(verbs), 1, true)
+        .await
+        .unwrap();
+
+    let login = rule(&data, "auth.login");
+    assert_eq!(verbs_of(&login.impl_refs), [None]);
+    assert_eq!(verbs_of(&login.verify_refs), [Some("bench")]);
+
+    // `sketch` isn't declared, so `r[sketch auth.session]` isn't a reference.
+    let session = rule(&data, "auth.session");
+    assert_eq!(verbs_of(&session.impl_refs), [Some("stub")]);
+    assert!(session.verify_refs.is_empty());
+    assert!(session.depends_refs.is_empty());
+    assert_eq!(verbs_of(&session.other_refs), [Some("fuzz")]);
+
+    // Verbs counting toward impl are kept out of test files by default.
+    let errors: Vec<_> = data
+        .validation_by_impl
+        .values()
+        .flat_map(|v| &v.errors)
+        .map(|e| (e.code, e.file.as_deref(), e.line))
+        .collect();
+    assert_eq!(
+        errors,
+        [(
+            ValidationErrorCode::ImplInTestFile,
+            Some("tests/benches.rs"),
+            Some(4)
+        )]
+    );
+
+    let warnings: Vec<_> = data
+        .workspace_diagnostics
+        .iter()
+        .flat_map(|f| f.diagnostics.iter().map(move |d| (f.path.as_str(), d)))
+        .map(|(path, d)| (path, d.code.as_str(), d.start_line))
+        .collect();
+    assert_eq!(warnings, [("tests/benches.rs", "impl-in-test", 3)]);
+}
+
+#[tokio::test]
+async fn declaring_a_verb_reparses_cached_files() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+
+    let mut cache = BuildCache::default();
+    let data = build_cached(tmp.path(), vec![], &mut cache).await;
+    assert!(rule(&data, "auth.session").other_refs.is_empty());
+
+    let data = build_cached(tmp.path(), vec![verb("fuzz", None)], &mut cache).await;
+    assert_eq!(
+        verbs_of(&rule(&data, "auth.session").other_refs),
+        [Some("fuzz")]
+    );
+}
+
+#[tokio::test]
+async fn invalid_verb_declarations_are_rejected() {
+    let tmp = tempfile::tempdir().unwrap();
+    write_project(tmp.path());
+
+    for verbs in [
+        vec![verb("verify", None)],
+        vec![verb("Bench", None)],
+        vec![verb("bench", None), verb("bench", Some("verify"))],
+        vec![verb("bench", Some("depends"))],
+    ] {
+        let names: Vec<_> = verbs.iter().map(|v| v.name.clone()).collect();
+        let result = build_dashboard_data(tmp.path(), &config(verbs), 1, true).await;
+        assert!(result.is_err(), "{names:?}");
+    }
+}